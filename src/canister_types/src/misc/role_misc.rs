@@ -8,6 +8,13 @@ pub static ADMIN_ROLE: &str = "admin";
 pub static MODERATOR_ROLE: &str = "moderator";
 pub static MEMBER_ROLE: &str = "member";
 
+// The index of a role determines its rank within the group, a higher index means a higher rank.
+// The default roles are spaced out so custom roles can be placed in between them.
+pub static OWNER_ROLE_INDEX: u64 = 400;
+pub static ADMIN_ROLE_INDEX: u64 = 300;
+pub static MODERATOR_ROLE_INDEX: u64 = 200;
+pub static MEMBER_ROLE_INDEX: u64 = 100;
+
 pub fn read_only_permission_actions() -> PermissionActions {
     PermissionActions::new(false, true, false, false)
 }
@@ -31,28 +38,28 @@ pub fn default_roles() -> Vec<Role> {
             true,
             all_access_permissions(),
             "#FFB800".into(),
-            None,
+            Some(OWNER_ROLE_INDEX),
         ),
         Role::new(
             ADMIN_ROLE.into(),
             true,
             all_access_permissions(),
             "#F56E00".into(),
-            None,
+            Some(ADMIN_ROLE_INDEX),
         ),
         Role::new(
             MODERATOR_ROLE.into(),
             true,
             moderator_permissions(),
             "#06E143".into(),
-            None,
+            Some(MODERATOR_ROLE_INDEX),
         ),
         Role::new(
             MEMBER_ROLE.into(),
             true,
             read_only_permissions(),
             "#7A9CF2".into(),
-            None,
+            Some(MEMBER_ROLE_INDEX),
         ),
     ]
 }
@@ -114,6 +121,11 @@ pub fn read_only_permissions() -> Vec<Permission> {
     ]
 }
 
+/// The names of the permissions that are available in every group
+pub fn default_permission_names() -> Vec<String> {
+    read_only_permissions().iter().map(|p| p.name()).collect()
+}
+
 pub fn create_permission(permission: &PermissionType) -> Permission {
     use PermissionType::*;
     match permission {
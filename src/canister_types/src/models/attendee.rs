@@ -6,7 +6,10 @@ use serde::Serialize;
 
 use crate::impl_storable_for;
 
-use super::invite_type::InviteType;
+use super::{
+    invite_type::InviteType,
+    permission::{PermissionActionType, PermissionType},
+};
 
 impl_storable_for!(Attendee);
 
@@ -28,14 +31,74 @@ impl Attendee {
                 group_id,
                 updated_at: time(),
                 created_at: time(),
+                occurrences: None,
+                rsvp: Some(RsvpStatus::Going),
+                rsvp_updated_at: Some(time()),
+                roles: None,
             },
         );
     }
 
+    pub fn set_rsvp(&mut self, event_id: u64, status: RsvpStatus) -> Option<AttendeeJoin> {
+        let join = self.joined.get_mut(&event_id)?;
+        join.rsvp = Some(status);
+        join.rsvp_updated_at = Some(time());
+        join.updated_at = time();
+        Some(join.clone())
+    }
+
+    /// Join a single occurrence of a recurring event, a no-op when the whole series is already joined
+    pub fn add_joined_occurrence(&mut self, event_id: u64, group_id: u64, occurrence_start: u64) {
+        let join = self.joined.entry(event_id).or_insert_with(|| AttendeeJoin {
+            group_id,
+            updated_at: time(),
+            created_at: time(),
+            occurrences: Some(vec![]),
+            rsvp: Some(RsvpStatus::Going),
+            rsvp_updated_at: Some(time()),
+            roles: None,
+        });
+
+        if let Some(occurrences) = join.occurrences.as_mut() {
+            if !occurrences.contains(&occurrence_start) {
+                occurrences.push(occurrence_start);
+                join.updated_at = time();
+            }
+        }
+    }
+
     pub fn get_joined(&self, event_id: &u64) -> Option<AttendeeJoin> {
         self.joined.get(event_id).cloned()
     }
 
+    pub fn add_event_role(&mut self, event_id: u64, role: EventRole) -> Option<AttendeeJoin> {
+        let join = self.joined.get_mut(&event_id)?;
+        let mut roles = join.get_roles();
+        if !roles.contains(&role) {
+            roles.push(role);
+        }
+        join.roles = Some(roles);
+        join.updated_at = time();
+        Some(join.clone())
+    }
+
+    pub fn remove_event_role(&mut self, event_id: u64, role: &EventRole) -> Option<AttendeeJoin> {
+        let join = self.joined.get_mut(&event_id)?;
+        let mut roles = join.get_roles();
+        roles.retain(|r| r != role);
+        join.roles = Some(roles);
+        join.updated_at = time();
+        Some(join.clone())
+    }
+
+    pub fn has_event_permission(&self, event_id: &u64, permission: &EventPermission) -> bool {
+        self.joined.get(event_id).is_some_and(|join| {
+            join.get_roles()
+                .iter()
+                .any(|role| role.get_permissions().contains(permission))
+        })
+    }
+
     pub fn get_multiple_joined(&self) -> Vec<(u64, AttendeeJoin)> {
         self.joined.iter().map(|(k, v)| (*k, v.clone())).collect()
     }
@@ -74,6 +137,19 @@ impl Attendee {
         self.invites.get(event_id).cloned()
     }
 
+    /// Point the join and invite of an event to the group the event is moved to
+    pub fn transfer_event_to_group(&mut self, event_id: u64, group_id: u64) {
+        if let Some(join) = self.joined.get_mut(&event_id) {
+            join.group_id = group_id;
+            join.updated_at = time();
+        }
+
+        if let Some(invite) = self.invites.get_mut(&event_id) {
+            invite.group_id = group_id;
+            invite.updated_at = time();
+        }
+    }
+
     pub fn remove_invite(&mut self, event_id: u64) {
         self.invites.remove(&event_id);
     }
@@ -106,6 +182,119 @@ pub struct AttendeeJoin {
     pub group_id: u64,
     pub updated_at: u64,
     pub created_at: u64,
+    // `None` when the whole series is joined, otherwise the start dates of the joined occurrences
+    pub occurrences: Option<Vec<u64>>,
+    pub rsvp: Option<RsvpStatus>,
+    pub rsvp_updated_at: Option<u64>,
+    pub roles: Option<Vec<EventRole>>,
+}
+
+impl AttendeeJoin {
+    pub fn get_roles(&self) -> Vec<EventRole> {
+        self.roles.clone().unwrap_or_default()
+    }
+
+    /// Joins from before RSVP states existed count as going
+    pub fn get_rsvp(&self) -> RsvpStatus {
+        self.rsvp.clone().unwrap_or_default()
+    }
+
+    pub fn is_series_joined(&self) -> bool {
+        self.occurrences.is_none()
+    }
+
+    pub fn is_occurrence_joined(&self, occurrence_start: u64) -> bool {
+        match &self.occurrences {
+            Some(occurrences) => occurrences.contains(&occurrence_start),
+            None => true,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug, PartialEq, Eq, CandidType, Deserialize, Serialize)]
+pub enum RsvpStatus {
+    #[default]
+    Going,
+    Maybe,
+    NotGoing,
+}
+
+/// Roles an attendee can have within a single event, next to their group roles
+#[derive(Clone, Debug, PartialEq, Eq, CandidType, Deserialize, Serialize)]
+pub enum EventRole {
+    Host,
+    Speaker,
+    Volunteer,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, CandidType, Deserialize, Serialize)]
+pub enum EventPermission {
+    // create check-in codes, check in attendees and see the check-ins
+    ManageCheckIn,
+    // add, edit and remove sessions of the agenda
+    ManageAgenda,
+    // shown on the public event page
+    ListedPublicly,
+}
+
+impl EventPermission {
+    /// The group permissions the event permission stands in for, these are needed to grant it
+    pub fn get_group_permissions(&self) -> Vec<(PermissionType, PermissionActionType)> {
+        match self {
+            EventPermission::ManageCheckIn => {
+                vec![(PermissionType::Attendee(None), PermissionActionType::Edit)]
+            }
+            EventPermission::ManageAgenda => vec![
+                (PermissionType::Event(None), PermissionActionType::Edit),
+                (PermissionType::Event(None), PermissionActionType::Delete),
+            ],
+            EventPermission::ListedPublicly => vec![],
+        }
+    }
+}
+
+impl EventRole {
+    pub fn get_permissions(&self) -> Vec<EventPermission> {
+        use EventPermission::*;
+        match self {
+            EventRole::Host => vec![ManageCheckIn, ManageAgenda, ListedPublicly],
+            EventRole::Speaker => vec![ListedPublicly],
+            EventRole::Volunteer => vec![ManageCheckIn],
+        }
+    }
+
+    pub fn name(&self) -> String {
+        match self {
+            EventRole::Host => "event_host",
+            EventRole::Speaker => "event_speaker",
+            EventRole::Volunteer => "event_volunteer",
+        }
+        .to_string()
+    }
+}
+
+#[derive(Clone, Debug, CandidType, Deserialize, Serialize)]
+pub struct EventRoleResponse {
+    pub event_id: u64,
+    pub principal: Principal,
+    pub roles: Vec<EventRole>,
+}
+
+#[derive(Clone, Default, Debug, CandidType, Deserialize, Serialize)]
+pub struct RsvpCounts {
+    pub going: u64,
+    pub maybe: u64,
+    pub not_going: u64,
+}
+
+impl RsvpCounts {
+    pub fn add(&mut self, status: &RsvpStatus) {
+        match status {
+            RsvpStatus::Going => self.going += 1,
+            RsvpStatus::Maybe => self.maybe += 1,
+            RsvpStatus::NotGoing => self.not_going += 1,
+        }
+    }
 }
 
 #[derive(Clone, Debug, CandidType, Deserialize, Serialize)]
@@ -132,6 +321,8 @@ pub struct JoinedAttendeeResponse {
     pub event_id: u64,
     pub group_id: u64,
     pub principal: Principal,
+    // set when the event was full and the principal is put on the waitlist instead
+    pub waitlist_position: Option<u64>,
 }
 
 impl JoinedAttendeeResponse {
@@ -140,6 +331,14 @@ impl JoinedAttendeeResponse {
             event_id,
             group_id,
             principal,
+            waitlist_position: None,
+        }
+    }
+
+    pub fn waitlisted(event_id: u64, group_id: u64, principal: Principal, position: u64) -> Self {
+        Self {
+            waitlist_position: Some(position),
+            ..Self::new(event_id, group_id, principal)
         }
     }
 }
@@ -167,3 +366,93 @@ impl InviteAttendeeResponse {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{Attendee, AttendeeJoin, EventPermission, EventRole, RsvpCounts, RsvpStatus};
+    use crate::models::permission::{PermissionActionType, PermissionType};
+
+    fn join(rsvp: Option<RsvpStatus>) -> AttendeeJoin {
+        AttendeeJoin {
+            group_id: 1,
+            updated_at: 0,
+            created_at: 0,
+            occurrences: None,
+            rsvp,
+            rsvp_updated_at: None,
+            roles: None,
+        }
+    }
+
+    fn attendee_with_roles(event_id: u64, roles: Vec<EventRole>) -> Attendee {
+        let mut attendee = Attendee::default();
+        attendee.joined.insert(
+            event_id,
+            AttendeeJoin {
+                roles: Some(roles),
+                ..join(None)
+            },
+        );
+        attendee
+    }
+
+    #[test]
+    fn test_rsvp_counts() {
+        let joins = [
+            join(None),
+            join(Some(RsvpStatus::Going)),
+            join(Some(RsvpStatus::Maybe)),
+            join(Some(RsvpStatus::NotGoing)),
+            join(Some(RsvpStatus::Maybe)),
+        ];
+
+        let mut counts = RsvpCounts::default();
+        for join in &joins {
+            counts.add(&join.get_rsvp());
+        }
+
+        // joins from before RSVP states existed count as going
+        assert_eq!(2, counts.going);
+        assert_eq!(2, counts.maybe);
+        assert_eq!(1, counts.not_going);
+    }
+
+    #[test]
+    fn test_event_role_permissions() {
+        let host = attendee_with_roles(1, vec![EventRole::Host]);
+        assert!(host.has_event_permission(&1, &EventPermission::ManageAgenda));
+        assert!(host.has_event_permission(&1, &EventPermission::ManageCheckIn));
+        // roles only apply to the event they are given for
+        assert!(!host.has_event_permission(&2, &EventPermission::ManageAgenda));
+
+        let volunteer = attendee_with_roles(1, vec![EventRole::Volunteer]);
+        assert!(volunteer.has_event_permission(&1, &EventPermission::ManageCheckIn));
+        assert!(!volunteer.has_event_permission(&1, &EventPermission::ManageAgenda));
+
+        let speaker = attendee_with_roles(1, vec![EventRole::Speaker]);
+        assert!(speaker.has_event_permission(&1, &EventPermission::ListedPublicly));
+        assert!(!speaker.has_event_permission(&1, &EventPermission::ManageCheckIn));
+    }
+
+    #[test]
+    fn test_event_permission_group_permissions() {
+        assert!(matches!(
+            EventPermission::ManageCheckIn
+                .get_group_permissions()
+                .as_slice(),
+            [(PermissionType::Attendee(None), PermissionActionType::Edit)]
+        ));
+        assert!(matches!(
+            EventPermission::ManageAgenda
+                .get_group_permissions()
+                .as_slice(),
+            [
+                (PermissionType::Event(None), PermissionActionType::Edit),
+                (PermissionType::Event(None), PermissionActionType::Delete)
+            ]
+        ));
+        assert!(EventPermission::ListedPublicly
+            .get_group_permissions()
+            .is_empty());
+    }
+}
@@ -0,0 +1,109 @@
+use candid::{CandidType, Deserialize, Principal};
+use ic_cdk::{api::time, caller};
+use serde::Serialize;
+
+use crate::{impl_storable_for, models::date_range::DateRange};
+
+use super::subject::Subject;
+
+impl_storable_for!(AuditLog);
+
+#[derive(Clone, Debug, CandidType, Deserialize, Serialize, PartialEq, Eq)]
+pub enum AuditAction {
+    GroupEdited,
+    MemberRemoved,
+    MemberBanned,
+    MemberBanLifted,
+    MemberMuted,
+    MemberMuteLifted,
+    RoleAdded,
+    RoleRemoved,
+    RolePermissionsEdited,
+    MemberRoleAssigned,
+    MemberRoleRemoved,
+    CustomPermissionAdded,
+    CustomPermissionRemoved,
+    OwnershipTransferRequested,
+    OwnershipTransferred,
+    EventCreated,
+    EventEdited,
+    EventCancelled,
+    EventDeleted,
+    EventTransferred,
+    AttendeeRemoved,
+    AttendeeCheckedIn,
+    EventCoHostInvited,
+    EventCoHostAdded,
+    EventCoHostRemoved,
+    AttendeeRoleAssigned,
+    AttendeeRoleRemoved,
+}
+
+#[derive(Clone, Debug, CandidType, Deserialize, Serialize)]
+pub struct AuditLog {
+    pub group_id: u64,
+    pub actor: Principal,
+    pub action: AuditAction,
+    pub subject: Subject,
+    pub details: String,
+    pub created_on: u64,
+}
+
+impl AuditLog {
+    pub fn new(group_id: u64, action: AuditAction, subject: Subject, details: String) -> Self {
+        Self {
+            group_id,
+            actor: caller(),
+            action,
+            subject,
+            details,
+            created_on: time(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, CandidType, Deserialize, Serialize)]
+pub struct AuditLogResponse {
+    pub id: u64,
+    pub group_id: u64,
+    pub actor: Principal,
+    pub action: AuditAction,
+    pub subject: Subject,
+    pub details: String,
+    pub created_on: u64,
+}
+
+impl AuditLogResponse {
+    pub fn new(id: u64, log: AuditLog) -> Self {
+        Self {
+            id,
+            group_id: log.group_id,
+            actor: log.actor,
+            action: log.action,
+            subject: log.subject,
+            details: log.details,
+            created_on: log.created_on,
+        }
+    }
+}
+
+#[derive(Clone, Debug, CandidType, Deserialize, Default)]
+pub enum AuditLogFilter {
+    #[default]
+    None,
+    Actor(Principal),
+    Action(AuditAction),
+    CreatedOn(DateRange),
+}
+
+impl AuditLogFilter {
+    pub fn is_match(&self, _id: &u64, log: &AuditLog) -> bool {
+        use AuditLogFilter::*;
+        match self {
+            None => true,
+            Actor(principal) => principal == &log.actor,
+            Action(action) => action == &log.action,
+            CreatedOn(date_range) => date_range.is_within(log.created_on),
+        }
+    }
+}
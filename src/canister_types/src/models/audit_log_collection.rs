@@ -0,0 +1,22 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+
+use crate::impl_storable_for;
+
+impl_storable_for!(AuditLogCollection);
+
+/// The ids of the audit logs of a single group, in insertion order
+#[derive(Clone, Default, Debug, CandidType, Deserialize, Serialize)]
+pub struct AuditLogCollection {
+    pub logs: Vec<u64>,
+}
+
+impl AuditLogCollection {
+    pub fn get_log_ids(&self) -> Vec<u64> {
+        self.logs.clone()
+    }
+
+    pub fn add_log(&mut self, id: u64) {
+        self.logs.push(id);
+    }
+}
@@ -0,0 +1,39 @@
+use candid::CandidType;
+use ic_cdk::api::time;
+use serde::{Deserialize, Serialize};
+
+use crate::impl_storable_for;
+
+impl_storable_for!(CalendarFeed);
+
+#[derive(Clone, Debug, CandidType, Deserialize, Serialize)]
+pub struct CalendarFeed {
+    pub token: String,
+    pub created_at: u64,
+}
+
+impl CalendarFeed {
+    pub fn new(token: String) -> Self {
+        Self {
+            token,
+            created_at: time(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, CandidType, Deserialize, Serialize)]
+pub struct CalendarFeedResponse {
+    pub token: String,
+    pub path: String,
+    pub created_at: u64,
+}
+
+impl From<CalendarFeed> for CalendarFeedResponse {
+    fn from(feed: CalendarFeed) -> Self {
+        Self {
+            path: format!("/calendar/{}.ics", feed.token),
+            token: feed.token,
+            created_at: feed.created_at,
+        }
+    }
+}
@@ -0,0 +1,231 @@
+use candid::{CandidType, Principal};
+use ic_cdk::api::time;
+use serde::{Deserialize, Serialize};
+
+use crate::impl_storable_for;
+
+impl_storable_for!(EventCheckIns);
+
+#[derive(Clone, Debug, PartialEq, Eq, CandidType, Deserialize, Serialize)]
+pub enum CheckInMethod {
+    Code,
+    // checked in by an organiser
+    Manual(Principal),
+}
+
+#[derive(Clone, Debug, CandidType, Deserialize, Serialize)]
+pub struct CheckIn {
+    pub principal: Principal,
+    pub method: CheckInMethod,
+    pub checked_in_at: u64,
+    // start of the checked in occurrence, the event start for non-recurring events
+    pub occurrence_start: Option<u64>,
+}
+
+#[derive(Clone, Debug, CandidType, Deserialize, Serialize)]
+pub struct CheckInCode {
+    pub code: String,
+    pub created_by: Principal,
+    pub created_at: u64,
+    pub expires_at: u64,
+    pub redeemed_by: Option<Principal>,
+}
+
+impl CheckInCode {
+    pub fn is_redeemable(&self) -> bool {
+        self.is_redeemable_at(time())
+    }
+
+    pub fn is_redeemable_at(&self, now: u64) -> bool {
+        self.redeemed_by.is_none() && self.expires_at > now
+    }
+}
+
+#[derive(Clone, Default, Debug, CandidType, Deserialize, Serialize)]
+pub struct EventCheckIns {
+    pub codes: Vec<CheckInCode>,
+    pub check_ins: Vec<CheckIn>,
+}
+
+impl EventCheckIns {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a new code and drops the ones that can no longer be redeemed
+    pub fn add_code(
+        &mut self,
+        code: String,
+        created_by: Principal,
+        now: u64,
+        expires_at: u64,
+    ) -> CheckInCode {
+        self.codes.retain(|c| c.is_redeemable_at(now));
+
+        let code = CheckInCode {
+            code,
+            created_by,
+            created_at: now,
+            expires_at,
+            redeemed_by: None,
+        };
+        self.codes.push(code.clone());
+        code
+    }
+
+    /// Marks the code as used by the principal, returns false if the code is unknown, used or expired
+    pub fn redeem_code(&mut self, code: &str, principal: Principal, now: u64) -> bool {
+        match self
+            .codes
+            .iter_mut()
+            .find(|c| c.code == code && c.is_redeemable_at(now))
+        {
+            Some(code) => {
+                code.redeemed_by = Some(principal);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn is_checked_in(&self, principal: &Principal, occurrence_start: u64) -> bool {
+        self.get_occurrence_check_ins(occurrence_start)
+            .iter()
+            .any(|c| &c.principal == principal)
+    }
+
+    pub fn get_occurrence_check_ins(&self, occurrence_start: u64) -> Vec<CheckIn> {
+        self.check_ins
+            .iter()
+            .filter(|c| c.occurrence_start == Some(occurrence_start))
+            .cloned()
+            .collect()
+    }
+
+    pub fn add_check_in(
+        &mut self,
+        principal: Principal,
+        method: CheckInMethod,
+        occurrence_start: u64,
+        now: u64,
+    ) -> CheckIn {
+        let check_in = CheckIn {
+            principal,
+            method,
+            checked_in_at: now,
+            occurrence_start: Some(occurrence_start),
+        };
+        self.check_ins.push(check_in.clone());
+        check_in
+    }
+
+    /// Share of the going attendees that did not check in for the occurrence, check-ins of other attendees are ignored
+    pub fn no_show_rate(&self, going: &[Principal], occurrence_start: u64) -> f64 {
+        if going.is_empty() {
+            return 0.0;
+        }
+
+        let checked_in = going
+            .iter()
+            .filter(|p| self.is_checked_in(p, occurrence_start))
+            .count();
+        (going.len() - checked_in) as f64 / going.len() as f64
+    }
+}
+
+#[derive(Clone, Debug, CandidType, Deserialize, Serialize)]
+pub struct EventCheckInSummary {
+    pub occurrence_start: u64,
+    pub check_ins: Vec<CheckIn>,
+    // attendees with a Going RSVP
+    pub going_count: u64,
+    pub checked_in_count: u64,
+    // share of going attendees that did not check in, between 0 and 1
+    pub no_show_rate: f64,
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CheckInMethod, EventCheckIns};
+    use candid::Principal;
+
+    #[test]
+    fn test_redeem_code() {
+        let mut check_ins = EventCheckIns::new();
+        check_ins.add_code("ABCD2345".to_string(), Principal::from_slice(&[0]), 10, 100);
+
+        assert!(!check_ins.redeem_code("UNKNOWN1", Principal::from_slice(&[1]), 50));
+        // expired
+        assert!(!check_ins.redeem_code("ABCD2345", Principal::from_slice(&[1]), 100));
+
+        assert!(check_ins.redeem_code("ABCD2345", Principal::from_slice(&[1]), 50));
+        assert_eq!(
+            Some(Principal::from_slice(&[1])),
+            check_ins.codes[0].redeemed_by
+        );
+        // codes can only be used once
+        assert!(!check_ins.redeem_code("ABCD2345", Principal::from_slice(&[2]), 50));
+    }
+
+    #[test]
+    fn test_add_code_drops_unusable_codes() {
+        let mut check_ins = EventCheckIns::new();
+        check_ins.add_code("EXPIRED2".to_string(), Principal::from_slice(&[0]), 0, 20);
+        check_ins.add_code("REDEEMED".to_string(), Principal::from_slice(&[0]), 0, 100);
+        check_ins.add_code("VALID234".to_string(), Principal::from_slice(&[0]), 0, 100);
+        assert!(check_ins.redeem_code("REDEEMED", Principal::from_slice(&[1]), 10));
+
+        check_ins.add_code("NEW23456".to_string(), Principal::from_slice(&[0]), 30, 130);
+
+        let codes: Vec<&str> = check_ins.codes.iter().map(|c| c.code.as_str()).collect();
+        assert_eq!(vec!["VALID234", "NEW23456"], codes);
+    }
+
+    #[test]
+    fn test_no_show_rate() {
+        let mut check_ins = EventCheckIns::new();
+        assert_eq!(0.0, check_ins.no_show_rate(&[], 100));
+
+        let going = [
+            Principal::from_slice(&[1]),
+            Principal::from_slice(&[2]),
+            Principal::from_slice(&[3]),
+            Principal::from_slice(&[4]),
+        ];
+        assert_eq!(1.0, check_ins.no_show_rate(&going, 100));
+
+        check_ins.add_check_in(Principal::from_slice(&[1]), CheckInMethod::Code, 100, 110);
+        check_ins.add_check_in(
+            Principal::from_slice(&[2]),
+            CheckInMethod::Manual(Principal::from_slice(&[0])),
+            100,
+            110,
+        );
+        // attendees that are not going don't lower the rate
+        check_ins.add_check_in(Principal::from_slice(&[5]), CheckInMethod::Code, 100, 110);
+
+        assert!(check_ins.is_checked_in(&Principal::from_slice(&[5]), 100));
+        assert_eq!(0.5, check_ins.no_show_rate(&going, 100));
+    }
+
+    #[test]
+    fn test_check_ins_per_occurrence() {
+        let mut check_ins = EventCheckIns::new();
+        let going = [Principal::from_slice(&[1]), Principal::from_slice(&[2])];
+
+        check_ins.add_check_in(Principal::from_slice(&[1]), CheckInMethod::Code, 100, 110);
+        check_ins.add_check_in(Principal::from_slice(&[1]), CheckInMethod::Code, 200, 210);
+        check_ins.add_check_in(Principal::from_slice(&[2]), CheckInMethod::Code, 200, 210);
+
+        // a check-in only counts for its own occurrence
+        assert!(check_ins.is_checked_in(&Principal::from_slice(&[1]), 100));
+        assert!(!check_ins.is_checked_in(&Principal::from_slice(&[2]), 100));
+        assert!(!check_ins.is_checked_in(&Principal::from_slice(&[1]), 300));
+
+        assert_eq!(1, check_ins.get_occurrence_check_ins(100).len());
+        assert_eq!(2, check_ins.get_occurrence_check_ins(200).len());
+        assert_eq!(0.5, check_ins.no_show_rate(&going, 100));
+        assert_eq!(0.0, check_ins.no_show_rate(&going, 200));
+        assert_eq!(1.0, check_ins.no_show_rate(&going, 300));
+    }
+}
@@ -7,14 +7,20 @@ use serde::Serialize;
 use crate::{
     impl_storable_for,
     models::{
-        asset::Asset, date_range::DateRange, location::Location, privacy::Privacy,
+        asset::Asset,
+        date_range::DateRange,
+        location::Location,
+        privacy::Privacy,
+        recurrence::RecurrenceRule,
         sort_direction::SortDirection,
+        ticket::TicketPrice,
+        time_zone::{LocalDayRange, TimeZone},
     },
 };
 
 use super::{
     api_error::ApiError,
-    attendee::{InviteAttendeeResponse, JoinedAttendeeResponse},
+    attendee::{InviteAttendeeResponse, JoinedAttendeeResponse, RsvpCounts, RsvpStatus},
     boosted::Boost,
 };
 
@@ -39,6 +45,23 @@ pub struct Event {
     pub metadata: Option<String>,
     pub updated_on: u64,
     pub created_on: u64,
+    pub recurrence: Option<RecurrenceRule>,
+    // (series event id, occurrence start) when this event was detached from a recurring series
+    pub detached_from: Option<(u64, u64)>,
+    pub max_attendees: Option<u64>,
+    pub ticket_price: Option<TicketPrice>,
+    // groups that host the event next to `group_id`
+    pub co_hosts: Option<Vec<u64>>,
+    // groups that are invited to co-host the event
+    pub co_host_invites: Option<Vec<u64>>,
+    // attendees need to be a member of a hosting group for at least this many days
+    pub min_group_tenure_days: Option<u64>,
+    // IANA time zone identifier, recurring events keep their local time in this zone
+    pub time_zone: Option<String>,
+    // drafts are only visible to editors of the hosting groups
+    pub is_draft: Option<bool>,
+    // the draft is published by a timer at this timestamp
+    pub publish_at: Option<u64>,
 }
 
 impl Event {
@@ -67,6 +90,17 @@ impl From<PostEvent> for Event {
             metadata: post_event.metadata,
             updated_on: time(),
             created_on: time(),
+            recurrence: post_event.recurrence,
+            detached_from: None,
+            max_attendees: post_event.max_attendees,
+            ticket_price: post_event.ticket_price,
+            co_hosts: None,
+            co_host_invites: None,
+            min_group_tenure_days: post_event.min_group_tenure_days,
+            time_zone: post_event.time_zone,
+            is_draft: (post_event.is_draft.unwrap_or_default() || post_event.publish_at.is_some())
+                .then_some(true),
+            publish_at: post_event.publish_at,
         }
     }
 }
@@ -83,6 +117,11 @@ impl Event {
         self.banner_image = update_event.banner_image;
         self.tags = update_event.tags;
         self.metadata = update_event.metadata;
+        self.recurrence = update_event.recurrence;
+        self.max_attendees = update_event.max_attendees;
+        self.ticket_price = update_event.ticket_price;
+        self.min_group_tenure_days = update_event.min_group_tenure_days;
+        self.time_zone = update_event.time_zone;
         self.updated_on = time();
         self.clone()
     }
@@ -105,9 +144,199 @@ impl Event {
         self.clone()
     }
 
+    /// A new event with the same setup at another date, owned by `owner`
+    /// # Note
+    /// The end of a recurring series moves along with the date, skipped occurrences are not copied
+    pub fn copy_to_date(&self, date: DateRange, owner: Principal, now: u64) -> Self {
+        let recurrence = self.recurrence.clone().map(|rule| RecurrenceRule {
+            until: rule.until.map(|until| {
+                until
+                    .saturating_add(date.start_date())
+                    .saturating_sub(self.date.start_date())
+            }),
+            exceptions: vec![],
+            ..rule
+        });
+
+        Self {
+            date,
+            created_by: owner,
+            owner,
+            is_canceled: (false, "".to_string()),
+            is_deleted: false,
+            updated_on: now,
+            created_on: now,
+            recurrence,
+            detached_from: None,
+            co_hosts: None,
+            co_host_invites: None,
+            is_draft: None,
+            publish_at: None,
+            ..self.clone()
+        }
+    }
+
+    pub fn is_draft(&self) -> bool {
+        self.is_draft.unwrap_or_default()
+    }
+
+    pub fn publish(&mut self) -> Self {
+        self.is_draft = None;
+        self.publish_at = None;
+        self.updated_on = time();
+        self.clone()
+    }
+
+    /// Keep the event as a draft until `publish_at`
+    pub fn schedule_publish(&mut self, publish_at: u64) -> Self {
+        self.is_draft = Some(true);
+        self.publish_at = Some(publish_at);
+        self.updated_on = time();
+        self.clone()
+    }
+
     pub fn is_from_group(&self, group_id: u64) -> bool {
         self.group_id == group_id
     }
+
+    /// Check if the update changes the fields that only the owning group can edit
+    /// # Note
+    /// Co-hosts can't change the privacy, ticket price, date, time zone or recurrence of the event
+    pub fn changes_owner_fields(&self, update_event: &UpdateEvent) -> bool {
+        self.privacy != update_event.privacy
+            || self.ticket_price != update_event.ticket_price
+            || self.date != update_event.date
+            || self.time_zone != update_event.time_zone
+            || self.recurrence != update_event.recurrence
+    }
+
+    /// Check if the group hosts the event, either as the owning group or as a co-host
+    pub fn is_hosted_by(&self, group_id: u64) -> bool {
+        self.is_from_group(group_id) || self.get_co_hosts().contains(&group_id)
+    }
+
+    pub fn get_co_hosts(&self) -> Vec<u64> {
+        self.co_hosts.clone().unwrap_or_default()
+    }
+
+    /// The owning group followed by the co-hosts
+    pub fn get_host_group_ids(&self) -> Vec<u64> {
+        let mut group_ids = vec![self.group_id];
+        group_ids.extend(self.get_co_hosts());
+        group_ids
+    }
+
+    pub fn is_co_host_invited(&self, group_id: u64) -> bool {
+        self.co_host_invites
+            .as_ref()
+            .is_some_and(|invites| invites.contains(&group_id))
+    }
+
+    pub fn add_co_host_invite(&mut self, group_id: u64) {
+        self.co_host_invites
+            .get_or_insert_with(Vec::new)
+            .push(group_id);
+        self.updated_on = time();
+    }
+
+    pub fn remove_co_host_invite(&mut self, group_id: u64) {
+        if let Some(invites) = self.co_host_invites.as_mut() {
+            invites.retain(|id| id != &group_id);
+        }
+        self.updated_on = time();
+    }
+
+    pub fn add_co_host(&mut self, group_id: u64) {
+        self.co_hosts.get_or_insert_with(Vec::new).push(group_id);
+        self.updated_on = time();
+    }
+
+    /// Move the event to another group, the new group stops being a co-host
+    pub fn transfer_to_group(&mut self, group_id: u64) {
+        self.group_id = group_id;
+        self.remove_co_host(group_id);
+        self.remove_co_host_invite(group_id);
+    }
+
+    pub fn remove_co_host(&mut self, group_id: u64) {
+        if let Some(co_hosts) = self.co_hosts.as_mut() {
+            co_hosts.retain(|id| id != &group_id);
+        }
+        self.updated_on = time();
+    }
+
+    /// Check if there is room for a new attendee, events without `max_attendees` have no limit
+    pub fn has_capacity(&self, attendees_count: u64) -> bool {
+        self.max_attendees
+            .map(|max_attendees| attendees_count < max_attendees)
+            .unwrap_or(true)
+    }
+
+    /// Check if the event, or one of its occurrences, is taking place at the given time
+    pub fn is_ongoing(&self, now: u64) -> bool {
+        self.ongoing_occurrence(now).is_some()
+    }
+
+    /// The date of the event, or of the occurrence, that is taking place at the given time
+    pub fn ongoing_occurrence(&self, now: u64) -> Option<DateRange> {
+        let duration = self.date.end_date().saturating_sub(self.date.start_date());
+        self.occurrences(&DateRange::new(now.saturating_sub(duration), now))
+            .into_iter()
+            .find(|date| date.start_date() <= now && now <= date.end_date())
+    }
+
+    pub fn is_recurring(&self) -> bool {
+        self.recurrence.is_some()
+    }
+
+    pub fn get_time_zone(&self) -> Option<TimeZone> {
+        self.time_zone.as_deref().and_then(TimeZone::find)
+    }
+
+    pub fn is_occurrence(&self, start: u64) -> bool {
+        match &self.recurrence {
+            Some(rule) => {
+                rule.is_occurrence(self.date.start_date(), start, self.get_time_zone().as_ref())
+            }
+            None => self.date.start_date() == start,
+        }
+    }
+
+    /// The dates of this event that start within the window, a single date for non-recurring events
+    pub fn occurrences(&self, window: &DateRange) -> Vec<DateRange> {
+        match &self.recurrence {
+            Some(rule) => rule.occurrences(&self.date, window, self.get_time_zone().as_ref()),
+            None if window.is_within(self.date.start_date()) => vec![self.date.clone()],
+            None => vec![],
+        }
+    }
+
+    /// Start of the occurrence of this series that takes the place of the `start` occurrence of `series`,
+    /// when this series continues `series` from its `split_start` occurrence onwards
+    /// # Note
+    /// Occurrences are matched by their position after the split, `None` when this series has no such occurrence
+    pub fn continued_occurrence_start(
+        &self,
+        series: &Event,
+        split_start: u64,
+        start: u64,
+    ) -> Option<u64> {
+        let series_rule = series.recurrence.as_ref()?;
+        let series_time_zone = series.get_time_zone();
+        let index_of = |start| {
+            series_rule.index_of(series.date.start_date(), start, series_time_zone.as_ref())
+        };
+
+        let offset = index_of(start)?.checked_sub(index_of(split_start)?)?;
+        let continued_start = self.recurrence.as_ref()?.nth_start(
+            self.date.start_date(),
+            offset,
+            self.get_time_zone().as_ref(),
+        )?;
+
+        self.is_occurrence(continued_start)
+            .then_some(continued_start)
+    }
 }
 
 impl Default for Event {
@@ -130,6 +359,16 @@ impl Default for Event {
             updated_on: Default::default(),
             created_on: Default::default(),
             metadata: Default::default(),
+            recurrence: Default::default(),
+            detached_from: Default::default(),
+            max_attendees: Default::default(),
+            ticket_price: Default::default(),
+            co_hosts: Default::default(),
+            co_host_invites: Default::default(),
+            min_group_tenure_days: Default::default(),
+            time_zone: Default::default(),
+            is_draft: Default::default(),
+            publish_at: Default::default(),
         }
     }
 }
@@ -147,6 +386,43 @@ pub struct PostEvent {
     banner_image: Asset,
     metadata: Option<String>,
     tags: Vec<u32>,
+    recurrence: Option<RecurrenceRule>,
+    max_attendees: Option<u64>,
+    ticket_price: Option<TicketPrice>,
+    min_group_tenure_days: Option<u64>,
+    time_zone: Option<String>,
+    is_draft: Option<bool>,
+    publish_at: Option<u64>,
+}
+
+impl PostEvent {
+    pub fn date(&self) -> &DateRange {
+        &self.date
+    }
+
+    pub fn privacy(&self) -> &Privacy {
+        &self.privacy
+    }
+
+    pub fn recurrence(&self) -> &Option<RecurrenceRule> {
+        &self.recurrence
+    }
+
+    pub fn max_attendees(&self) -> Option<u64> {
+        self.max_attendees
+    }
+
+    pub fn ticket_price(&self) -> &Option<TicketPrice> {
+        &self.ticket_price
+    }
+
+    pub fn time_zone(&self) -> &Option<String> {
+        &self.time_zone
+    }
+
+    pub fn publish_at(&self) -> Option<u64> {
+        self.publish_at
+    }
 }
 
 #[derive(Clone, Debug, CandidType, Deserialize)]
@@ -162,6 +438,17 @@ pub struct UpdateEvent {
     pub banner_image: Asset,
     pub metadata: Option<String>,
     pub tags: Vec<u32>,
+    pub recurrence: Option<RecurrenceRule>,
+    pub max_attendees: Option<u64>,
+    pub ticket_price: Option<TicketPrice>,
+    pub min_group_tenure_days: Option<u64>,
+    pub time_zone: Option<String>,
+}
+
+#[derive(Clone, Debug, CandidType, Deserialize)]
+pub enum EventEditScope {
+    ThisOccurrence,
+    AllFuture,
 }
 
 #[derive(Clone, CandidType, Serialize, Deserialize, Debug)]
@@ -169,6 +456,8 @@ pub struct EventCallerData {
     pub joined: Option<JoinedAttendeeResponse>,
     pub invite: Option<InviteAttendeeResponse>,
     pub is_starred: bool,
+    pub waitlist_position: Option<u64>,
+    pub rsvp: Option<RsvpStatus>,
 }
 
 impl EventCallerData {
@@ -176,11 +465,15 @@ impl EventCallerData {
         joined: Option<JoinedAttendeeResponse>,
         invite: Option<InviteAttendeeResponse>,
         is_starred: bool,
+        waitlist_position: Option<u64>,
+        rsvp: Option<RsvpStatus>,
     ) -> Self {
         Self {
             joined,
             invite,
             is_starred,
+            waitlist_position,
+            rsvp,
         }
     }
 }
@@ -195,7 +488,10 @@ pub enum EventSort {
 
 impl EventSort {
     pub fn sort(&self, events: HashMap<u64, Event>) -> Vec<(u64, Event)> {
-        let mut events: Vec<(u64, Event)> = events.into_iter().collect();
+        self.sort_list(events.into_iter().collect())
+    }
+
+    pub fn sort_list(&self, mut events: Vec<(u64, Event)>) -> Vec<(u64, Event)> {
         match self {
             EventSort::CreatedOn(SortDirection::Asc) => {
                 events.sort_by(|a, b| a.1.created_on.cmp(&b.1.created_on))
@@ -232,6 +528,8 @@ pub enum EventFilter {
     None,
     Name(String),
     StartDate(DateRange),
+    // whole days in a time zone, matched like `StartDate`
+    LocalStartDate(LocalDayRange),
     EndDate(DateRange),
     Owner(Principal),
     Groups(Vec<u64>),
@@ -240,22 +538,39 @@ pub enum EventFilter {
     IsCanceled(bool),
     UpdatedOn(DateRange),
     CreatedOn(DateRange),
+    // the caller's RSVP on the event
+    Rsvp(RsvpStatus),
 }
 
 impl EventFilter {
+    /// Converts local-day filters into `StartDate` filters
+    pub fn resolve_local_dates(self) -> Result<Self, ApiError> {
+        match self {
+            EventFilter::LocalStartDate(range) => {
+                Ok(EventFilter::StartDate(range.to_date_range()?))
+            }
+            filter => Ok(filter),
+        }
+    }
+
     pub fn is_match(&self, id: &u64, event: &Event) -> bool {
         match self {
             EventFilter::None => true,
             EventFilter::Name(name) => event.name.to_lowercase().contains(&name.to_lowercase()),
-            EventFilter::StartDate(date) => date.is_within(event.date.start_date()),
+            EventFilter::StartDate(date) => !event.occurrences(date).is_empty(),
+            EventFilter::LocalStartDate(range) => range
+                .to_date_range()
+                .is_ok_and(|date| !event.occurrences(&date).is_empty()),
             EventFilter::EndDate(date) => date.is_within(event.date.end_date()),
             EventFilter::Owner(owner) => *owner == event.owner,
-            EventFilter::Groups(groups) => groups.contains(&event.group_id),
+            EventFilter::Groups(groups) => groups.iter().any(|id| event.is_hosted_by(*id)),
             EventFilter::Ids(ids) => ids.contains(id),
             EventFilter::Tag(tag) => event.tags.contains(tag),
             EventFilter::IsCanceled(is_canceled) => event.is_canceled.0 == *is_canceled,
             EventFilter::UpdatedOn(date) => date.is_within(event.updated_on),
             EventFilter::CreatedOn(date) => date.is_within(event.created_on),
+            // depends on the caller's attendee data, applied in the event logic
+            EventFilter::Rsvp(_) => true,
         }
     }
 }
@@ -294,6 +609,24 @@ pub struct EventResponse {
     pub attendee_count: u64,
     pub boosted: Option<Boost>,
     pub caller_data: Option<EventCallerData>,
+    pub recurrence: Option<RecurrenceRule>,
+    pub detached_from: Option<(u64, u64)>,
+    // set when the response represents a single expanded occurrence of a recurring event
+    pub occurrence_start: Option<u64>,
+    pub max_attendees: Option<u64>,
+    // `None` when the event has no attendee limit
+    pub remaining_seats: Option<u64>,
+    pub waitlist_count: u64,
+    pub rsvp_counts: RsvpCounts,
+    pub ticket_price: Option<TicketPrice>,
+    pub co_hosts: Vec<u64>,
+    pub min_group_tenure_days: Option<u64>,
+    pub time_zone: Option<String>,
+    // ISO 8601 start and end in the event time zone, with the UTC offset
+    pub local_start: Option<String>,
+    pub local_end: Option<String>,
+    pub is_draft: bool,
+    pub publish_at: Option<u64>,
 }
 
 impl EventResponse {
@@ -303,7 +636,13 @@ impl EventResponse {
         boosted: Option<Boost>,
         caller_data: Option<EventCallerData>,
         attendee_count: u64,
+        waitlist_count: u64,
+        rsvp_counts: RsvpCounts,
     ) -> Self {
+        let time_zone = event.get_time_zone();
+        let local_start = time_zone.map(|zone| zone.format_local(event.date.start_date()));
+        let local_end = time_zone.map(|zone| zone.format_local(event.date.end_date()));
+
         Self {
             id,
             name: event.name,
@@ -326,19 +665,212 @@ impl EventResponse {
             boosted,
             caller_data,
             attendee_count,
+            recurrence: event.recurrence,
+            detached_from: event.detached_from,
+            occurrence_start: None,
+            max_attendees: event.max_attendees,
+            remaining_seats: event
+                .max_attendees
+                .map(|max_attendees| max_attendees.saturating_sub(attendee_count)),
+            waitlist_count,
+            rsvp_counts,
+            co_hosts: event.co_hosts.unwrap_or_default(),
+            min_group_tenure_days: event.min_group_tenure_days,
+            time_zone: event.time_zone,
+            local_start,
+            local_end,
+            ticket_price: event.ticket_price,
+            is_draft: event.is_draft.unwrap_or_default(),
+            publish_at: event.publish_at,
         }
     }
 
+    pub fn with_occurrence_start(mut self, occurrence_start: u64) -> Self {
+        self.occurrence_start = Some(occurrence_start);
+        self
+    }
+
     pub fn from_result(
         id: u64,
         event: Result<Event, ApiError>,
         boosted: Option<Boost>,
         attendee_count: u64,
+        waitlist_count: u64,
+        rsvp_counts: RsvpCounts,
         caller_data: Option<EventCallerData>,
     ) -> Result<Self, ApiError> {
         match event {
             Err(e) => Err(e),
-            Ok(event) => Ok(Self::new(id, event, boosted, caller_data, attendee_count)),
+            Ok(event) => Ok(Self::new(
+                id,
+                event,
+                boosted,
+                caller_data,
+                attendee_count,
+                waitlist_count,
+                rsvp_counts,
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Event, UpdateEvent};
+    use crate::models::{
+        date_range::DateRange,
+        privacy::Privacy,
+        recurrence::{RecurrenceFrequency, RecurrenceRule},
+        ticket::TicketPrice,
+    };
+    use candid::Principal;
+
+    fn update_from(event: &Event) -> UpdateEvent {
+        UpdateEvent {
+            name: event.name.clone(),
+            description: event.description.clone(),
+            date: event.date.clone(),
+            privacy: event.privacy.clone(),
+            website: event.website.clone(),
+            location: event.location.clone(),
+            image: event.image.clone(),
+            owner: event.owner,
+            banner_image: event.banner_image.clone(),
+            metadata: event.metadata.clone(),
+            tags: event.tags.clone(),
+            recurrence: event.recurrence.clone(),
+            max_attendees: event.max_attendees,
+            ticket_price: event.ticket_price.clone(),
+            min_group_tenure_days: event.min_group_tenure_days,
+            time_zone: event.time_zone.clone(),
         }
     }
+
+    #[test]
+    fn test_has_capacity() {
+        let mut event = Event::default();
+        assert!(event.has_capacity(u64::MAX));
+
+        event.max_attendees = Some(2);
+        assert!(event.has_capacity(1));
+        assert!(!event.has_capacity(2));
+        assert!(!event.has_capacity(3));
+    }
+
+    #[test]
+    fn test_changes_owner_fields() {
+        let event = Event {
+            date: DateRange::new(100, 200),
+            ..Default::default()
+        };
+
+        let mut update = update_from(&event);
+        update.name = "renamed".to_string();
+        update.description = "new description".to_string();
+        update.max_attendees = Some(10);
+        assert!(!event.changes_owner_fields(&update));
+
+        let mut update = update_from(&event);
+        update.date = DateRange::new(100, 300);
+        assert!(event.changes_owner_fields(&update));
+
+        let mut update = update_from(&event);
+        update.privacy = Privacy::InviteOnly;
+        assert!(event.changes_owner_fields(&update));
+
+        let mut update = update_from(&event);
+        update.time_zone = Some("Europe/Amsterdam".to_string());
+        assert!(event.changes_owner_fields(&update));
+
+        let mut update = update_from(&event);
+        update.ticket_price = Some(TicketPrice {
+            amount_e8s: 1,
+            recipient: Principal::anonymous(),
+        });
+        assert!(event.changes_owner_fields(&update));
+    }
+
+    #[test]
+    fn test_copy_to_date() {
+        let event = Event {
+            name: "meetup".to_string(),
+            date: DateRange::new(1_000, 1_100),
+            created_on: 1,
+            is_canceled: (true, "weather".to_string()),
+            recurrence: Some(RecurrenceRule {
+                frequency: RecurrenceFrequency::Weekly,
+                interval: 1,
+                count: None,
+                until: Some(5_000),
+                exceptions: vec![2_000],
+            }),
+            co_hosts: Some(vec![2]),
+            is_draft: Some(true),
+            ..Default::default()
+        };
+
+        let owner = Principal::from_slice(&[1]);
+        let copy = event.copy_to_date(DateRange::new(3_000, 3_100), owner, 50);
+
+        assert_eq!("meetup", copy.name);
+        assert_eq!(DateRange::new(3_000, 3_100), copy.date);
+        assert_eq!(owner, copy.owner);
+        assert_eq!(owner, copy.created_by);
+        assert_eq!(50, copy.created_on);
+        assert!(!copy.is_canceled.0);
+        assert!(!copy.is_draft());
+        assert!(copy.get_co_hosts().is_empty());
+
+        // the end of the series moves along, skipped occurrences are not copied
+        let recurrence = copy.recurrence.unwrap();
+        assert_eq!(Some(7_000), recurrence.until);
+        assert!(recurrence.exceptions.is_empty());
+    }
+
+    #[test]
+    fn test_continued_occurrence_start() {
+        const DAY: u64 = 86_400_000_000_000;
+        let weekly = |count: Option<u64>| {
+            Some(RecurrenceRule {
+                frequency: RecurrenceFrequency::Weekly,
+                interval: 1,
+                count,
+                until: None,
+                exceptions: vec![],
+            })
+        };
+
+        let series = Event {
+            date: DateRange::new(10 * DAY, 10 * DAY + 100),
+            recurrence: weekly(None),
+            ..Default::default()
+        };
+        let split_start = 24 * DAY;
+
+        // the future occurrences moved a day later
+        let moved = Event {
+            date: DateRange::new(25 * DAY, 25 * DAY + 100),
+            recurrence: weekly(Some(2)),
+            ..Default::default()
+        };
+
+        let continued = |start| moved.continued_occurrence_start(&series, split_start, start);
+        assert_eq!(Some(25 * DAY), continued(24 * DAY));
+        assert_eq!(Some(32 * DAY), continued(31 * DAY));
+        // past the end of the new series
+        assert_eq!(None, continued(38 * DAY));
+        // before the split or not an occurrence of the series
+        assert_eq!(None, continued(17 * DAY));
+        assert_eq!(None, continued(26 * DAY));
+
+        // a new event without recurrence has no occurrences to continue in
+        let single = Event {
+            date: DateRange::new(25 * DAY, 25 * DAY + 100),
+            ..Default::default()
+        };
+        assert_eq!(
+            None,
+            single.continued_occurrence_start(&series, split_start, 24 * DAY)
+        );
+    }
 }
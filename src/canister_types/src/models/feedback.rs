@@ -0,0 +1,142 @@
+use candid::{CandidType, Principal};
+use serde::{Deserialize, Serialize};
+
+use crate::impl_storable_for;
+
+pub const MIN_EVENT_RATING: u8 = 1;
+pub const MAX_EVENT_RATING: u8 = 5;
+
+impl_storable_for!(EventFeedbacks);
+
+#[derive(Clone, Debug, CandidType, Deserialize, Serialize)]
+pub struct EventFeedback {
+    pub principal: Principal,
+    pub rating: u8,
+    pub comment: Option<String>,
+    pub created_on: u64,
+}
+
+#[derive(Clone, Default, Debug, CandidType, Deserialize, Serialize)]
+pub struct EventFeedbacks {
+    pub feedback: Vec<EventFeedback>,
+}
+
+impl EventFeedbacks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn has_feedback(&self, principal: &Principal) -> bool {
+        self.feedback
+            .iter()
+            .any(|feedback| &feedback.principal == principal)
+    }
+
+    pub fn add_feedback(
+        &mut self,
+        principal: Principal,
+        rating: u8,
+        comment: Option<String>,
+        now: u64,
+    ) -> EventFeedback {
+        let feedback = EventFeedback {
+            principal,
+            rating,
+            comment,
+            created_on: now,
+        };
+        self.feedback.push(feedback.clone());
+        feedback
+    }
+
+    pub fn get_ratings_count(&self) -> u64 {
+        self.feedback.len() as u64
+    }
+
+    pub fn get_ratings_total(&self) -> u64 {
+        self.feedback
+            .iter()
+            .map(|feedback| feedback.rating as u64)
+            .sum()
+    }
+}
+
+/// Average of the ratings, `None` if nothing is rated
+pub fn average_rating(total: u64, count: u64) -> Option<f64> {
+    match count {
+        0 => None,
+        _ => Some(total as f64 / count as f64),
+    }
+}
+
+#[derive(Clone, Debug, CandidType, Deserialize, Serialize)]
+pub struct EventFeedbackSummary {
+    pub event_id: u64,
+    pub average_rating: Option<f64>,
+    pub ratings_count: u64,
+    // number of ratings per score, index 0 holds the 1 star ratings
+    pub rating_distribution: Vec<u64>,
+    pub feedback: Vec<EventFeedback>,
+}
+
+impl EventFeedbackSummary {
+    pub fn new(event_id: u64, feedbacks: EventFeedbacks) -> Self {
+        let rating_distribution = (MIN_EVENT_RATING..=MAX_EVENT_RATING)
+            .map(|rating| {
+                feedbacks
+                    .feedback
+                    .iter()
+                    .filter(|feedback| feedback.rating == rating)
+                    .count() as u64
+            })
+            .collect();
+
+        Self {
+            event_id,
+            average_rating: average_rating(
+                feedbacks.get_ratings_total(),
+                feedbacks.get_ratings_count(),
+            ),
+            ratings_count: feedbacks.get_ratings_count(),
+            rating_distribution,
+            feedback: feedbacks.feedback,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{average_rating, EventFeedbackSummary, EventFeedbacks};
+    use candid::Principal;
+
+    #[test]
+    fn test_average_rating() {
+        assert_eq!(None, average_rating(0, 0));
+        assert_eq!(Some(3.5), average_rating(7, 2));
+    }
+
+    #[test]
+    fn test_feedback_summary() {
+        let mut feedbacks = EventFeedbacks::new();
+        assert!(!feedbacks.has_feedback(&Principal::from_slice(&[1])));
+
+        feedbacks.add_feedback(Principal::from_slice(&[1]), 5, None, 10);
+        feedbacks.add_feedback(Principal::from_slice(&[2]), 4, Some("good".to_string()), 10);
+        feedbacks.add_feedback(Principal::from_slice(&[3]), 5, None, 10);
+        feedbacks.add_feedback(Principal::from_slice(&[4]), 1, None, 10);
+        assert!(feedbacks.has_feedback(&Principal::from_slice(&[1])));
+        assert_eq!(15, feedbacks.get_ratings_total());
+        assert_eq!(4, feedbacks.get_ratings_count());
+
+        let summary = EventFeedbackSummary::new(7, feedbacks);
+        assert_eq!(7, summary.event_id);
+        assert_eq!(Some(3.75), summary.average_rating);
+        assert_eq!(4, summary.ratings_count);
+        assert_eq!(vec![1, 0, 0, 1, 2], summary.rating_distribution);
+        assert_eq!(4, summary.feedback.len());
+
+        let empty = EventFeedbackSummary::new(8, EventFeedbacks::new());
+        assert_eq!(None, empty.average_rating);
+        assert_eq!(vec![0; 5], empty.rating_distribution);
+    }
+}
@@ -6,7 +6,7 @@ use serde::Serialize;
 
 use crate::{
     impl_storable_for,
-    misc::role_misc::default_roles,
+    misc::role_misc::{all_access_permission_actions, default_roles, ADMIN_ROLE, OWNER_ROLE},
     models::{
         asset::Asset, date_range::DateRange, location::Location, privacy::Privacy, role::Role,
         sort_direction::SortDirection,
@@ -16,9 +16,10 @@ use crate::{
 use super::{
     api_error::ApiError,
     boosted::Boost,
+    group_moderation::{ModerationKind, ModerationRecord},
     member::{InviteMemberResponse, JoinedMemberResponse},
     member_collection::MemberCollection,
-    permission::Permission,
+    permission::{CustomPermission, Permission},
     relation_type::RelationType,
 };
 
@@ -43,8 +44,15 @@ pub struct Group {
     pub notification_id: Option<u64>,
     pub special_members: HashMap<Principal, String>,
     pub wallets: HashMap<Principal, String>,
+    pub custom_permissions: Option<Vec<CustomPermission>>,
+    pub moderation_records: Option<Vec<ModerationRecord>>,
+    pub max_members: Option<u64>,
     pub updated_on: u64,
     pub created_on: u64,
+    // drafts are only visible to editors of the group
+    pub is_draft: Option<bool>,
+    // the draft is published by a timer at this timestamp
+    pub publish_at: Option<u64>,
 }
 
 impl Default for Group {
@@ -69,6 +77,11 @@ impl Default for Group {
             created_on: Default::default(),
             privacy_gated_type_amount: Default::default(),
             special_members: Default::default(),
+            custom_permissions: Default::default(),
+            moderation_records: Default::default(),
+            max_members: Default::default(),
+            is_draft: Default::default(),
+            publish_at: Default::default(),
         }
     }
 }
@@ -95,6 +108,12 @@ impl Group {
             created_on: time(),
             privacy_gated_type_amount: group.privacy_gated_type_amount,
             special_members: HashMap::default(),
+            custom_permissions: None,
+            moderation_records: None,
+            max_members: group.max_members,
+            is_draft: (group.is_draft.unwrap_or_default() || group.publish_at.is_some())
+                .then_some(true),
+            publish_at: group.publish_at,
         }
     }
 
@@ -108,6 +127,7 @@ impl Group {
         self.banner_image = group.banner_image;
         self.tags = group.tags;
         self.privacy_gated_type_amount = group.privacy_gated_type_amount;
+        self.max_members = group.max_members;
         self.updated_on = time();
     }
 
@@ -123,15 +143,87 @@ impl Group {
         self.clone()
     }
 
+    pub fn is_draft(&self) -> bool {
+        self.is_draft.unwrap_or_default()
+    }
+
+    pub fn publish(&mut self) -> Self {
+        self.is_draft = None;
+        self.publish_at = None;
+        self.updated_on = time();
+        self.clone()
+    }
+
+    /// Keep the group as a draft until `publish_at`
+    pub fn schedule_publish(&mut self, publish_at: u64) -> Self {
+        self.is_draft = Some(true);
+        self.publish_at = Some(publish_at);
+        self.updated_on = time();
+        self.clone()
+    }
+
     pub fn get_roles(&self) -> Vec<Role> {
         // set the default roles
         let mut roles = self.roles.clone();
 
         // append the custom roles stored on the group
-        roles.append(&mut default_roles());
+        roles.append(&mut self.get_default_roles());
+        roles
+    }
+
+    /// Get the default roles, where the owner and admin roles have full access to the custom permissions
+    pub fn get_default_roles(&self) -> Vec<Role> {
+        let mut roles = default_roles();
+
+        for role in roles
+            .iter_mut()
+            .filter(|r| r.name == OWNER_ROLE || r.name == ADMIN_ROLE)
+        {
+            for custom_permission in self.get_custom_permissions() {
+                role.permissions.push(Permission::new(
+                    custom_permission.name,
+                    true,
+                    all_access_permission_actions(),
+                ));
+            }
+        }
+
         roles
     }
 
+    pub fn get_custom_permissions(&self) -> Vec<CustomPermission> {
+        self.custom_permissions.clone().unwrap_or_default()
+    }
+
+    pub fn has_custom_permission(&self, name: &str) -> bool {
+        self.get_custom_permissions().iter().any(|p| p.name == name)
+    }
+
+    pub fn add_custom_permission(&mut self, custom_permission: CustomPermission) {
+        let mut custom_permissions = self.get_custom_permissions();
+        custom_permissions.push(custom_permission);
+        self.custom_permissions = Some(custom_permissions);
+        self.updated_on = time();
+    }
+
+    /// Remove a custom permission from the group and from the roles it is attached to
+    pub fn remove_custom_permission(&mut self, name: &str) -> bool {
+        if !self.has_custom_permission(name) {
+            return false;
+        }
+
+        let mut custom_permissions = self.get_custom_permissions();
+        custom_permissions.retain(|p| p.name != name);
+        self.custom_permissions = Some(custom_permissions);
+
+        for role in self.roles.iter_mut() {
+            role.permissions.retain(|p| p.name() != name);
+        }
+
+        self.updated_on = time();
+        true
+    }
+
     pub fn get_role_permissions(&self, role: String) -> Vec<Permission> {
         let roles = self.get_roles();
         let role = roles.iter().find(|r| r.name == role);
@@ -158,11 +250,83 @@ impl Group {
     }
 
     pub fn is_banned_member(&self, member: Principal) -> bool {
-        self.special_members
+        let is_legacy_banned = self
+            .special_members
             .get(&member)
             .map(|relation| relation == &RelationType::Blocked.to_string())
+            .unwrap_or(false);
+
+        is_legacy_banned || self.has_active_moderation(member, ModerationKind::Ban)
+    }
+
+    /// Check if there is room for a new member, groups without `max_members` have no limit
+    pub fn has_capacity(&self, members_count: u64) -> bool {
+        self.max_members
+            .map(|max_members| members_count < max_members)
+            .unwrap_or(true)
+    }
+
+    pub fn is_muted_member(&self, member: Principal) -> bool {
+        self.has_active_moderation(member, ModerationKind::Mute)
+    }
+
+    fn has_active_moderation(&self, member: Principal, kind: ModerationKind) -> bool {
+        self.moderation_records
+            .as_ref()
+            .map(|records| {
+                records
+                    .iter()
+                    .any(|r| r.principal == member && r.is_active(kind.clone()))
+            })
             .unwrap_or(false)
     }
+
+    /// Returns the moderation records of the given kind, bans stored as special members are included
+    pub fn get_moderation_records(&self, kind: ModerationKind) -> Vec<ModerationRecord> {
+        let mut records: Vec<ModerationRecord> = self
+            .moderation_records
+            .clone()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|r| r.kind == kind)
+            .collect();
+
+        if kind == ModerationKind::Ban {
+            let legacy_bans: Vec<ModerationRecord> = self
+                .special_members
+                .iter()
+                .filter(|(principal, relation)| {
+                    *relation == &RelationType::Blocked.to_string()
+                        && !records.iter().any(|r| &r.principal == *principal)
+                })
+                .map(|(principal, _)| ModerationRecord::legacy_ban(*principal))
+                .collect();
+            records.extend(legacy_bans);
+        }
+
+        records
+    }
+
+    /// A member can only have a single moderation record, a new record replaces the existing one
+    pub fn add_moderation_record(&mut self, record: ModerationRecord) {
+        let records = self.moderation_records.get_or_insert_with(Vec::new);
+        records.retain(|r| r.principal != record.principal);
+        records.push(record);
+        self.updated_on = time();
+    }
+
+    pub fn remove_moderation_record(
+        &mut self,
+        member: Principal,
+        kind: ModerationKind,
+    ) -> Option<ModerationRecord> {
+        let records = self.moderation_records.as_mut()?;
+        let index = records
+            .iter()
+            .position(|r| r.principal == member && r.kind == kind)?;
+        self.updated_on = time();
+        Some(records.remove(index))
+    }
 }
 
 #[derive(Clone, CandidType, Deserialize)]
@@ -177,6 +341,9 @@ pub struct PostGroup {
     pub image: Asset,
     pub banner_image: Asset,
     pub tags: Vec<u32>,
+    pub max_members: Option<u64>,
+    pub is_draft: Option<bool>,
+    pub publish_at: Option<u64>,
 }
 
 #[derive(Clone, CandidType, Deserialize, Debug)]
@@ -190,6 +357,7 @@ pub struct UpdateGroup {
     pub privacy_gated_type_amount: Option<u64>,
     pub banner_image: Asset,
     pub tags: Vec<u32>,
+    pub max_members: Option<u64>,
 }
 
 #[derive(Clone, CandidType, Serialize, Deserialize, Debug)]
@@ -198,6 +366,7 @@ pub struct GroupCallerData {
     pub invite: Option<InviteMemberResponse>,
     pub is_starred: bool,
     pub is_pinned: bool,
+    pub waitlist_position: Option<u64>,
 }
 
 impl GroupCallerData {
@@ -206,12 +375,14 @@ impl GroupCallerData {
         invite: Option<InviteMemberResponse>,
         is_starred: bool,
         is_pinned: bool,
+        waitlist_position: Option<u64>,
     ) -> Self {
         Self {
             joined,
             invite,
             is_starred,
             is_pinned,
+            waitlist_position,
         }
     }
 }
@@ -232,13 +403,18 @@ pub struct GroupResponse {
     pub tags: Vec<u32>,
     pub roles: Vec<Role>,
     pub wallets: Vec<(Principal, String)>,
+    pub custom_permissions: Vec<CustomPermission>,
     pub is_deleted: bool,
     pub privacy_gated_type_amount: Option<u64>,
+    pub max_members: Option<u64>,
     pub updated_on: u64,
     pub created_on: u64,
     pub boosted: Option<Boost>,
     pub events_count: u64,
     pub members_count: u64,
+    pub average_event_rating: Option<f64>,
+    pub is_draft: bool,
+    pub publish_at: Option<u64>,
     pub caller_data: Option<GroupCallerData>,
 }
 
@@ -249,10 +425,12 @@ impl GroupResponse {
         boosted: Option<Boost>,
         events_count: u64,
         members_count: u64,
+        average_event_rating: Option<f64>,
         caller_data: Option<GroupCallerData>,
     ) -> Self {
-        let mut roles = default_roles();
+        let mut roles = group.get_default_roles();
         roles.append(&mut group.roles.clone());
+        let custom_permissions = group.get_custom_permissions();
         Self {
             id,
             name: group.name,
@@ -267,15 +445,20 @@ impl GroupResponse {
             banner_image: group.banner_image,
             tags: group.tags,
             roles,
+            custom_permissions,
             wallets: group.wallets.into_iter().collect(),
             is_deleted: group.is_deleted,
             caller_data,
             privacy_gated_type_amount: group.privacy_gated_type_amount,
+            max_members: group.max_members,
             boosted,
             updated_on: group.updated_on,
             created_on: group.created_on,
             events_count,
             members_count,
+            average_event_rating,
+            is_draft: group.is_draft.unwrap_or_default(),
+            publish_at: group.publish_at,
         }
     }
 
@@ -284,6 +467,7 @@ impl GroupResponse {
         boosted: Option<Boost>,
         events_count: u64,
         members_count: u64,
+        average_event_rating: Option<f64>,
         caller_data: Option<GroupCallerData>,
     ) -> Result<Self, ApiError> {
         match group_result {
@@ -294,6 +478,7 @@ impl GroupResponse {
                 boosted,
                 events_count,
                 members_count,
+                average_event_rating,
                 caller_data,
             )),
         }
@@ -407,3 +592,80 @@ impl GroupFilter {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::Group;
+    use crate::{
+        misc::role_misc::{
+            read_only_permission_actions, ADMIN_ROLE, MEMBER_ROLE, MODERATOR_ROLE, OWNER_ROLE,
+        },
+        models::{
+            permission::{CustomPermission, Permission},
+            role::Role,
+        },
+    };
+    use candid::Principal;
+
+    fn group_with_custom_permission(name: &str) -> Group {
+        Group {
+            custom_permissions: Some(vec![CustomPermission {
+                name: name.to_string(),
+                description: String::new(),
+                created_by: Principal::anonymous(),
+                created_on: 0,
+            }]),
+            roles: vec![Role::new(
+                "vendor".to_string(),
+                false,
+                vec![Permission::new(
+                    name.to_string(),
+                    false,
+                    read_only_permission_actions(),
+                )],
+                "#000000".to_string(),
+                Some(150),
+            )],
+            ..Default::default()
+        }
+    }
+
+    fn has_permission(group: &Group, role: &str, permission: &str) -> bool {
+        group
+            .get_role_permissions(role.to_string())
+            .iter()
+            .any(|p| p.name() == permission)
+    }
+
+    #[test]
+    fn test_custom_permissions() {
+        let group = group_with_custom_permission("sell_tickets");
+
+        assert!(group.has_custom_permission("sell_tickets"));
+        assert!(!group.has_custom_permission("unknown"));
+
+        // the owner and admin have full access to the custom permissions
+        assert!(has_permission(&group, OWNER_ROLE, "sell_tickets"));
+        assert!(has_permission(&group, ADMIN_ROLE, "sell_tickets"));
+        assert!(!has_permission(&group, MODERATOR_ROLE, "sell_tickets"));
+        assert!(!has_permission(&group, MEMBER_ROLE, "sell_tickets"));
+
+        // custom roles only have the custom permissions they are given
+        assert!(has_permission(&group, "vendor", "sell_tickets"));
+        assert!(!Group::default().has_custom_permission("sell_tickets"));
+    }
+
+    #[test]
+    fn test_has_capacity() {
+        let mut group = Group::default();
+        assert!(group.has_capacity(u64::MAX));
+
+        group.max_members = Some(2);
+        assert!(group.has_capacity(1));
+        assert!(!group.has_capacity(2));
+        assert!(!group.has_capacity(3));
+
+        group.max_members = Some(0);
+        assert!(!group.has_capacity(0));
+    }
+}
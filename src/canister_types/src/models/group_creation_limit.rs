@@ -0,0 +1,109 @@
+use candid::{CandidType, Deserialize, Principal};
+use serde::Serialize;
+
+use crate::impl_storable_for;
+
+use super::application_role::ApplicationRole;
+
+impl_storable_for!(GroupCreationLimits);
+
+/// Limits on the number of groups a principal can own
+/// # Note
+/// A principal override takes precedence over the limit of the application role,
+/// roles without a limit fall back to `default_limit`
+#[derive(Clone, Debug, CandidType, Deserialize, Serialize)]
+pub struct GroupCreationLimits {
+    pub default_limit: u64,
+    pub role_limits: Vec<(ApplicationRole, u64)>,
+    pub principal_overrides: Vec<(Principal, u64)>,
+}
+
+impl GroupCreationLimits {
+    pub fn new(default_limit: u64) -> Self {
+        Self {
+            default_limit,
+            role_limits: vec![],
+            principal_overrides: vec![],
+        }
+    }
+
+    pub fn get_limit(&self, principal: &Principal, role: &ApplicationRole) -> u64 {
+        if let Some((_, limit)) = self
+            .principal_overrides
+            .iter()
+            .find(|(p, _)| p == principal)
+        {
+            return *limit;
+        }
+
+        self.role_limits
+            .iter()
+            .find(|(r, _)| r == role)
+            .map(|(_, limit)| *limit)
+            .unwrap_or(self.default_limit)
+    }
+
+    pub fn set_role_limit(&mut self, role: ApplicationRole, limit: Option<u64>) {
+        self.role_limits.retain(|(r, _)| r != &role);
+        if let Some(limit) = limit {
+            self.role_limits.push((role, limit));
+        }
+    }
+
+    pub fn set_principal_override(&mut self, principal: Principal, limit: Option<u64>) {
+        self.principal_overrides.retain(|(p, _)| p != &principal);
+        if let Some(limit) = limit {
+            self.principal_overrides.push((principal, limit));
+        }
+    }
+}
+
+#[derive(Clone, Debug, CandidType, Deserialize, Serialize)]
+pub struct GroupCreationQuota {
+    pub limit: u64,
+    pub usage: u64,
+}
+
+#[cfg(test)]
+mod test {
+    use super::GroupCreationLimits;
+    use crate::models::application_role::ApplicationRole;
+    use candid::Principal;
+
+    #[test]
+    fn test_get_limit() {
+        let principal = Principal::anonymous();
+        let mut limits = GroupCreationLimits::new(10);
+        assert_eq!(10, limits.get_limit(&principal, &ApplicationRole::Member));
+
+        limits.set_role_limit(ApplicationRole::Member, Some(3));
+        assert_eq!(3, limits.get_limit(&principal, &ApplicationRole::Member));
+        assert_eq!(10, limits.get_limit(&principal, &ApplicationRole::Leader));
+
+        // the principal override takes precedence over the role limit
+        limits.set_principal_override(principal, Some(25));
+        assert_eq!(25, limits.get_limit(&principal, &ApplicationRole::Member));
+        assert_eq!(
+            3,
+            limits.get_limit(&Principal::management_canister(), &ApplicationRole::Member)
+        );
+    }
+
+    #[test]
+    fn test_clear_limits() {
+        let principal = Principal::anonymous();
+        let mut limits = GroupCreationLimits::new(10);
+
+        limits.set_role_limit(ApplicationRole::Member, Some(3));
+        limits.set_role_limit(ApplicationRole::Member, Some(5));
+        assert_eq!(1, limits.role_limits.len());
+        assert_eq!(5, limits.get_limit(&principal, &ApplicationRole::Member));
+
+        limits.set_principal_override(principal, Some(0));
+        assert_eq!(0, limits.get_limit(&principal, &ApplicationRole::Member));
+
+        limits.set_principal_override(principal, None);
+        limits.set_role_limit(ApplicationRole::Member, None);
+        assert_eq!(10, limits.get_limit(&principal, &ApplicationRole::Member));
+    }
+}
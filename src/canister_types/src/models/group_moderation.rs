@@ -0,0 +1,102 @@
+use candid::{CandidType, Principal};
+use ic_cdk::{api::time, caller};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, CandidType, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub enum ModerationKind {
+    Ban,
+    Mute,
+}
+
+#[derive(Clone, CandidType, Serialize, Deserialize, Debug)]
+pub struct ModerationRecord {
+    pub principal: Principal,
+    pub kind: ModerationKind,
+    pub reason: String,
+    pub issued_by: Principal,
+    pub created_at: u64,
+    pub expires_at: Option<u64>,
+}
+
+impl ModerationRecord {
+    pub fn new(
+        principal: Principal,
+        kind: ModerationKind,
+        reason: String,
+        expires_at: Option<u64>,
+    ) -> Self {
+        Self {
+            principal,
+            kind,
+            reason,
+            issued_by: caller(),
+            created_at: time(),
+            expires_at,
+        }
+    }
+
+    /// Bans that were stored as a special member before moderation records existed
+    pub fn legacy_ban(principal: Principal) -> Self {
+        Self {
+            principal,
+            kind: ModerationKind::Ban,
+            reason: Default::default(),
+            issued_by: Principal::anonymous(),
+            created_at: 0,
+            expires_at: None,
+        }
+    }
+
+    /// Records without an expiry are permanent
+    pub fn is_expired(&self) -> bool {
+        self.is_expired_at(time())
+    }
+
+    pub fn is_expired_at(&self, now: u64) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+
+    pub fn is_active(&self, kind: ModerationKind) -> bool {
+        self.is_active_at(kind, time())
+    }
+
+    pub fn is_active_at(&self, kind: ModerationKind, now: u64) -> bool {
+        self.kind == kind && !self.is_expired_at(now)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ModerationKind, ModerationRecord};
+    use candid::Principal;
+
+    fn record(kind: ModerationKind, expires_at: Option<u64>) -> ModerationRecord {
+        ModerationRecord {
+            expires_at,
+            kind,
+            ..ModerationRecord::legacy_ban(Principal::anonymous())
+        }
+    }
+
+    #[test]
+    fn test_timed_records() {
+        let mute = record(ModerationKind::Mute, Some(100));
+
+        assert!(mute.is_active_at(ModerationKind::Mute, 99));
+        assert!(!mute.is_active_at(ModerationKind::Mute, 100));
+        assert!(mute.is_expired_at(100));
+
+        // a mute is not a ban
+        assert!(!mute.is_active_at(ModerationKind::Ban, 99));
+    }
+
+    #[test]
+    fn test_permanent_records() {
+        let ban = record(ModerationKind::Ban, None);
+        assert!(ban.is_active_at(ModerationKind::Ban, u64::MAX));
+        assert!(!ban.is_expired_at(u64::MAX));
+
+        let legacy_ban = ModerationRecord::legacy_ban(Principal::anonymous());
+        assert!(legacy_ban.is_active_at(ModerationKind::Ban, u64::MAX));
+    }
+}
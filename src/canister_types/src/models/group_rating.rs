@@ -0,0 +1,54 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+
+use crate::impl_storable_for;
+
+use super::feedback::average_rating;
+
+impl_storable_for!(GroupRating);
+
+/// Running totals of the feedback ratings given on the events of a group
+#[derive(Clone, Default, Debug, CandidType, Deserialize, Serialize)]
+pub struct GroupRating {
+    pub ratings_total: u64,
+    pub ratings_count: u64,
+}
+
+impl GroupRating {
+    pub fn add(&mut self, ratings_total: u64, ratings_count: u64) {
+        self.ratings_total += ratings_total;
+        self.ratings_count += ratings_count;
+    }
+
+    pub fn subtract(&mut self, ratings_total: u64, ratings_count: u64) {
+        self.ratings_total = self.ratings_total.saturating_sub(ratings_total);
+        self.ratings_count = self.ratings_count.saturating_sub(ratings_count);
+    }
+
+    pub fn get_average_rating(&self) -> Option<f64> {
+        average_rating(self.ratings_total, self.ratings_count)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::GroupRating;
+
+    #[test]
+    fn test_running_average() {
+        let mut rating = GroupRating::default();
+        assert_eq!(None, rating.get_average_rating());
+
+        rating.add(5, 1);
+        rating.add(7, 2);
+        assert_eq!(Some(4.0), rating.get_average_rating());
+
+        // an event with two ratings leaves the group
+        rating.subtract(7, 2);
+        assert_eq!(Some(5.0), rating.get_average_rating());
+
+        rating.subtract(10, 2);
+        assert_eq!(0, rating.ratings_total);
+        assert_eq!(None, rating.get_average_rating());
+    }
+}
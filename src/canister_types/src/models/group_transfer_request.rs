@@ -10,15 +10,83 @@ impl_storable_for!(GroupTransferRequest);
 pub struct GroupTransferRequest {
     pub from: Principal,
     pub to: Principal,
+    pub notification_id: Option<u64>,
+    pub expires_at: Option<u64>,
     pub created_on: u64,
 }
 
 impl GroupTransferRequest {
-    pub fn new(from: Principal, to: Principal) -> Self {
+    pub fn new(from: Principal, to: Principal, ttl: u64) -> Self {
         Self {
             from,
             to,
+            notification_id: None,
+            expires_at: Some(time() + ttl),
             created_on: time(),
         }
     }
+
+    pub fn set_notification_id(&mut self, notification_id: u64) {
+        self.notification_id = Some(notification_id);
+    }
+
+    pub fn remove_notification_id(&mut self) {
+        self.notification_id = None;
+    }
+
+    /// Requests created before the TTL was introduced have no expiry and never expire
+    pub fn is_expired(&self) -> bool {
+        self.is_expired_at(time())
+    }
+
+    pub fn is_expired_at(&self, now: u64) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
+#[derive(Clone, CandidType, Serialize, Deserialize, Debug)]
+pub struct GroupTransferRequestResponse {
+    pub group_id: u64,
+    pub from: Principal,
+    pub to: Principal,
+    pub expires_at: Option<u64>,
+    pub created_on: u64,
+}
+
+impl GroupTransferRequestResponse {
+    pub fn new(group_id: u64, request: GroupTransferRequest) -> Self {
+        Self {
+            group_id,
+            from: request.from,
+            to: request.to,
+            expires_at: request.expires_at,
+            created_on: request.created_on,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::GroupTransferRequest;
+    use candid::Principal;
+
+    fn request(expires_at: Option<u64>) -> GroupTransferRequest {
+        GroupTransferRequest {
+            from: Principal::anonymous(),
+            to: Principal::management_canister(),
+            notification_id: None,
+            expires_at,
+            created_on: 0,
+        }
+    }
+
+    #[test]
+    fn test_is_expired_at() {
+        assert!(!request(Some(100)).is_expired_at(99));
+        assert!(request(Some(100)).is_expired_at(100));
+        assert!(request(Some(100)).is_expired_at(101));
+
+        // requests from before the expiry was introduced never expire
+        assert!(!request(None).is_expired_at(u64::MAX));
+    }
 }
@@ -18,3 +18,10 @@ pub struct HttpResponse {
     pub headers: Vec<HeaderField>,
     pub body: Vec<u8>,
 }
+
+#[derive(CandidType, Clone, Deserialize, Debug)]
+pub struct ExportTokenResponse {
+    pub token: String,
+    pub path: String,
+    pub expires_at: u64,
+}
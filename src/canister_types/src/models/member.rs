@@ -195,6 +195,8 @@ pub struct JoinedMemberResponse {
     pub group_id: u64,
     pub principal: Principal,
     pub roles: Vec<String>,
+    // set when the group was full and the principal is put on the waitlist instead
+    pub waitlist_position: Option<u64>,
 }
 
 impl JoinedMemberResponse {
@@ -203,6 +205,14 @@ impl JoinedMemberResponse {
             group_id,
             principal,
             roles: member.get_roles(group_id),
+            waitlist_position: None,
+        }
+    }
+
+    pub fn waitlisted(principal: Principal, member: Member, group_id: u64, position: u64) -> Self {
+        Self {
+            waitlist_position: Some(position),
+            ..Self::new(principal, member, group_id)
         }
     }
 }
@@ -9,6 +9,7 @@ impl_storable_for!(MemberCollection);
 pub struct MemberCollection {
     members: Vec<Principal>,
     invites: Vec<Principal>,
+    waitlist: Option<Vec<Principal>>,
 }
 
 impl MemberCollection {
@@ -60,8 +61,97 @@ impl MemberCollection {
         self.invites.retain(|p| p != principal);
     }
 
+    pub fn get_waitlist_principals(&self) -> Vec<Principal> {
+        self.waitlist.clone().unwrap_or_default()
+    }
+
+    /// Returns the 1-based position on the waitlist
+    pub fn get_waitlist_position(&self, principal: &Principal) -> Option<u64> {
+        self.waitlist
+            .as_ref()?
+            .iter()
+            .position(|p| p == principal)
+            .map(|index| index as u64 + 1)
+    }
+
+    pub fn add_to_waitlist(&mut self, principal: Principal) -> u64 {
+        let waitlist = self.waitlist.get_or_insert_with(Vec::new);
+        if !waitlist.contains(&principal) {
+            waitlist.push(principal);
+        }
+        self.get_waitlist_position(&principal).unwrap_or_default()
+    }
+
+    pub fn remove_from_waitlist(&mut self, principal: &Principal) {
+        if let Some(waitlist) = self.waitlist.as_mut() {
+            waitlist.retain(|p| p != principal);
+        }
+    }
+
+    /// Removes and returns the first principal on the waitlist
+    pub fn pop_waitlist(&mut self) -> Option<Principal> {
+        let waitlist = self.waitlist.as_mut()?;
+        if waitlist.is_empty() {
+            return None;
+        }
+        Some(waitlist.remove(0))
+    }
+
     pub fn create_member_from_invite(&mut self, principal: Principal) {
         self.remove_invite(&principal);
         self.add_member(principal);
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::MemberCollection;
+    use candid::Principal;
+
+    #[test]
+    fn test_waitlist_position() {
+        let mut members = MemberCollection::new();
+        assert_eq!(
+            None,
+            members.get_waitlist_position(&Principal::from_slice(&[1]))
+        );
+
+        assert_eq!(1, members.add_to_waitlist(Principal::from_slice(&[1])));
+        assert_eq!(2, members.add_to_waitlist(Principal::from_slice(&[2])));
+        // adding a principal twice keeps its position
+        assert_eq!(1, members.add_to_waitlist(Principal::from_slice(&[1])));
+        assert_eq!(
+            vec![Principal::from_slice(&[1]), Principal::from_slice(&[2])],
+            members.get_waitlist_principals()
+        );
+
+        members.remove_from_waitlist(&Principal::from_slice(&[1]));
+        assert_eq!(
+            None,
+            members.get_waitlist_position(&Principal::from_slice(&[1]))
+        );
+        assert_eq!(
+            Some(1),
+            members.get_waitlist_position(&Principal::from_slice(&[2]))
+        );
+    }
+
+    #[test]
+    fn test_pop_waitlist_in_order() {
+        let mut members = MemberCollection::new();
+        assert_eq!(None, members.pop_waitlist());
+
+        members.add_to_waitlist(Principal::from_slice(&[1]));
+        members.add_to_waitlist(Principal::from_slice(&[2]));
+        members.add_to_waitlist(Principal::from_slice(&[3]));
+
+        assert_eq!(Some(Principal::from_slice(&[1])), members.pop_waitlist());
+        assert_eq!(Some(Principal::from_slice(&[2])), members.pop_waitlist());
+        assert_eq!(
+            Some(1),
+            members.get_waitlist_position(&Principal::from_slice(&[3]))
+        );
+        assert_eq!(Some(Principal::from_slice(&[3])), members.pop_waitlist());
+        assert_eq!(None, members.pop_waitlist());
+    }
+}
@@ -1,16 +1,26 @@
 pub mod api_error;
 pub mod application_role;
 pub mod asset;
+pub mod audit_log;
+pub mod audit_log_collection;
 pub mod boosted;
+pub mod calendar_feed;
+pub mod check_in;
 pub mod date_range;
+pub mod feedback;
 pub mod filter_type;
+pub mod group_creation_limit;
+pub mod group_moderation;
+pub mod group_rating;
 pub mod group_transfer_request;
 pub mod location;
 pub mod notification;
 pub mod paged_response;
 pub mod privacy;
+pub mod recurrence;
 pub mod referral;
 pub mod role;
+pub mod session;
 pub mod sort_direction;
 pub mod storage;
 pub mod validation;
@@ -40,8 +50,11 @@ pub mod http_types;
 pub mod member_collection;
 pub mod neuron;
 pub mod subject;
+pub mod ticket;
+pub mod time_zone;
 pub mod transaction_data;
 pub mod user_notifications;
+pub mod waitlist;
 pub mod websocket_message;
 
 pub mod log;
@@ -8,7 +8,9 @@ use crate::impl_storable_for;
 
 use super::{
     attendee::{InviteAttendeeResponse, JoinedAttendeeResponse},
+    date_range::DateRange,
     friend_request::FriendRequestResponse,
+    group_transfer_request::GroupTransferRequestResponse,
     member::{InviteMemberResponse, JoinedMemberResponse},
     transaction_data::{TransactionCompleteData, TransactionData},
     user_notifications::UserNotificationData,
@@ -116,7 +118,19 @@ pub enum GroupNotificationType {
 
     UserLeaveGroup(u64),
     UserJoinGroup(u64),
+    // not sent, event reminders go to the attendees as `EventNotificationType::EventReminder`
     GroupReminder(u64),
+    // a seat freed up and the user was moved from the waitlist into the group
+    WaitlistPromoted(JoinedMemberResponse),
+    // a draft group was published
+    GroupPublished(u64),
+
+    // group owner wants to transfer the ownership to a member
+    TransferOwnershipRequest(GroupTransferRequestResponse),
+    TransferOwnershipRequestAccept(GroupTransferRequestResponse),
+    TransferOwnershipRequestDecline(GroupTransferRequestResponse),
+    TransferOwnershipRequestCancel(GroupTransferRequestResponse),
+    TransferOwnershipRequestExpired(GroupTransferRequestResponse),
 }
 
 #[derive(CandidType, Deserialize, Serialize, Clone, Debug)]
@@ -137,7 +151,49 @@ pub enum EventNotificationType {
 
     UserJoinEvent((u64, u64)),
     UserLeaveEvent((u64, u64)),
-    EventReminder(u64),
+    EventReminder(EventReminderResponse),
+
+    // a seat freed up and the user was moved from the waitlist into the event
+    WaitlistPromoted(JoinedAttendeeResponse),
+
+    // (group id, event id) the group is invited to co-host the event
+    CoHostInvite((u64, u64)),
+    // (group id, event id) a draft event was published
+    EventPublished((u64, u64)),
+
+    EventCanceled(EventCanceledResponse),
+    EventRescheduled(EventRescheduledResponse),
+    EventTransferred(EventTransferredResponse),
+}
+
+#[derive(CandidType, Deserialize, Serialize, Clone, Debug)]
+pub struct EventReminderResponse {
+    pub event_id: u64,
+    pub start_date: u64,
+    // ISO 8601 start in the event time zone, when the event has one
+    pub local_start: Option<String>,
+}
+
+#[derive(CandidType, Deserialize, Serialize, Clone, Debug)]
+pub struct EventCanceledResponse {
+    pub event_id: u64,
+    pub group_id: u64,
+    pub reason: String,
+}
+
+#[derive(CandidType, Deserialize, Serialize, Clone, Debug)]
+pub struct EventTransferredResponse {
+    pub event_id: u64,
+    pub old_group_id: u64,
+    pub new_group_id: u64,
+}
+
+#[derive(CandidType, Deserialize, Serialize, Clone, Debug)]
+pub struct EventRescheduledResponse {
+    pub event_id: u64,
+    pub group_id: u64,
+    pub old_date: DateRange,
+    pub new_date: DateRange,
 }
 
 #[derive(CandidType, Deserialize, Serialize, Clone, Debug)]
@@ -48,4 +48,34 @@ impl<T: Clone> PagedResponse<T> {
             data: data[start_limit..end_limit].to_vec(),
         }
     }
+
+    /// Convert the data of the page, so expensive conversions only run for the returned entries
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> PagedResponse<U> {
+        PagedResponse {
+            page: self.page,
+            limit: self.limit,
+            total: self.total,
+            number_of_pages: self.number_of_pages,
+            data: self.data.into_iter().map(f).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::PagedResponse;
+
+    #[test]
+    fn test_map_only_converts_the_page() {
+        let mut converted = vec![];
+        let response = PagedResponse::new(2, 2, vec![1, 2, 3, 4, 5]).map(|value| {
+            converted.push(value);
+            value.to_string()
+        });
+
+        assert_eq!(vec![3, 4], converted);
+        assert_eq!(vec!["3".to_string(), "4".to_string()], response.data);
+        assert_eq!(5, response.total);
+        assert_eq!(3, response.number_of_pages);
+    }
 }
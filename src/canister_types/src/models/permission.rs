@@ -1,6 +1,7 @@
 use std::fmt;
 
-use candid::{CandidType, Deserialize};
+use candid::{CandidType, Deserialize, Principal};
+use ic_cdk::{api::time, caller};
 use serde::Serialize;
 
 #[derive(Clone, CandidType, Serialize, Deserialize, Debug, Default)]
@@ -38,6 +39,26 @@ impl Permission {
     }
 }
 
+/// A permission defined by a group itself, for example to be used by bots or other canisters
+#[derive(Clone, CandidType, Serialize, Deserialize, Debug)]
+pub struct CustomPermission {
+    pub name: String,
+    pub description: String,
+    pub created_by: Principal,
+    pub created_on: u64,
+}
+
+impl CustomPermission {
+    pub fn new(name: String, description: String) -> Self {
+        Self {
+            name,
+            description,
+            created_by: caller(),
+            created_on: time(),
+        }
+    }
+}
+
 #[derive(Clone, CandidType, Serialize, Deserialize, Debug)]
 pub struct PostPermission {
     name: String,
@@ -0,0 +1,382 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+
+use super::{date_range::DateRange, time_zone::TimeZone};
+
+/// Upper bound on the number of occurrences a single rule is expanded into
+pub const MAX_RECURRENCE_OCCURRENCES: u64 = 1000;
+
+const NANOS_PER_DAY: u64 = 24 * 60 * 60 * 1_000_000_000;
+
+#[derive(Clone, Debug, PartialEq, Eq, CandidType, Serialize, Deserialize)]
+pub enum RecurrenceFrequency {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, CandidType, Serialize, Deserialize)]
+pub struct RecurrenceRule {
+    pub frequency: RecurrenceFrequency,
+    // repeat every `interval` days, weeks or months
+    pub interval: u64,
+    // stop after this many occurrences (including skipped exceptions)
+    pub count: Option<u64>,
+    // no occurrence starts after this timestamp
+    pub until: Option<u64>,
+    // start dates of occurrences that are skipped
+    pub exceptions: Vec<u64>,
+}
+
+impl RecurrenceRule {
+    /// Start date of the occurrence at `index`, where index 0 is the start of the series
+    /// # Note
+    /// With a time zone the occurrences keep the local time of the first start across daylight saving time changes
+    pub fn nth_start(
+        &self,
+        first_start: u64,
+        index: u64,
+        time_zone: Option<&TimeZone>,
+    ) -> Option<u64> {
+        let first_start = time_zone.map_or(first_start, |zone| zone.to_local(first_start));
+        let steps = index.checked_mul(self.interval.max(1))?;
+        let start = match self.frequency {
+            RecurrenceFrequency::Daily => {
+                first_start.checked_add(steps.checked_mul(NANOS_PER_DAY)?)
+            }
+            RecurrenceFrequency::Weekly => {
+                first_start.checked_add(steps.checked_mul(7 * NANOS_PER_DAY)?)
+            }
+            RecurrenceFrequency::Monthly => add_months(first_start, steps),
+        }?;
+        Some(time_zone.map_or(start, |zone| zone.to_utc(start)))
+    }
+
+    /// Index of the occurrence starting at `start`, ignoring exceptions
+    pub fn index_of(
+        &self,
+        first_start: u64,
+        start: u64,
+        time_zone: Option<&TimeZone>,
+    ) -> Option<u64> {
+        for index in 0..self.max_index() {
+            let occurrence_start = self.nth_start(first_start, index, time_zone)?;
+            if self.until.is_some_and(|until| occurrence_start > until) {
+                return None;
+            }
+            if occurrence_start == start {
+                return Some(index);
+            }
+            if occurrence_start > start {
+                return None;
+            }
+        }
+        None
+    }
+
+    pub fn is_occurrence(
+        &self,
+        first_start: u64,
+        start: u64,
+        time_zone: Option<&TimeZone>,
+    ) -> bool {
+        !self.exceptions.contains(&start) && self.index_of(first_start, start, time_zone).is_some()
+    }
+
+    /// All occurrences of the series starting within the window, an `end_date` of 0 means no upper bound
+    pub fn occurrences(
+        &self,
+        date: &DateRange,
+        window: &DateRange,
+        time_zone: Option<&TimeZone>,
+    ) -> Vec<DateRange> {
+        let duration = date.end_date().saturating_sub(date.start_date());
+        let mut occurrences = vec![];
+
+        for index in 0..self.max_index() {
+            let Some(start) = self.nth_start(date.start_date(), index, time_zone) else {
+                break;
+            };
+
+            if self.until.is_some_and(|until| start > until) {
+                break;
+            }
+
+            if window.end_date() != 0 && start > window.end_date() {
+                break;
+            }
+
+            if window.is_within(start) && !self.exceptions.contains(&start) {
+                occurrences.push(DateRange::new(start, start.saturating_add(duration)));
+            }
+        }
+
+        occurrences
+    }
+
+    pub fn add_exception(&mut self, start: u64) {
+        if !self.exceptions.contains(&start) {
+            self.exceptions.push(start);
+        }
+    }
+
+    fn max_index(&self) -> u64 {
+        self.count
+            .unwrap_or(MAX_RECURRENCE_OCCURRENCES)
+            .min(MAX_RECURRENCE_OCCURRENCES)
+    }
+}
+
+// Adds calendar months to a timestamp, clamping the day to the length of the target month
+fn add_months(timestamp: u64, months: u64) -> Option<u64> {
+    let days = timestamp / NANOS_PER_DAY;
+    let time_of_day = timestamp % NANOS_PER_DAY;
+
+    let (year, month, day) = civil_from_days(days);
+    let total_months = (month as u64 - 1).checked_add(months)?;
+    let year = year.checked_add(total_months / 12)?;
+    let month = (total_months % 12) as u32 + 1;
+    let day = day.min(days_in_month(year, month));
+
+    days_from_civil(year, month, day)
+        .checked_mul(NANOS_PER_DAY)?
+        .checked_add(time_of_day)
+}
+
+fn is_leap_year(year: u64) -> bool {
+    (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
+}
+
+fn days_in_month(year: u64, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// Days since 1970-01-01 to (year, month, day), see http://howardhinnant.github.io/date_algorithms.html
+pub fn civil_from_days(days: u64) -> (u64, u32, u32) {
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    (year, month, day)
+}
+
+pub fn days_from_civil(year: u64, month: u32, day: u32) -> u64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year / 400;
+    let yoe = year - era * 400;
+    let month = month as u64;
+    let mp = if month > 2 { month - 3 } else { month + 9 };
+    let doy = (153 * mp + 2) / 5 + day as u64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        add_months, civil_from_days, days_from_civil, RecurrenceFrequency, RecurrenceRule,
+        MAX_RECURRENCE_OCCURRENCES, NANOS_PER_DAY,
+    };
+    use crate::models::date_range::DateRange;
+
+    const NANOS_PER_HOUR: u64 = NANOS_PER_DAY / 24;
+
+    fn date(year: u64, month: u32, day: u32) -> u64 {
+        days_from_civil(year, month, day) * NANOS_PER_DAY
+    }
+
+    fn rule(frequency: RecurrenceFrequency, interval: u64) -> RecurrenceRule {
+        RecurrenceRule {
+            frequency,
+            interval,
+            count: None,
+            until: None,
+            exceptions: vec![],
+        }
+    }
+
+    fn starts(rule: &RecurrenceRule, first_start: u64, window: &DateRange) -> Vec<u64> {
+        rule.occurrences(
+            &DateRange::new(first_start, first_start + NANOS_PER_HOUR),
+            window,
+            None,
+        )
+        .iter()
+        .map(|occurrence| occurrence.start_date())
+        .collect()
+    }
+
+    #[test]
+    fn test_civil_days() {
+        assert_eq!(0, days_from_civil(1970, 1, 1));
+        assert_eq!((1970, 1, 1), civil_from_days(0));
+        assert_eq!(11_016, days_from_civil(2000, 2, 29));
+        assert_eq!((2000, 2, 29), civil_from_days(11_016));
+        assert_eq!((2000, 3, 1), civil_from_days(11_017));
+
+        for days in (0..200_000).step_by(7) {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days, days_from_civil(year, month, day));
+        }
+    }
+
+    #[test]
+    fn test_add_months_clamps_to_month_end() {
+        let time_of_day = 18 * NANOS_PER_HOUR;
+
+        assert_eq!(
+            Some(date(2025, 2, 28) + time_of_day),
+            add_months(date(2025, 1, 31) + time_of_day, 1)
+        );
+        assert_eq!(Some(date(2025, 4, 30)), add_months(date(2025, 1, 31), 3));
+        assert_eq!(Some(date(2025, 3, 31)), add_months(date(2025, 1, 31), 2));
+        assert_eq!(Some(date(2026, 1, 15)), add_months(date(2025, 12, 15), 1));
+        assert_eq!(Some(date(2027, 2, 15)), add_months(date(2025, 2, 15), 24));
+    }
+
+    #[test]
+    fn test_add_months_leap_years() {
+        assert_eq!(Some(date(2024, 2, 29)), add_months(date(2024, 1, 31), 1));
+        assert_eq!(Some(date(2000, 2, 29)), add_months(date(2000, 1, 31), 1));
+        assert_eq!(Some(date(2100, 2, 28)), add_months(date(2100, 1, 31), 1));
+        assert_eq!(Some(date(2025, 2, 28)), add_months(date(2024, 2, 29), 12));
+    }
+
+    #[test]
+    fn test_monthly_keeps_the_first_day() {
+        // clamping a single occurrence doesn't move the following ones
+        let first_start = date(2025, 1, 31);
+        let rule = rule(RecurrenceFrequency::Monthly, 1);
+
+        assert_eq!(
+            Some(date(2025, 2, 28)),
+            rule.nth_start(first_start, 1, None)
+        );
+        assert_eq!(
+            Some(date(2025, 3, 31)),
+            rule.nth_start(first_start, 2, None)
+        );
+        assert_eq!(Some(2), rule.index_of(first_start, date(2025, 3, 31), None));
+        assert_eq!(None, rule.index_of(first_start, date(2025, 3, 28), None));
+    }
+
+    #[test]
+    fn test_interval() {
+        let first_start = date(2025, 1, 1) + 9 * NANOS_PER_HOUR;
+
+        let daily = rule(RecurrenceFrequency::Daily, 2);
+        assert_eq!(
+            Some(first_start + 6 * NANOS_PER_DAY),
+            daily.nth_start(first_start, 3, None)
+        );
+
+        let weekly = rule(RecurrenceFrequency::Weekly, 3);
+        assert_eq!(
+            Some(first_start + 42 * NANOS_PER_DAY),
+            weekly.nth_start(first_start, 2, None)
+        );
+        assert!(weekly.is_occurrence(first_start, first_start + 21 * NANOS_PER_DAY, None));
+        assert!(!weekly.is_occurrence(first_start, first_start + 7 * NANOS_PER_DAY, None));
+
+        let monthly = rule(RecurrenceFrequency::Monthly, 2);
+        assert_eq!(
+            Some(date(2025, 7, 1) + 9 * NANOS_PER_HOUR),
+            monthly.nth_start(first_start, 3, None)
+        );
+    }
+
+    #[test]
+    fn test_count_until_and_exceptions() {
+        let first_start = date(2025, 3, 1);
+        let window = DateRange::new(0, 0);
+
+        let mut count = rule(RecurrenceFrequency::Daily, 1);
+        count.count = Some(3);
+        assert_eq!(
+            vec![
+                first_start,
+                first_start + NANOS_PER_DAY,
+                first_start + 2 * NANOS_PER_DAY
+            ],
+            starts(&count, first_start, &window)
+        );
+
+        // skipped exceptions still count towards `count`
+        count.add_exception(first_start + NANOS_PER_DAY);
+        count.add_exception(first_start + NANOS_PER_DAY);
+        assert_eq!(1, count.exceptions.len());
+        assert_eq!(
+            vec![first_start, first_start + 2 * NANOS_PER_DAY],
+            starts(&count, first_start, &window)
+        );
+        assert!(!count.is_occurrence(first_start, first_start + NANOS_PER_DAY, None));
+        assert!(!count.is_occurrence(first_start, first_start + 3 * NANOS_PER_DAY, None));
+
+        // `until` is inclusive
+        let mut until = rule(RecurrenceFrequency::Weekly, 1);
+        until.until = Some(first_start + 14 * NANOS_PER_DAY);
+        assert_eq!(3, starts(&until, first_start, &window).len());
+        assert_eq!(
+            None,
+            until.index_of(first_start, first_start + 21 * NANOS_PER_DAY, None)
+        );
+    }
+
+    #[test]
+    fn test_occurrences_window() {
+        let first_start = date(2025, 3, 1);
+        let rule = rule(RecurrenceFrequency::Daily, 1);
+        let window = DateRange::new(
+            first_start + 10 * NANOS_PER_DAY,
+            first_start + 12 * NANOS_PER_DAY,
+        );
+
+        let occurrences = rule.occurrences(
+            &DateRange::new(first_start, first_start + NANOS_PER_HOUR),
+            &window,
+            None,
+        );
+        assert_eq!(3, occurrences.len());
+        assert_eq!(window.start_date(), occurrences[0].start_date());
+        assert_eq!(
+            window.start_date() + NANOS_PER_HOUR,
+            occurrences[0].end_date()
+        );
+    }
+
+    #[test]
+    fn test_max_occurrences() {
+        let first_start = date(2025, 3, 1);
+        let window = DateRange::new(0, 0);
+
+        let mut rule = rule(RecurrenceFrequency::Daily, 1);
+        assert_eq!(
+            MAX_RECURRENCE_OCCURRENCES as usize,
+            starts(&rule, first_start, &window).len()
+        );
+
+        rule.count = Some(5000);
+        assert_eq!(
+            MAX_RECURRENCE_OCCURRENCES as usize,
+            starts(&rule, first_start, &window).len()
+        );
+        assert_eq!(
+            None,
+            rule.index_of(
+                first_start,
+                first_start + MAX_RECURRENCE_OCCURRENCES * NANOS_PER_DAY,
+                None
+            )
+        );
+    }
+}
@@ -58,6 +58,8 @@ pub enum Activity {
     UserReferral(Principal),
     UserProfileFilled(Principal),
     FirstGroupJoined(Principal),
+    // (event id, attendee) for a verified check-in
+    EventAttendance((u64, Principal)),
 }
 
 impl Activity {
@@ -78,6 +80,7 @@ impl fmt::Display for Activity {
             Activity::UserReferral(_) => write!(f, "user_referral"),
             Activity::UserProfileFilled(_) => write!(f, "user_profile_filled"),
             Activity::FirstGroupJoined(_) => write!(f, "first_group_joined"),
+            Activity::EventAttendance(_) => write!(f, "event_attendance"),
         }
     }
 }
@@ -99,6 +102,23 @@ impl GroupReward {
     }
 }
 
+#[derive(Deserialize, CandidType, Clone)]
+pub struct EventAttendanceReward {
+    pub attendee: Principal,
+    pub event_id: u64,
+    pub group_id: u64,
+}
+
+impl EventAttendanceReward {
+    pub fn new(attendee: Principal, event_id: u64, group_id: u64) -> Self {
+        Self {
+            attendee,
+            event_id,
+            group_id,
+        }
+    }
+}
+
 #[derive(Deserialize, CandidType, Clone)]
 pub struct UserActivity {
     pub owner: Principal,
@@ -117,4 +137,5 @@ pub struct RewardDataPackage {
     pub user_referrals: Vec<Principal>,
     pub filled_profiles: Vec<Principal>,
     pub first_group_joined: Vec<Principal>,
+    pub event_attendances: Vec<EventAttendanceReward>,
 }
@@ -1,6 +1,8 @@
 use candid::{CandidType, Deserialize};
 use serde::Serialize;
 
+use crate::misc::role_misc::{MEMBER_ROLE_INDEX, MODERATOR_ROLE_INDEX};
+
 use super::permission::{Permission, PostPermission};
 
 #[derive(Clone, CandidType, Serialize, Deserialize, Debug, Default)]
@@ -52,6 +54,34 @@ impl Role {
     pub fn permissions(&self) -> Vec<Permission> {
         self.permissions.clone()
     }
+
+    /// The rank of the role within the group, roles without an index have the lowest rank
+    pub fn rank(&self) -> u64 {
+        self.index.unwrap_or_default()
+    }
+
+    /// Custom roles created before the role hierarchy used small indices, which now rank below
+    /// the member role. These are moved in between the member and moderator role, keeping their order.
+    /// Returns `true` if the index was changed
+    pub fn remap_legacy_index(&mut self) -> bool {
+        if self.protected || self.rank() >= MEMBER_ROLE_INDEX {
+            return false;
+        }
+
+        let max_offset = MODERATOR_ROLE_INDEX - MEMBER_ROLE_INDEX - 1;
+        self.index = Some(MEMBER_ROLE_INDEX + (self.rank() + 1).min(max_offset));
+        true
+    }
+}
+
+/// The rank of the highest role out of `role_names`, `0` if none of the roles are known
+pub fn highest_rank(roles: &[Role], role_names: &[String]) -> u64 {
+    roles
+        .iter()
+        .filter(|role| role_names.contains(&role.name))
+        .map(|role| role.rank())
+        .max()
+        .unwrap_or_default()
 }
 
 #[derive(Clone, CandidType, Serialize, Deserialize, Debug)]
@@ -61,3 +91,84 @@ pub struct PostRole {
     pub color: String,
     pub index: Option<u64>,
 }
+
+#[cfg(test)]
+mod test {
+    use super::{highest_rank, Role};
+    use crate::misc::role_misc::{
+        default_roles, ADMIN_ROLE, MEMBER_ROLE, MEMBER_ROLE_INDEX, MODERATOR_ROLE,
+        MODERATOR_ROLE_INDEX, OWNER_ROLE,
+    };
+
+    fn custom_role(name: &str, index: Option<u64>) -> Role {
+        Role::new(
+            name.to_string(),
+            false,
+            vec![],
+            "#000000".to_string(),
+            index,
+        )
+    }
+
+    #[test]
+    fn test_default_role_ranks() {
+        let roles = default_roles();
+        let rank = |name: &str| highest_rank(&roles, &[name.to_string()]);
+
+        assert!(rank(OWNER_ROLE) > rank(ADMIN_ROLE));
+        assert!(rank(ADMIN_ROLE) > rank(MODERATOR_ROLE));
+        assert!(rank(MODERATOR_ROLE) > rank(MEMBER_ROLE));
+        assert!(rank(MEMBER_ROLE) > 0);
+    }
+
+    #[test]
+    fn test_highest_rank() {
+        let mut roles = default_roles();
+        roles.push(custom_role("host", Some(150)));
+
+        let names = |names: &[&str]| names.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(150, highest_rank(&roles, &names(&[MEMBER_ROLE, "host"])));
+        assert_eq!(
+            MODERATOR_ROLE_INDEX,
+            highest_rank(&roles, &names(&["host", MODERATOR_ROLE]))
+        );
+        assert_eq!(0, highest_rank(&roles, &names(&["unknown"])));
+        assert_eq!(0, highest_rank(&roles, &[]));
+    }
+
+    #[test]
+    fn test_remap_legacy_index() {
+        let mut role = custom_role("legacy", Some(0));
+        assert!(role.remap_legacy_index());
+        assert_eq!(Some(MEMBER_ROLE_INDEX + 1), role.index);
+
+        let mut role = custom_role("legacy", None);
+        assert!(role.remap_legacy_index());
+        assert_eq!(Some(MEMBER_ROLE_INDEX + 1), role.index);
+
+        // the order of the legacy roles is kept
+        let mut lower = custom_role("lower", Some(2));
+        let mut higher = custom_role("higher", Some(5));
+        lower.remap_legacy_index();
+        higher.remap_legacy_index();
+        assert!(higher.rank() > lower.rank());
+
+        // very high legacy indices stay below the moderator role
+        let mut role = custom_role("legacy", Some(99));
+        role.remap_legacy_index();
+        assert!(role.rank() > MEMBER_ROLE_INDEX);
+        assert!(role.rank() < MODERATOR_ROLE_INDEX);
+
+        // remapping is only done once
+        assert!(!role.remap_legacy_index());
+
+        // roles within the hierarchy and the default roles are not changed
+        let mut role = custom_role("host", Some(150));
+        assert!(!role.remap_legacy_index());
+        assert_eq!(Some(150), role.index);
+
+        let mut member = default_roles().pop().unwrap();
+        assert!(!member.remap_legacy_index());
+    }
+}
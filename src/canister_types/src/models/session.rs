@@ -0,0 +1,232 @@
+use candid::{CandidType, Principal};
+use serde::{Deserialize, Serialize};
+
+use crate::impl_storable_for;
+
+use super::date_range::DateRange;
+
+impl_storable_for!(EventSessions);
+
+#[derive(Clone, Debug, CandidType, Deserialize, Serialize)]
+pub struct EventSession {
+    pub id: u64,
+    pub title: String,
+    pub description: String,
+    pub speakers: Vec<Principal>,
+    pub room: Option<String>,
+    pub stream_url: Option<String>,
+    pub date: DateRange,
+    // attendees that bookmarked the session to attend it
+    pub bookmarks: Vec<Principal>,
+    pub created_by: Principal,
+    pub created_on: u64,
+    pub updated_on: u64,
+}
+
+#[derive(Clone, Debug, CandidType, Deserialize)]
+pub struct PostEventSession {
+    pub title: String,
+    pub description: String,
+    pub speakers: Vec<Principal>,
+    pub room: Option<String>,
+    pub stream_url: Option<String>,
+    pub date: DateRange,
+}
+
+#[derive(Clone, Default, Debug, CandidType, Deserialize, Serialize)]
+pub struct EventSessions {
+    pub sessions: Vec<EventSession>,
+    pub last_id: u64,
+}
+
+impl EventSessions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(
+        &mut self,
+        post_session: PostEventSession,
+        created_by: Principal,
+        now: u64,
+    ) -> EventSession {
+        self.last_id += 1;
+
+        let session = EventSession {
+            id: self.last_id,
+            title: post_session.title,
+            description: post_session.description,
+            speakers: post_session.speakers,
+            room: post_session.room,
+            stream_url: post_session.stream_url,
+            date: post_session.date,
+            bookmarks: vec![],
+            created_by,
+            created_on: now,
+            updated_on: now,
+        };
+        self.sessions.push(session.clone());
+        session
+    }
+
+    pub fn update(
+        &mut self,
+        session_id: u64,
+        post_session: PostEventSession,
+        now: u64,
+    ) -> Option<EventSession> {
+        let session = self.sessions.iter_mut().find(|s| s.id == session_id)?;
+
+        session.title = post_session.title;
+        session.description = post_session.description;
+        session.speakers = post_session.speakers;
+        session.room = post_session.room;
+        session.stream_url = post_session.stream_url;
+        session.date = post_session.date;
+        session.updated_on = now;
+        Some(session.clone())
+    }
+
+    pub fn remove(&mut self, session_id: u64) -> bool {
+        let count = self.sessions.len();
+        self.sessions.retain(|session| session.id != session_id);
+        self.sessions.len() != count
+    }
+
+    pub fn set_bookmark(
+        &mut self,
+        session_id: u64,
+        principal: Principal,
+        bookmark: bool,
+    ) -> Option<EventSession> {
+        let session = self.sessions.iter_mut().find(|s| s.id == session_id)?;
+
+        session.bookmarks.retain(|p| p != &principal);
+        if bookmark {
+            session.bookmarks.push(principal);
+        }
+        Some(session.clone())
+    }
+
+    /// Sessions ordered by start date
+    pub fn get_agenda(&self) -> Vec<EventSession> {
+        let mut sessions = self.sessions.clone();
+        sessions.sort_by_key(|session| (session.date.start_date(), session.id));
+        sessions
+    }
+}
+
+#[derive(Clone, Debug, CandidType, Deserialize, Serialize)]
+pub struct EventSessionResponse {
+    pub id: u64,
+    pub event_id: u64,
+    pub title: String,
+    pub description: String,
+    pub speakers: Vec<Principal>,
+    pub room: Option<String>,
+    pub stream_url: Option<String>,
+    pub date: DateRange,
+    pub bookmark_count: u64,
+    pub is_bookmarked: bool,
+    pub created_by: Principal,
+    pub created_on: u64,
+    pub updated_on: u64,
+}
+
+impl EventSessionResponse {
+    pub fn new(event_id: u64, session: EventSession, caller: Principal) -> Self {
+        Self {
+            id: session.id,
+            event_id,
+            bookmark_count: session.bookmarks.len() as u64,
+            is_bookmarked: session.bookmarks.contains(&caller),
+            title: session.title,
+            description: session.description,
+            speakers: session.speakers,
+            room: session.room,
+            stream_url: session.stream_url,
+            date: session.date,
+            created_by: session.created_by,
+            created_on: session.created_on,
+            updated_on: session.updated_on,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{EventSessions, PostEventSession};
+    use crate::models::date_range::DateRange;
+    use candid::Principal;
+
+    fn post_session(title: &str, start_date: u64) -> PostEventSession {
+        PostEventSession {
+            title: title.to_string(),
+            description: String::new(),
+            speakers: vec![],
+            room: None,
+            stream_url: None,
+            date: DateRange::new(start_date, start_date + 10),
+        }
+    }
+
+    #[test]
+    fn test_add_update_remove() {
+        let mut sessions = EventSessions::new();
+        let first = sessions.add(post_session("opening", 100), Principal::anonymous(), 1);
+        let second = sessions.add(post_session("keynote", 200), Principal::anonymous(), 1);
+        assert_eq!(1, first.id);
+        assert_eq!(2, second.id);
+
+        let updated = sessions
+            .update(2, post_session("workshop", 300), 5)
+            .unwrap();
+        assert_eq!("workshop", updated.title);
+        assert_eq!(1, updated.created_on);
+        assert_eq!(5, updated.updated_on);
+        assert!(sessions
+            .update(3, post_session("unknown", 300), 5)
+            .is_none());
+
+        assert!(sessions.remove(1));
+        assert!(!sessions.remove(1));
+
+        // ids are not reused after a removal
+        assert_eq!(
+            3,
+            sessions
+                .add(post_session("closing", 400), Principal::anonymous(), 1)
+                .id
+        );
+    }
+
+    #[test]
+    fn test_bookmarks() {
+        let attendee = Principal::anonymous();
+        let mut sessions = EventSessions::new();
+        sessions.add(post_session("opening", 100), Principal::anonymous(), 1);
+
+        sessions.set_bookmark(1, attendee, true);
+        let session = sessions.set_bookmark(1, attendee, true).unwrap();
+        assert_eq!(vec![attendee], session.bookmarks);
+
+        let session = sessions.set_bookmark(1, attendee, false).unwrap();
+        assert!(session.bookmarks.is_empty());
+        assert!(sessions.set_bookmark(2, attendee, true).is_none());
+    }
+
+    #[test]
+    fn test_agenda_order() {
+        let mut sessions = EventSessions::new();
+        sessions.add(post_session("closing", 300), Principal::anonymous(), 1);
+        sessions.add(post_session("opening", 100), Principal::anonymous(), 1);
+        sessions.add(post_session("parallel", 100), Principal::anonymous(), 1);
+
+        let titles: Vec<String> = sessions
+            .get_agenda()
+            .into_iter()
+            .map(|session| session.title)
+            .collect();
+        assert_eq!(vec!["opening", "parallel", "closing"], titles);
+    }
+}
@@ -0,0 +1,60 @@
+use candid::{CandidType, Principal};
+use ic_cdk::api::time;
+use serde::{Deserialize, Serialize};
+
+use crate::impl_storable_for;
+
+impl_storable_for!(TicketReceipt);
+
+#[derive(Clone, Debug, PartialEq, Eq, CandidType, Deserialize, Serialize)]
+pub struct TicketPrice {
+    pub amount_e8s: u64,
+    // wallet of the group that receives the ICP, must be one of `Group.wallets`
+    pub recipient: Principal,
+}
+
+#[derive(Clone, Debug, CandidType, Deserialize, Serialize)]
+pub struct TicketReceipt {
+    pub event_id: u64,
+    pub principal: Principal,
+    pub block_height: u64,
+    pub amount_e8s: u64,
+    pub recipient: Principal,
+    pub purchased_at: u64,
+}
+
+impl TicketReceipt {
+    pub fn new(
+        event_id: u64,
+        principal: Principal,
+        block_height: u64,
+        amount_e8s: u64,
+        recipient: Principal,
+    ) -> Self {
+        Self {
+            event_id,
+            principal,
+            block_height,
+            amount_e8s,
+            recipient,
+            purchased_at: time(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, CandidType, Deserialize, Serialize)]
+pub struct TicketSales {
+    pub receipts: Vec<TicketReceipt>,
+    pub tickets_sold: u64,
+    pub total_e8s: u64,
+}
+
+impl TicketSales {
+    pub fn new(receipts: Vec<TicketReceipt>) -> Self {
+        Self {
+            tickets_sold: receipts.len() as u64,
+            total_e8s: receipts.iter().map(|receipt| receipt.amount_e8s).sum(),
+            receipts,
+        }
+    }
+}
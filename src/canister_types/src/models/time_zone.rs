@@ -0,0 +1,525 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    api_error::ApiError,
+    date_range::DateRange,
+    recurrence::{civil_from_days, days_from_civil},
+};
+
+const NANOS_PER_MINUTE: u64 = 60 * 1_000_000_000;
+const NANOS_PER_DAY: u64 = 24 * 60 * NANOS_PER_MINUTE;
+const DST_SHIFT_MINUTES: i64 = 60;
+
+/// The daylight saving time rules that are currently in use, historical rule changes are not tracked
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DstRule {
+    // last Sunday of March until the last Sunday of October, at 01:00 UTC
+    Europe,
+    // second Sunday of March until the first Sunday of November, at 02:00 local time
+    NorthAmerica,
+    // first Sunday of October until the first Sunday of April
+    SouthEastAustralia,
+    // last Sunday of September until the first Sunday of April
+    NewZealand,
+}
+
+impl DstRule {
+    /// (month, n-th Sunday where -1 is the last one, local standard time in minutes) of the switch to daylight saving time
+    pub fn daylight_start(&self, standard_offset: i64) -> (u32, i32, i64) {
+        match self {
+            DstRule::Europe => (3, -1, 60 + standard_offset),
+            DstRule::NorthAmerica => (3, 2, 120),
+            DstRule::SouthEastAustralia => (10, 1, 120),
+            DstRule::NewZealand => (9, -1, 120),
+        }
+    }
+
+    /// (month, n-th Sunday where -1 is the last one, local daylight time in minutes) of the switch back to standard time
+    pub fn standard_start(&self, standard_offset: i64) -> (u32, i32, i64) {
+        match self {
+            DstRule::Europe => (10, -1, 60 + standard_offset + DST_SHIFT_MINUTES),
+            DstRule::NorthAmerica => (11, 1, 120),
+            DstRule::SouthEastAustralia => (4, 1, 180),
+            DstRule::NewZealand => (4, 1, 180),
+        }
+    }
+
+    fn is_dst(&self, utc: u64, standard_offset: i64) -> bool {
+        let (year, _, _) = civil_from_days(utc / NANOS_PER_DAY);
+
+        let (month, n, minutes) = self.daylight_start(standard_offset);
+        let start = shift(
+            sunday(year, month, n) * NANOS_PER_DAY,
+            minutes - standard_offset,
+        );
+
+        let (month, n, minutes) = self.standard_start(standard_offset);
+        let end = shift(
+            sunday(year, month, n) * NANOS_PER_DAY,
+            minutes - standard_offset - DST_SHIFT_MINUTES,
+        );
+
+        match start < end {
+            // northern hemisphere, daylight saving time within the year
+            true => start <= utc && utc < end,
+            // southern hemisphere, daylight saving time over the turn of the year
+            false => utc >= start || utc < end,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct TimeZone {
+    pub name: &'static str,
+    // offset from UTC in minutes outside of daylight saving time
+    pub standard_offset: i64,
+    pub dst: Option<DstRule>,
+}
+
+const fn zone(name: &'static str, standard_offset: i64, dst: Option<DstRule>) -> TimeZone {
+    TimeZone {
+        name,
+        standard_offset,
+        dst,
+    }
+}
+
+const EU: Option<DstRule> = Some(DstRule::Europe);
+const NA: Option<DstRule> = Some(DstRule::NorthAmerica);
+const AU: Option<DstRule> = Some(DstRule::SouthEastAustralia);
+const NZ: Option<DstRule> = Some(DstRule::NewZealand);
+
+/// The supported IANA time zones, zones with rules that can not be expressed by `DstRule` are left out
+static TIME_ZONES: &[TimeZone] = &[
+    zone("UTC", 0, None),
+    zone("Etc/UTC", 0, None),
+    // Europe
+    zone("Europe/London", 0, EU),
+    zone("Europe/Dublin", 0, EU),
+    zone("Europe/Lisbon", 0, EU),
+    zone("Atlantic/Canary", 0, EU),
+    zone("Europe/Amsterdam", 60, EU),
+    zone("Europe/Berlin", 60, EU),
+    zone("Europe/Brussels", 60, EU),
+    zone("Europe/Budapest", 60, EU),
+    zone("Europe/Copenhagen", 60, EU),
+    zone("Europe/Luxembourg", 60, EU),
+    zone("Europe/Madrid", 60, EU),
+    zone("Europe/Oslo", 60, EU),
+    zone("Europe/Paris", 60, EU),
+    zone("Europe/Prague", 60, EU),
+    zone("Europe/Rome", 60, EU),
+    zone("Europe/Stockholm", 60, EU),
+    zone("Europe/Vienna", 60, EU),
+    zone("Europe/Warsaw", 60, EU),
+    zone("Europe/Zurich", 60, EU),
+    zone("Europe/Athens", 120, EU),
+    zone("Europe/Bucharest", 120, EU),
+    zone("Europe/Helsinki", 120, EU),
+    zone("Europe/Kyiv", 120, EU),
+    zone("Europe/Riga", 120, EU),
+    zone("Europe/Sofia", 120, EU),
+    zone("Europe/Tallinn", 120, EU),
+    zone("Europe/Vilnius", 120, EU),
+    zone("Europe/Istanbul", 180, None),
+    zone("Europe/Minsk", 180, None),
+    zone("Europe/Moscow", 180, None),
+    // Africa
+    zone("Africa/Abidjan", 0, None),
+    zone("Africa/Accra", 0, None),
+    zone("Africa/Lagos", 60, None),
+    zone("Africa/Johannesburg", 120, None),
+    zone("Africa/Addis_Ababa", 180, None),
+    zone("Africa/Nairobi", 180, None),
+    // Asia
+    zone("Asia/Dubai", 240, None),
+    zone("Asia/Tehran", 210, None),
+    zone("Asia/Karachi", 300, None),
+    zone("Asia/Kolkata", 330, None),
+    zone("Asia/Kathmandu", 345, None),
+    zone("Asia/Dhaka", 360, None),
+    zone("Asia/Bangkok", 420, None),
+    zone("Asia/Ho_Chi_Minh", 420, None),
+    zone("Asia/Jakarta", 420, None),
+    zone("Asia/Hong_Kong", 480, None),
+    zone("Asia/Kuala_Lumpur", 480, None),
+    zone("Asia/Manila", 480, None),
+    zone("Asia/Shanghai", 480, None),
+    zone("Asia/Singapore", 480, None),
+    zone("Asia/Taipei", 480, None),
+    zone("Asia/Seoul", 540, None),
+    zone("Asia/Tokyo", 540, None),
+    // Australia and Pacific
+    zone("Australia/Perth", 480, None),
+    zone("Australia/Darwin", 570, None),
+    zone("Australia/Adelaide", 570, AU),
+    zone("Australia/Brisbane", 600, None),
+    zone("Australia/Hobart", 600, AU),
+    zone("Australia/Melbourne", 600, AU),
+    zone("Australia/Sydney", 600, AU),
+    zone("Pacific/Auckland", 720, NZ),
+    zone("Pacific/Honolulu", -600, None),
+    // America
+    zone("America/St_Johns", -210, NA),
+    zone("America/Halifax", -240, NA),
+    zone("America/Caracas", -240, None),
+    zone("America/Puerto_Rico", -240, None),
+    zone("America/Argentina/Buenos_Aires", -180, None),
+    zone("America/Sao_Paulo", -180, None),
+    zone("America/Bogota", -300, None),
+    zone("America/Lima", -300, None),
+    zone("America/New_York", -300, NA),
+    zone("America/Toronto", -300, NA),
+    zone("America/Chicago", -360, NA),
+    zone("America/Mexico_City", -360, None),
+    zone("America/Winnipeg", -360, NA),
+    zone("America/Denver", -420, NA),
+    zone("America/Edmonton", -420, NA),
+    zone("America/Phoenix", -420, None),
+    zone("America/Los_Angeles", -480, NA),
+    zone("America/Vancouver", -480, NA),
+    zone("America/Anchorage", -540, NA),
+];
+
+impl TimeZone {
+    pub fn find(name: &str) -> Option<TimeZone> {
+        TIME_ZONES.iter().find(|zone| zone.name == name).copied()
+    }
+
+    pub fn validate(name: &str) -> Result<TimeZone, ApiError> {
+        Self::find(name).ok_or_else(|| {
+            ApiError::bad_request().add_message(&format!("Unknown time zone {name}"))
+        })
+    }
+
+    pub fn names() -> Vec<String> {
+        TIME_ZONES
+            .iter()
+            .map(|zone| zone.name.to_string())
+            .collect()
+    }
+
+    pub fn daylight_offset(&self) -> i64 {
+        self.standard_offset + DST_SHIFT_MINUTES
+    }
+
+    /// Offset from UTC in minutes at the given UTC timestamp
+    pub fn offset_at(&self, utc: u64) -> i64 {
+        match self.dst {
+            Some(rule) if rule.is_dst(utc, self.standard_offset) => self.daylight_offset(),
+            _ => self.standard_offset,
+        }
+    }
+
+    /// Wall-clock time in this zone, expressed as if it were a UTC timestamp
+    pub fn to_local(&self, utc: u64) -> u64 {
+        shift(utc, self.offset_at(utc))
+    }
+
+    /// UTC timestamp of a wall-clock time, ambiguous times resolve to the first one and
+    /// times skipped by the switch to daylight saving time resolve to after the switch
+    pub fn to_utc(&self, local: u64) -> u64 {
+        [self.daylight_offset(), self.standard_offset]
+            .iter()
+            .map(|offset| shift(local, -offset))
+            .find(|utc| self.to_local(*utc) == local)
+            .unwrap_or_else(|| shift(local, -self.standard_offset))
+    }
+
+    /// ISO 8601 local time with the UTC offset, e.g. 2024-01-31T18:30:00+01:00
+    pub fn format_local(&self, utc: u64) -> String {
+        let offset = self.offset_at(utc);
+        let seconds = shift(utc, offset) / 1_000_000_000;
+        let (year, month, day) = civil_from_days(seconds / 86_400);
+        let seconds_of_day = seconds % 86_400;
+        let sign = if offset < 0 { '-' } else { '+' };
+
+        format!(
+            "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}{sign}{:02}:{:02}",
+            seconds_of_day / 3600,
+            seconds_of_day % 3600 / 60,
+            seconds_of_day % 60,
+            offset.abs() / 60,
+            offset.abs() % 60
+        )
+    }
+}
+
+#[derive(Clone, Debug, CandidType, Serialize, Deserialize)]
+pub struct LocalDate {
+    pub year: u64,
+    pub month: u32,
+    pub day: u32,
+}
+
+/// A range of whole days in a time zone, both days are included
+#[derive(Clone, Debug, CandidType, Serialize, Deserialize)]
+pub struct LocalDayRange {
+    pub time_zone: String,
+    pub start: LocalDate,
+    pub end: LocalDate,
+}
+
+impl LocalDayRange {
+    pub fn to_date_range(&self) -> Result<DateRange, ApiError> {
+        let time_zone = TimeZone::validate(&self.time_zone)?;
+
+        let invalid = || ApiError::bad_request().add_message("Invalid date");
+        let start_day = Self::days(&self.start).ok_or_else(invalid)?;
+        let end_day = Self::days(&self.end).ok_or_else(invalid)?;
+        if end_day < start_day {
+            return Err(ApiError::bad_request().add_message("End date is before the start date"));
+        }
+
+        Ok(DateRange::new(
+            time_zone.to_utc(start_day * NANOS_PER_DAY),
+            time_zone.to_utc((end_day + 1) * NANOS_PER_DAY) - 1,
+        ))
+    }
+
+    fn days(date: &LocalDate) -> Option<u64> {
+        let valid = (1970..=9999).contains(&date.year)
+            && (1..=12).contains(&date.month)
+            && (1..=31).contains(&date.day);
+
+        // days_from_civil rolls over invalid days, so compare the round trip
+        valid
+            .then(|| days_from_civil(date.year, date.month, date.day))
+            .filter(|days| civil_from_days(*days) == (date.year, date.month, date.day))
+    }
+}
+
+// Day (since the epoch) of the n-th Sunday of the month, -1 for the last Sunday
+pub fn sunday(year: u64, month: u32, n: i32) -> u64 {
+    let weekday = |days: u64| (days + 4) % 7; // 1970-01-01 was a Thursday, 0 is Sunday
+
+    if n < 0 {
+        let (next_year, next_month) = if month == 12 {
+            (year + 1, 1)
+        } else {
+            (year, month + 1)
+        };
+        let last_day = days_from_civil(next_year, next_month, 1) - 1;
+        return last_day - weekday(last_day);
+    }
+
+    let first_day = days_from_civil(year, month, 1);
+    first_day + (7 - weekday(first_day)) % 7 + 7 * (n as u64 - 1)
+}
+
+fn shift(timestamp: u64, minutes: i64) -> u64 {
+    let nanos = minutes.unsigned_abs() * NANOS_PER_MINUTE;
+    match minutes < 0 {
+        true => timestamp.saturating_sub(nanos),
+        false => timestamp.saturating_add(nanos),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{sunday, LocalDate, LocalDayRange, TimeZone, NANOS_PER_DAY, NANOS_PER_MINUTE};
+    use crate::models::recurrence::days_from_civil;
+
+    // UTC or wall-clock timestamp of the given date and time
+    fn at(year: u64, month: u32, day: u32, hour: u64, minute: u64) -> u64 {
+        days_from_civil(year, month, day) * NANOS_PER_DAY + (hour * 60 + minute) * NANOS_PER_MINUTE
+    }
+
+    fn zone(name: &str) -> TimeZone {
+        TimeZone::find(name).unwrap()
+    }
+
+    fn local_date(year: u64, month: u32, day: u32) -> LocalDate {
+        LocalDate { year, month, day }
+    }
+
+    #[test]
+    fn test_sunday() {
+        assert_eq!(days_from_civil(2024, 3, 31), sunday(2024, 3, -1));
+        assert_eq!(days_from_civil(2024, 3, 10), sunday(2024, 3, 2));
+        assert_eq!(days_from_civil(2024, 9, 1), sunday(2024, 9, 1));
+        assert_eq!(days_from_civil(2024, 11, 3), sunday(2024, 11, 1));
+        assert_eq!(days_from_civil(2024, 12, 29), sunday(2024, 12, -1));
+        assert_eq!(days_from_civil(2023, 10, 29), sunday(2023, 10, -1));
+    }
+
+    #[test]
+    fn test_europe_transitions() {
+        // the whole of Europe switches at 01:00 UTC
+        let amsterdam = zone("Europe/Amsterdam");
+        let london = zone("Europe/London");
+
+        assert_eq!(60, amsterdam.offset_at(at(2024, 3, 31, 0, 59)));
+        assert_eq!(120, amsterdam.offset_at(at(2024, 3, 31, 1, 0)));
+        assert_eq!(120, amsterdam.offset_at(at(2024, 10, 27, 0, 59)));
+        assert_eq!(60, amsterdam.offset_at(at(2024, 10, 27, 1, 0)));
+
+        assert_eq!(0, london.offset_at(at(2024, 3, 31, 0, 59)));
+        assert_eq!(60, london.offset_at(at(2024, 3, 31, 1, 0)));
+        assert_eq!(60, london.offset_at(at(2024, 10, 27, 0, 59)));
+        assert_eq!(0, london.offset_at(at(2024, 10, 27, 1, 0)));
+
+        assert_eq!(
+            180,
+            zone("Europe/Helsinki").offset_at(at(2024, 7, 1, 12, 0))
+        );
+        assert_eq!(180, zone("Europe/Moscow").offset_at(at(2024, 7, 1, 12, 0)));
+    }
+
+    #[test]
+    fn test_north_america_transitions() {
+        // 02:00 local time, so a different UTC time per zone
+        let new_york = zone("America/New_York");
+        assert_eq!(-300, new_york.offset_at(at(2024, 3, 10, 6, 59)));
+        assert_eq!(-240, new_york.offset_at(at(2024, 3, 10, 7, 0)));
+        assert_eq!(-240, new_york.offset_at(at(2024, 11, 3, 5, 59)));
+        assert_eq!(-300, new_york.offset_at(at(2024, 11, 3, 6, 0)));
+
+        let los_angeles = zone("America/Los_Angeles");
+        assert_eq!(-480, los_angeles.offset_at(at(2024, 3, 10, 9, 59)));
+        assert_eq!(-420, los_angeles.offset_at(at(2024, 3, 10, 10, 0)));
+        assert_eq!(-420, los_angeles.offset_at(at(2024, 11, 3, 8, 59)));
+        assert_eq!(-480, los_angeles.offset_at(at(2024, 11, 3, 9, 0)));
+
+        assert_eq!(
+            -420,
+            zone("America/Phoenix").offset_at(at(2024, 7, 1, 12, 0))
+        );
+    }
+
+    #[test]
+    fn test_southern_hemisphere_transitions() {
+        // daylight saving time runs over the turn of the year
+        let sydney = zone("Australia/Sydney");
+        assert_eq!(660, sydney.offset_at(at(2024, 1, 15, 0, 0)));
+        assert_eq!(600, sydney.offset_at(at(2024, 7, 15, 0, 0)));
+        assert_eq!(660, sydney.offset_at(at(2024, 4, 6, 15, 59)));
+        assert_eq!(600, sydney.offset_at(at(2024, 4, 6, 16, 0)));
+        assert_eq!(600, sydney.offset_at(at(2024, 10, 5, 15, 59)));
+        assert_eq!(660, sydney.offset_at(at(2024, 10, 5, 16, 0)));
+
+        let auckland = zone("Pacific/Auckland");
+        assert_eq!(780, auckland.offset_at(at(2024, 1, 15, 0, 0)));
+        assert_eq!(720, auckland.offset_at(at(2024, 7, 15, 0, 0)));
+        assert_eq!(780, auckland.offset_at(at(2024, 4, 6, 13, 59)));
+        assert_eq!(720, auckland.offset_at(at(2024, 4, 6, 14, 0)));
+        assert_eq!(720, auckland.offset_at(at(2024, 9, 28, 13, 59)));
+        assert_eq!(780, auckland.offset_at(at(2024, 9, 28, 14, 0)));
+
+        assert_eq!(
+            600,
+            zone("Australia/Brisbane").offset_at(at(2024, 1, 15, 0, 0))
+        );
+    }
+
+    #[test]
+    fn test_to_utc() {
+        let amsterdam = zone("Europe/Amsterdam");
+        assert_eq!(
+            at(2024, 1, 15, 11, 0),
+            amsterdam.to_utc(at(2024, 1, 15, 12, 0))
+        );
+        assert_eq!(
+            at(2024, 7, 15, 10, 0),
+            amsterdam.to_utc(at(2024, 7, 15, 12, 0))
+        );
+
+        for utc in (0..48).map(|hour| at(2024, 3, 30, 0, 0) + hour * 60 * NANOS_PER_MINUTE) {
+            assert_eq!(utc, amsterdam.to_utc(amsterdam.to_local(utc)));
+        }
+    }
+
+    #[test]
+    fn test_to_utc_skipped_time() {
+        // 02:30 doesn't exist on the day the clocks move forward, it resolves to 03:30
+        let amsterdam = zone("Europe/Amsterdam");
+        let utc = amsterdam.to_utc(at(2024, 3, 31, 2, 30));
+        assert_eq!(at(2024, 3, 31, 1, 30), utc);
+        assert_eq!(at(2024, 3, 31, 3, 30), amsterdam.to_local(utc));
+
+        let new_york = zone("America/New_York");
+        assert_eq!(
+            at(2024, 3, 10, 7, 30),
+            new_york.to_utc(at(2024, 3, 10, 2, 30))
+        );
+    }
+
+    #[test]
+    fn test_to_utc_ambiguous_time() {
+        // 02:30 happens twice on the day the clocks move back, the first one is used
+        let amsterdam = zone("Europe/Amsterdam");
+        assert_eq!(
+            at(2024, 10, 27, 0, 30),
+            amsterdam.to_utc(at(2024, 10, 27, 2, 30))
+        );
+
+        let sydney = zone("Australia/Sydney");
+        assert_eq!(at(2024, 4, 6, 15, 30), sydney.to_utc(at(2024, 4, 7, 2, 30)));
+    }
+
+    #[test]
+    fn test_format_local() {
+        assert_eq!(
+            "2024-01-31T18:30:00+01:00",
+            zone("Europe/Amsterdam").format_local(at(2024, 1, 31, 17, 30))
+        );
+        assert_eq!(
+            "2024-01-15T08:30:00-03:30",
+            zone("America/St_Johns").format_local(at(2024, 1, 15, 12, 0))
+        );
+        assert_eq!(
+            "2024-01-01T00:00:00+13:00",
+            zone("Pacific/Auckland").format_local(at(2023, 12, 31, 11, 0))
+        );
+    }
+
+    #[test]
+    fn test_local_day_range() {
+        let range = LocalDayRange {
+            time_zone: "UTC".to_string(),
+            start: local_date(2024, 2, 28),
+            end: local_date(2024, 2, 29),
+        }
+        .to_date_range()
+        .unwrap();
+        assert_eq!(at(2024, 2, 28, 0, 0), range.start_date());
+        assert_eq!(at(2024, 3, 1, 0, 0) - 1, range.end_date());
+
+        // the day the clocks move forward only has 23 hours
+        let range = LocalDayRange {
+            time_zone: "Europe/Amsterdam".to_string(),
+            start: local_date(2024, 3, 31),
+            end: local_date(2024, 3, 31),
+        }
+        .to_date_range()
+        .unwrap();
+        assert_eq!(at(2024, 3, 30, 23, 0), range.start_date());
+        assert_eq!(at(2024, 3, 31, 22, 0) - 1, range.end_date());
+    }
+
+    #[test]
+    fn test_invalid_local_date() {
+        assert_eq!(Some(0), LocalDayRange::days(&local_date(1970, 1, 1)));
+        assert!(LocalDayRange::days(&local_date(2024, 2, 29)).is_some());
+
+        // not a leap year
+        assert!(LocalDayRange::days(&local_date(2023, 2, 29)).is_none());
+        assert!(LocalDayRange::days(&local_date(2024, 4, 31)).is_none());
+        assert!(LocalDayRange::days(&local_date(2024, 13, 1)).is_none());
+        assert!(LocalDayRange::days(&local_date(2024, 1, 0)).is_none());
+        assert!(LocalDayRange::days(&local_date(1969, 12, 31)).is_none());
+    }
+
+    #[test]
+    fn test_find() {
+        assert!(TimeZone::find("Europe/Amsterdam").is_some());
+        assert!(TimeZone::find("Europe/Atlantis").is_none());
+
+        let mut names = TimeZone::names();
+        let count = names.len();
+        names.sort();
+        names.dedup();
+        assert_eq!(count, names.len());
+    }
+}
@@ -0,0 +1,90 @@
+use candid::{CandidType, Principal};
+use serde::{Deserialize, Serialize};
+
+use crate::impl_storable_for;
+
+impl_storable_for!(Waitlist);
+
+#[derive(Clone, Default, Debug, CandidType, Deserialize, Serialize)]
+pub struct Waitlist {
+    pub principals: Vec<Principal>,
+}
+
+impl Waitlist {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get_principals(&self) -> Vec<Principal> {
+        self.principals.clone()
+    }
+
+    pub fn get_count(&self) -> u64 {
+        self.principals.len() as u64
+    }
+
+    /// Returns the 1-based position on the waitlist
+    pub fn get_position(&self, principal: &Principal) -> Option<u64> {
+        self.principals
+            .iter()
+            .position(|p| p == principal)
+            .map(|index| index as u64 + 1)
+    }
+
+    pub fn add(&mut self, principal: Principal) -> u64 {
+        if !self.principals.contains(&principal) {
+            self.principals.push(principal);
+        }
+        self.get_position(&principal).unwrap_or_default()
+    }
+
+    pub fn remove(&mut self, principal: &Principal) {
+        self.principals.retain(|p| p != principal);
+    }
+
+    /// Removes and returns the first principal on the waitlist
+    pub fn pop(&mut self) -> Option<Principal> {
+        if self.principals.is_empty() {
+            return None;
+        }
+        Some(self.principals.remove(0))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Waitlist;
+    use candid::Principal;
+
+    #[test]
+    fn test_position() {
+        let mut waitlist = Waitlist::new();
+        assert_eq!(None, waitlist.get_position(&Principal::from_slice(&[1])));
+
+        assert_eq!(1, waitlist.add(Principal::from_slice(&[1])));
+        assert_eq!(2, waitlist.add(Principal::from_slice(&[2])));
+        // adding a principal twice keeps its position
+        assert_eq!(1, waitlist.add(Principal::from_slice(&[1])));
+        assert_eq!(2, waitlist.get_count());
+
+        waitlist.remove(&Principal::from_slice(&[1]));
+        assert_eq!(None, waitlist.get_position(&Principal::from_slice(&[1])));
+        assert_eq!(Some(1), waitlist.get_position(&Principal::from_slice(&[2])));
+    }
+
+    #[test]
+    fn test_pop_in_order() {
+        let mut waitlist = Waitlist::new();
+        assert_eq!(None, waitlist.pop());
+
+        waitlist.add(Principal::from_slice(&[1]));
+        waitlist.add(Principal::from_slice(&[2]));
+        waitlist.add(Principal::from_slice(&[3]));
+        waitlist.remove(&Principal::from_slice(&[2]));
+
+        assert_eq!(Some(Principal::from_slice(&[1])), waitlist.pop());
+        assert_eq!(Some(Principal::from_slice(&[3])), waitlist.pop());
+        assert_eq!(None, waitlist.pop());
+        assert_eq!(0, waitlist.get_count());
+    }
+}
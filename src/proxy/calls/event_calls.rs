@@ -1,19 +1,39 @@
 use crate::{
     helpers::{
-        group_permission::{can_delete, can_edit},
+        export_token::ExportKind,
+        group_permission::{
+            can_delete, can_edit, can_manage_event, can_manage_event_role, can_read, can_write,
+            is_not_muted,
+        },
         guards::has_access,
     },
-    logic::event_logic::EventCalls,
+    logic::{
+        calendar_logic::CalendarCalls, check_in_logic::CheckInCalls, event_logic::EventCalls,
+        export_logic::ExportCalls, feedback_logic::FeedbackCalls, session_logic::SessionCalls,
+    },
 };
 
 use candid::Principal;
 use canister_types::models::{
     api_error::ApiError,
-    attendee::{Attendee, InviteAttendeeResponse, JoinedAttendeeResponse},
-    event::{EventFilter, EventResponse, EventSort, EventsCount, PostEvent, UpdateEvent},
+    attendee::{
+        Attendee, AttendeeJoin, EventPermission, EventRole, EventRoleResponse,
+        InviteAttendeeResponse, JoinedAttendeeResponse, RsvpStatus,
+    },
+    calendar_feed::CalendarFeedResponse,
+    check_in::{CheckIn, CheckInCode, EventCheckInSummary},
+    date_range::DateRange,
+    event::{
+        EventEditScope, EventFilter, EventResponse, EventSort, EventsCount, PostEvent, UpdateEvent,
+    },
+    feedback::{EventFeedback, EventFeedbackSummary},
+    http_types::ExportTokenResponse,
     paged_response::PagedResponse,
-    permission::PermissionType,
+    permission::{PermissionActionType, PermissionType},
     profile::ProfileResponse,
+    session::{EventSessionResponse, PostEventSession},
+    ticket::TicketSales,
+    time_zone::TimeZone,
 };
 use ic_cdk::{query, update};
 
@@ -29,9 +49,52 @@ use ic_cdk::{query, update};
 #[update(guard = "has_access")]
 pub fn add_event(post_event: PostEvent) -> Result<EventResponse, ApiError> {
     can_edit(post_event.group_id, PermissionType::Event(None))?;
+    is_not_muted(post_event.group_id)?;
     EventCalls::add_event(post_event)
 }
 
+/// Move an event to a different group - [`[update]`](update)
+/// # Arguments
+/// * `event_id` - The identifier of the event
+/// * `target_group_id` - The identifier of the group to move the event to
+/// # Returns
+/// * `EventResponse` - The moved event
+/// # Errors
+/// * `ApiError` - If the event already belongs to the group or the ticket recipient is not a wallet of the group
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+/// Requires `Event` delete permission in the current group and `Event` write permission in the target group.
+#[update(guard = "has_access")]
+pub fn transfer_event_to_group(
+    event_id: u64,
+    target_group_id: u64,
+) -> Result<EventResponse, ApiError> {
+    let group_id = EventCalls::get_event_group_id(event_id)?;
+    can_delete(group_id, PermissionType::Event(None))?;
+    can_write(target_group_id, PermissionType::Event(None))?;
+    is_not_muted(target_group_id)?;
+    EventCalls::transfer_event_to_group(event_id, target_group_id)
+}
+
+/// Copy an event to a new date - [`[update]`](update)
+/// # Arguments
+/// * `event_id` - The identifier of the event to clone
+/// * `new_date` - The date of the new event
+/// # Returns
+/// * `EventResponse` - The new event
+/// # Errors
+/// * `ApiError` - If the date is invalid or something went wrong while adding the event
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+/// The event is added to the group of the original event, attendees and invites are not copied.
+#[update(guard = "has_access")]
+pub fn clone_event(event_id: u64, new_date: DateRange) -> Result<EventResponse, ApiError> {
+    let group_id = EventCalls::get_event_group_id(event_id)?;
+    can_edit(group_id, PermissionType::Event(None))?;
+    is_not_muted(group_id)?;
+    EventCalls::clone_event(event_id, new_date)
+}
+
 /// Get an event - [`[query]`](query)
 /// # Arguments
 /// * `event_id` - The identifier of the event
@@ -89,6 +152,7 @@ fn get_event_count(group_ids: Option<Vec<u64>>, query: Option<String>) -> Events
 /// * `ApiError` - If something went wrong while updating the event
 /// # Note
 /// This function is guarded by the [`has_access`](has_access) function.
+/// Co-hosts can't change the privacy, ticket price, date, time zone or recurrence of the event.
 #[update(guard = "has_access")]
 pub fn edit_event(
     event_id: u64,
@@ -99,6 +163,34 @@ pub fn edit_event(
     EventCalls::edit_event(event_id, update_event, group_id)
 }
 
+/// Edit a single occurrence or all future occurrences of a recurring event - [`[update]`](update)
+/// # Arguments
+/// * `event_id` - The identifier of the recurring event
+/// * `group_id` - Used to check if the user has access to the group, only the owning group can edit occurrences
+/// * `occurrence_start` - The start date of the occurrence to edit
+/// * `scope` - Whether to edit only this occurrence or this and all future occurrences
+/// * `update_event` - The updated event data for the occurrence(s)
+/// # Returns
+/// * `EventResponse` - The event that was split off from the series
+/// # Errors
+/// * `ApiError` - If something went wrong while updating the occurrence
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+/// Editing a single occurrence detaches it into a standalone event, editing all future occurrences
+/// ends the series before the occurrence and starts a new series with the `recurrence` of `update_event`,
+/// which can't start before `occurrence_start`.
+#[update(guard = "has_access")]
+pub fn edit_event_occurrence(
+    event_id: u64,
+    group_id: u64,
+    occurrence_start: u64,
+    scope: EventEditScope,
+    update_event: UpdateEvent,
+) -> Result<EventResponse, ApiError> {
+    can_edit(group_id, PermissionType::Event(None))?;
+    EventCalls::edit_event_occurrence(event_id, group_id, occurrence_start, scope, update_event)
+}
+
 /// Delete an event - [`[update]`](update)
 /// # Arguments
 /// * `event_id` - The identifier of the event
@@ -115,6 +207,48 @@ pub fn delete_event(event_id: u64, group_id: u64) -> Result<(), ApiError> {
     EventCalls::delete_event(event_id, group_id)
 }
 
+/// Create a short-lived token to download the attendees of an event as CSV - [`[update]`](update)
+/// # Arguments
+/// * `event_id` - The identifier of the event
+/// * `group_id` - Used to check if the user has access to the group the event belongs to
+/// # Returns
+/// * `ExportTokenResponse` - The token and the path to `GET /events/{id}/attendees.csv` with the token
+/// # Errors
+/// * `ApiError` - If the caller is not allowed to manage events
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+#[update(guard = "has_access")]
+pub async fn create_event_attendees_export_token(
+    event_id: u64,
+    group_id: u64,
+) -> Result<ExportTokenResponse, ApiError> {
+    can_edit(group_id, PermissionType::Event(None))?;
+    ExportCalls::create_export_token(ExportKind::EventAttendees, event_id, group_id).await
+}
+
+/// Publish a draft event, now or at a later moment - [`[update]`](update)
+/// # Arguments
+/// * `event_id` - The identifier of the event
+/// * `group_id` - Used to check if the user has access to the group the event belongs to
+/// * `publish_at` - When to publish the event, `None` publishes it right away
+/// # Returns
+/// * `EventResponse` - The (scheduled) event
+/// # Errors
+/// * `ApiError` - If the event is already published or `publish_at` is in the past
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+/// Only the owning group can publish the event.
+/// Members of the hosting groups are notified once the event is published.
+#[update(guard = "has_access")]
+pub fn publish_event(
+    event_id: u64,
+    group_id: u64,
+    publish_at: Option<u64>,
+) -> Result<EventResponse, ApiError> {
+    can_edit(group_id, PermissionType::Event(None))?;
+    EventCalls::publish_event(event_id, group_id, publish_at)
+}
+
 /// Cancel an event - [`[update]`](update)
 /// # Arguments
 /// * `event_id` - The identifier of the event
@@ -132,21 +266,128 @@ pub fn cancel_event(event_id: u64, group_id: u64, reason: String) -> Result<(),
     EventCalls::cancel_event(event_id, reason, group_id)
 }
 
+/// Invite another group to co-host an event - [`[update]`](update)
+/// # Arguments
+/// * `event_id` - The identifier of the event
+/// * `group_id` - Used to check if the user has access to the group the event belongs to
+/// * `co_host_group_id` - The identifier of the group to invite as co-host
+/// # Returns
+/// * `()` - If the group was invited
+/// # Errors
+/// * `ApiError` - If something went wrong while inviting the group
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+/// Only the group the event belongs to can invite co-hosts.
+#[update(guard = "has_access")]
+pub fn invite_event_co_host(
+    event_id: u64,
+    group_id: u64,
+    co_host_group_id: u64,
+) -> Result<(), ApiError> {
+    can_edit(group_id, PermissionType::Event(None))?;
+    EventCalls::invite_event_co_host(event_id, group_id, co_host_group_id)
+}
+
+/// Accept or decline an invite to co-host an event - [`[update]`](update)
+/// # Arguments
+/// * `event_id` - The identifier of the event
+/// * `group_id` - The identifier of the invited group
+/// * `accept` - Whether to accept or decline the invite
+/// # Returns
+/// * `()` - If the invite was accepted or declined
+/// # Errors
+/// * `ApiError` - If something went wrong while accepting or declining the invite
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+/// Once accepted the event is added to the events of the group, and admins of the group can edit
+/// the event and manage its attendees.
+#[update(guard = "has_access")]
+pub fn accept_or_decline_event_co_host_invite(
+    event_id: u64,
+    group_id: u64,
+    accept: bool,
+) -> Result<(), ApiError> {
+    can_edit(group_id, PermissionType::Event(None))?;
+    EventCalls::accept_or_decline_event_co_host_invite(event_id, group_id, accept)
+}
+
+/// Remove a co-host from an event - [`[update]`](update)
+/// # Arguments
+/// * `event_id` - The identifier of the event
+/// * `group_id` - The group the event belongs to, or the co-host group itself to step down
+/// * `co_host_group_id` - The identifier of the co-host group to remove
+/// # Returns
+/// * `()` - If the co-host was removed
+/// # Errors
+/// * `ApiError` - If something went wrong while removing the co-host
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+#[update(guard = "has_access")]
+pub fn remove_event_co_host(
+    event_id: u64,
+    group_id: u64,
+    co_host_group_id: u64,
+) -> Result<(), ApiError> {
+    can_edit(group_id, PermissionType::Event(None))?;
+    EventCalls::remove_event_co_host(event_id, group_id, co_host_group_id)
+}
+
 // Attendee methods
 
 /// Join an event - [`[update]`](update)
 /// # Arguments
 /// * `event_id` - The identifier of the event
-/// * `group_id` - Used to check if the user has access to the group the event belongs to
+/// * `block_height` - The block height of the ICP transfer for the ticket, only needed for ticketed events
+/// * `account_identifier` - Optional account identifier of the user in case the event is Gated
 /// # Returns
-/// * `JoinedAttendeeResponse` - the event join details
+/// * `JoinedAttendeeResponse` - the event join details, `waitlist_position` is set when the event is full
 /// # Errors
 /// * `ApiError` - If something went wrong while joining the event
 /// # Note
 /// This function is guarded by the [`has_access`](has_access) function.
+/// The ticket price has to be transferred from the caller to the `ticket_price.recipient` wallet beforehand,
+/// every block can only be used for a single ticket.
+#[update(guard = "has_access")]
+pub async fn join_event(
+    event_id: u64,
+    block_height: Option<u64>,
+    account_identifier: Option<String>,
+) -> Result<JoinedAttendeeResponse, ApiError> {
+    EventCalls::join_event(event_id, block_height, account_identifier).await
+}
+
+/// Join a single occurrence of a recurring event - [`[update]`](update)
+/// # Arguments
+/// * `event_id` - The identifier of the recurring event
+/// * `occurrence_start` - The start date of the occurrence to join
+/// # Returns
+/// * `JoinedAttendeeResponse` - The joined attendee details, `waitlist_position` is set when the event is full
+/// # Errors
+/// * `ApiError` - If something went wrong while joining the occurrence
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+/// Use `join_event` to join the whole series.
+#[update(guard = "has_access")]
+pub fn join_event_occurrence(
+    event_id: u64,
+    occurrence_start: u64,
+) -> Result<JoinedAttendeeResponse, ApiError> {
+    EventCalls::join_event_occurrence(event_id, occurrence_start)
+}
+
+/// Set the RSVP of the caller on a joined event - [`[update]`](update)
+/// # Arguments
+/// * `event_id` - The identifier of the event
+/// * `status` - Going, Maybe or NotGoing
+/// # Returns
+/// * `AttendeeJoin` - The updated join with the RSVP and the time it was set
+/// # Errors
+/// * `ApiError` - If the caller has not joined the event
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
 #[update(guard = "has_access")]
-pub fn join_event(event_id: u64) -> Result<JoinedAttendeeResponse, ApiError> {
-    EventCalls::join_event(event_id)
+pub fn set_rsvp(event_id: u64, status: RsvpStatus) -> Result<AttendeeJoin, ApiError> {
+    EventCalls::set_rsvp(event_id, status)
 }
 
 /// Invite a user to an event - [`[update]`](update)
@@ -177,7 +418,7 @@ pub fn invite_to_event(
 /// * `group_id` - Used to check if the user has access to the group the event belongs to
 /// * `attendee_principal` - The principal of the user to accept
 /// # Returns
-/// * `JoinedAttendeeResponse` - the event join details
+/// * `JoinedAttendeeResponse` - the event join details, `waitlist_position` is set when the event is full
 /// # Errors
 /// * `ApiError` - If something went wrong while accepting the user invite to the event
 /// # Note
@@ -204,7 +445,7 @@ pub fn accept_user_request_event_invite(
 /// * `group_id` - Used to check if the user has access to the group the event belongs to
 /// * `attendee_principal` - The principal of the user to accept
 /// # Returns
-/// * `JoinedAttendeeResponse` - the event join details
+/// * `JoinedAttendeeResponse` - the event join details, `waitlist_position` is set when the event is full
 /// # Errors
 /// * `ApiError` - If something went wrong while accepting the user invite to the event
 /// # Note
@@ -229,13 +470,15 @@ pub fn decline_user_request_event_invite(
 /// # Arguments
 /// * `event_id` - The identifier of the event
 /// # Returns
-/// * `Attendee` - The attendee
+/// * `JoinedAttendeeResponse` - the event join details, `waitlist_position` is set when the event is full
 /// # Errors
 /// * `ApiError` - If something went wrong while accepting the owner invite to the event
 /// # Note
 /// This function is guarded by the [`has_access`](has_access) function.
 #[update(guard = "has_access")]
-pub fn accept_owner_request_event_invite(event_id: u64) -> Result<Attendee, ApiError> {
+pub fn accept_owner_request_event_invite(
+    event_id: u64,
+) -> Result<JoinedAttendeeResponse, ApiError> {
     EventCalls::accept_or_decline_owner_request_event_invite(event_id, true)
 }
 
@@ -243,13 +486,15 @@ pub fn accept_owner_request_event_invite(event_id: u64) -> Result<Attendee, ApiE
 /// # Arguments
 /// * `event_id` - The identifier of the event
 /// # Returns
-/// * `Attendee` - The attendee
+/// * `JoinedAttendeeResponse` - the declined invite details
 /// # Errors
-/// * `ApiError` - If something went wrong while accepting the owner invite to the event
+/// * `ApiError` - If something went wrong while declining the owner invite to the event
 /// # Note
 /// This function is guarded by the [`has_access`](has_access) function.
 #[update(guard = "has_access")]
-pub fn decline_owner_request_event_invite(event_id: u64) -> Result<Attendee, ApiError> {
+pub fn decline_owner_request_event_invite(
+    event_id: u64,
+) -> Result<JoinedAttendeeResponse, ApiError> {
     EventCalls::accept_or_decline_owner_request_event_invite(event_id, false)
 }
 
@@ -276,6 +521,7 @@ pub fn get_event_attendees(event_id: u64) -> Result<Vec<JoinedAttendeeResponse>,
 /// * `ApiError` - If something went wrong while getting the attendees for the event
 /// # Note
 /// This function is guarded by the [`has_access`](has_access) function.
+/// The roles contain the group roles of the attendee followed by the names of their event roles.
 #[query(guard = "has_access")]
 pub fn get_event_attendees_profiles_and_roles(
     event_id: u64,
@@ -352,6 +598,52 @@ pub fn leave_event(event_id: u64) -> Result<(), ApiError> {
     EventCalls::leave_event(event_id)
 }
 
+/// Leave the waitlist of an event as a caller - [`[update]`](update)
+/// # Arguments
+/// * `event_id` - The identifier of the event
+/// # Returns
+/// * `()` - Empty tuple
+/// # Errors
+/// * `ApiError` - If the caller is not on the waitlist
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+#[update(guard = "has_access")]
+pub fn leave_event_waitlist(event_id: u64) -> Result<(), ApiError> {
+    EventCalls::leave_event_waitlist(event_id)
+}
+
+/// Get the waitlist of an event in order - [`[query]`](query)
+/// # Arguments
+/// * `event_id` - The identifier of the event
+/// * `group_id` - Used to check if the user has access to the group the event belongs to
+/// # Returns
+/// * `Vec<Principal>` - The principals on the waitlist, first in line first
+/// # Errors
+/// * `ApiError` - If something went wrong while getting the waitlist
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+#[query(guard = "has_access")]
+pub fn get_event_waitlist(event_id: u64, group_id: u64) -> Result<Vec<Principal>, ApiError> {
+    can_read(group_id, PermissionType::Event(None))?;
+    EventCalls::get_event_waitlist(event_id, group_id)
+}
+
+/// Get the ticket sales of an event - [`[query]`](query)
+/// # Arguments
+/// * `event_id` - The identifier of the event
+/// * `group_id` - Used to check if the user has access to the group the event belongs to
+/// # Returns
+/// * `TicketSales` - The ticket receipts with the number of tickets sold and the total amount in e8s
+/// # Errors
+/// * `ApiError` - If something went wrong while getting the ticket sales
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+#[query(guard = "has_access")]
+pub fn get_event_ticket_sales(event_id: u64, group_id: u64) -> Result<TicketSales, ApiError> {
+    can_edit(group_id, PermissionType::Event(None))?;
+    EventCalls::get_event_ticket_sales(event_id, group_id)
+}
+
 /// Remove an event invite as a user - [`[update]`](update)
 /// # Change
 /// * was `remove_event`
@@ -428,3 +720,354 @@ pub fn get_event_invites(
 ) -> Result<Vec<InviteAttendeeResponse>, ApiError> {
     EventCalls::get_event_invites(event_id, group_id)
 }
+
+/// Assign an event role to an attendee - [`[update]`](update)
+/// # Arguments
+/// * `event_id` - The identifier of the event
+/// * `group_id` - Used to check if the user has access to the group the event belongs to
+/// * `attendee_principal` - The principal of the attendee
+/// * `role` - The event role to assign
+/// # Returns
+/// * `EventRoleResponse` - The event roles of the attendee
+/// # Errors
+/// * `ApiError` - If the principal has not joined the event
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+/// Next to `Attendee` edit, the caller needs the group permissions behind the event permissions of the role,
+/// e.g. `Event` edit and delete to assign `EventRole::Host`.
+#[update(guard = "has_access")]
+pub fn assign_event_role(
+    event_id: u64,
+    group_id: u64,
+    attendee_principal: Principal,
+    role: EventRole,
+) -> Result<EventRoleResponse, ApiError> {
+    can_manage_event_role(group_id, &role)?;
+    EventCalls::assign_event_role(event_id, group_id, attendee_principal, role)
+}
+
+/// Remove an event role from an attendee - [`[update]`](update)
+/// # Arguments
+/// * `event_id` - The identifier of the event
+/// * `group_id` - Used to check if the user has access to the group the event belongs to
+/// * `attendee_principal` - The principal of the attendee
+/// * `role` - The event role to remove
+/// # Returns
+/// * `EventRoleResponse` - The event roles of the attendee
+/// # Errors
+/// * `ApiError` - If the principal has not joined the event
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+/// The caller needs the same permissions as for [`assign_event_role`](assign_event_role).
+#[update(guard = "has_access")]
+pub fn remove_event_role(
+    event_id: u64,
+    group_id: u64,
+    attendee_principal: Principal,
+    role: EventRole,
+) -> Result<EventRoleResponse, ApiError> {
+    can_manage_event_role(group_id, &role)?;
+    EventCalls::remove_event_role(event_id, group_id, attendee_principal, role)
+}
+
+/// Get the attendees with an event role that is listed publicly, like speakers - [`[query]`](query)
+/// # Arguments
+/// * `event_id` - The identifier of the event
+/// # Returns
+/// * `Vec<(ProfileResponse, EventRoleResponse)>` - The profiles with the event roles
+/// # Errors
+/// * `ApiError` - If the event is not found or the caller is not allowed to see it
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+#[query(guard = "has_access")]
+pub fn get_event_role_holders(
+    event_id: u64,
+) -> Result<Vec<(ProfileResponse, EventRoleResponse)>, ApiError> {
+    EventCalls::get_event_role_holders(event_id)
+}
+
+/// Create a one-time check-in code for an event - [`[update]`](update)
+/// # Arguments
+/// * `event_id` - The identifier of the event
+/// * `group_id` - Used to check if the user has access to the group the event belongs to
+/// # Returns
+/// * `CheckInCode` - The code, it can be redeemed once before it expires
+/// # Errors
+/// * `ApiError` - If the caller is not allowed to manage attendees
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+/// Codes expire after a few minutes, a new code should be created for every check-in.
+#[update(guard = "has_access")]
+pub async fn create_check_in_code(event_id: u64, group_id: u64) -> Result<CheckInCode, ApiError> {
+    can_manage_event(
+        group_id,
+        event_id,
+        PermissionType::Attendee(None),
+        PermissionActionType::Edit,
+        EventPermission::ManageCheckIn,
+    )?;
+    CheckInCalls::create_check_in_code(event_id, group_id).await
+}
+
+/// Check in to an event with a code shown by an organiser - [`[update]`](update)
+/// # Arguments
+/// * `event_id` - The identifier of the event
+/// * `code` - The one-time check-in code
+/// # Returns
+/// * `CheckIn` - The check-in of the caller
+/// # Errors
+/// * `ApiError` - If the event is not taking place, the code is invalid or the caller is already checked in
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+#[update(guard = "has_access")]
+pub fn redeem_check_in_code(event_id: u64, code: String) -> Result<CheckIn, ApiError> {
+    CheckInCalls::redeem_check_in_code(event_id, code)
+}
+
+/// Manually check in an attendee - [`[update]`](update)
+/// # Arguments
+/// * `event_id` - The identifier of the event
+/// * `group_id` - Used to check if the user has access to the group the event belongs to
+/// * `attendee_principal` - The principal of the attendee to check in
+/// # Returns
+/// * `CheckIn` - The check-in of the attendee
+/// # Errors
+/// * `ApiError` - If the event is not taking place, the principal has not joined the event or is already checked in
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+#[update(guard = "has_access")]
+pub fn check_in_attendee(
+    event_id: u64,
+    group_id: u64,
+    attendee_principal: Principal,
+) -> Result<CheckIn, ApiError> {
+    can_manage_event(
+        group_id,
+        event_id,
+        PermissionType::Attendee(None),
+        PermissionActionType::Edit,
+        EventPermission::ManageCheckIn,
+    )?;
+    CheckInCalls::check_in_attendee(event_id, group_id, attendee_principal)
+}
+
+/// Get the check-ins of an event (occurrence) with the no-show rate - [`[query]`](query)
+/// # Arguments
+/// * `event_id` - The identifier of the event
+/// * `group_id` - Used to check if the user has access to the group the event belongs to
+/// * `occurrence_start` - The start of the occurrence, defaults to the last occurrence that started
+/// # Returns
+/// * `EventCheckInSummary` - The check-ins and the share of going attendees that did not show up
+/// # Errors
+/// * `ApiError` - If something went wrong while getting the check-ins
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+#[query(guard = "has_access")]
+pub fn get_event_check_ins(
+    event_id: u64,
+    group_id: u64,
+    occurrence_start: Option<u64>,
+) -> Result<EventCheckInSummary, ApiError> {
+    can_manage_event(
+        group_id,
+        event_id,
+        PermissionType::Attendee(None),
+        PermissionActionType::Read,
+        EventPermission::ManageCheckIn,
+    )?;
+    CheckInCalls::get_event_check_ins(event_id, group_id, occurrence_start)
+}
+
+/// Add a session to the agenda of an event - [`[update]`](update)
+/// # Arguments
+/// * `event_id` - The identifier of the event
+/// * `group_id` - Used to check if the user has access to the group the event belongs to
+/// * `post_session` - The session to add
+/// # Returns
+/// * `EventSessionResponse` - The added session
+/// # Errors
+/// * `ApiError` - If the session is invalid or does not take place within the event date
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+#[update(guard = "has_access")]
+pub fn add_event_session(
+    event_id: u64,
+    group_id: u64,
+    post_session: PostEventSession,
+) -> Result<EventSessionResponse, ApiError> {
+    can_manage_event(
+        group_id,
+        event_id,
+        PermissionType::Event(None),
+        PermissionActionType::Edit,
+        EventPermission::ManageAgenda,
+    )?;
+    SessionCalls::add_event_session(event_id, group_id, post_session)
+}
+
+/// Edit a session of an event - [`[update]`](update)
+/// # Arguments
+/// * `event_id` - The identifier of the event
+/// * `group_id` - Used to check if the user has access to the group the event belongs to
+/// * `session_id` - The identifier of the session
+/// * `post_session` - The updated session
+/// # Returns
+/// * `EventSessionResponse` - The updated session
+/// # Errors
+/// * `ApiError` - If the session is not found, invalid or does not take place within the event date
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+#[update(guard = "has_access")]
+pub fn edit_event_session(
+    event_id: u64,
+    group_id: u64,
+    session_id: u64,
+    post_session: PostEventSession,
+) -> Result<EventSessionResponse, ApiError> {
+    can_manage_event(
+        group_id,
+        event_id,
+        PermissionType::Event(None),
+        PermissionActionType::Edit,
+        EventPermission::ManageAgenda,
+    )?;
+    SessionCalls::edit_event_session(event_id, group_id, session_id, post_session)
+}
+
+/// Remove a session from the agenda of an event - [`[update]`](update)
+/// # Arguments
+/// * `event_id` - The identifier of the event
+/// * `group_id` - Used to check if the user has access to the group the event belongs to
+/// * `session_id` - The identifier of the session
+/// # Returns
+/// * `()` - If the session was removed
+/// # Errors
+/// * `ApiError` - If the session is not found
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+#[update(guard = "has_access")]
+pub fn remove_event_session(event_id: u64, group_id: u64, session_id: u64) -> Result<(), ApiError> {
+    can_manage_event(
+        group_id,
+        event_id,
+        PermissionType::Event(None),
+        PermissionActionType::Delete,
+        EventPermission::ManageAgenda,
+    )?;
+    SessionCalls::remove_event_session(event_id, group_id, session_id)
+}
+
+/// Bookmark a session to attend, or remove the bookmark - [`[update]`](update)
+/// # Arguments
+/// * `event_id` - The identifier of the event
+/// * `session_id` - The identifier of the session
+/// * `bookmark` - Whether the caller wants to attend the session
+/// # Returns
+/// * `EventSessionResponse` - The session
+/// # Errors
+/// * `ApiError` - If the caller has not joined the event or the session is not found
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+#[update(guard = "has_access")]
+pub fn bookmark_event_session(
+    event_id: u64,
+    session_id: u64,
+    bookmark: bool,
+) -> Result<EventSessionResponse, ApiError> {
+    SessionCalls::bookmark_event_session(event_id, session_id, bookmark)
+}
+
+/// Get the agenda of an event - [`[query]`](query)
+/// # Arguments
+/// * `event_id` - The identifier of the event
+/// # Returns
+/// * `Vec<EventSessionResponse>` - The sessions of the event, ordered by start date
+/// # Errors
+/// * `ApiError` - If the event is not found or the caller is not allowed to see it
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+#[query(guard = "has_access")]
+pub fn get_event_agenda(event_id: u64) -> Result<Vec<EventSessionResponse>, ApiError> {
+    SessionCalls::get_event_agenda(event_id)
+}
+
+/// Rate an event that has ended - [`[update]`](update)
+/// # Arguments
+/// * `event_id` - The identifier of the event
+/// * `rating` - The rating, from 1 to 5
+/// * `comment` - An optional comment
+/// # Returns
+/// * `EventFeedback` - The submitted feedback
+/// # Errors
+/// * `ApiError` - If the event has not ended, the caller did not join the event or already gave feedback
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+#[update(guard = "has_access")]
+pub fn submit_event_feedback(
+    event_id: u64,
+    rating: u8,
+    comment: Option<String>,
+) -> Result<EventFeedback, ApiError> {
+    FeedbackCalls::submit_event_feedback(event_id, rating, comment)
+}
+
+/// Get the feedback of an event with the aggregated ratings - [`[query]`](query)
+/// # Arguments
+/// * `event_id` - The identifier of the event
+/// * `group_id` - Used to check if the user has access to the group the event belongs to
+/// # Returns
+/// * `EventFeedbackSummary` - The feedback, the average rating and the number of ratings per score
+/// # Errors
+/// * `ApiError` - If something went wrong while getting the feedback
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+#[query(guard = "has_access")]
+pub fn get_event_feedback(event_id: u64, group_id: u64) -> Result<EventFeedbackSummary, ApiError> {
+    can_read(group_id, PermissionType::Event(None))?;
+    FeedbackCalls::get_event_feedback(event_id, group_id)
+}
+
+/// Create a private calendar feed token for the caller - [`[update]`](update)
+/// # Returns
+/// * `CalendarFeedResponse` - The token and the path to `GET /calendar/{token}.ics`
+/// # Errors
+/// * `ApiError` - If something went wrong while creating the token
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+/// The feed lists every event the caller attends or is invited to, a previous token stops working.
+#[update(guard = "has_access")]
+pub async fn create_calendar_feed_token() -> Result<CalendarFeedResponse, ApiError> {
+    CalendarCalls::create_calendar_feed_token().await
+}
+
+/// Get the calendar feed token of the caller - [`[query]`](query)
+/// # Returns
+/// * `CalendarFeedResponse` - The token and the path to `GET /calendar/{token}.ics`
+/// # Errors
+/// * `ApiError` - If the caller has no calendar feed token
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+#[query(guard = "has_access")]
+pub fn get_calendar_feed_token() -> Result<CalendarFeedResponse, ApiError> {
+    CalendarCalls::get_calendar_feed_token()
+}
+
+/// Revoke the calendar feed token of the caller - [`[update]`](update)
+/// # Returns
+/// * `()` - If the token was revoked
+/// # Errors
+/// * `ApiError` - If the caller has no calendar feed token
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+#[update(guard = "has_access")]
+pub fn revoke_calendar_feed_token() -> Result<(), ApiError> {
+    CalendarCalls::revoke_calendar_feed_token()
+}
+
+/// Get the supported time zones for events - [`[query]`](query)
+/// # Returns
+/// * `Vec<String>` - The IANA identifiers that can be used as `time_zone`
+#[query]
+pub fn get_time_zones() -> Vec<String> {
+    TimeZone::names()
+}
@@ -1,6 +1,13 @@
 use crate::{
-    helpers::guards::{is_developer, is_prod_developer},
-    logic::{boost_logic::BoostCalls, id_logic::IDLogic, websocket_logic::Websocket},
+    helpers::{
+        export_token::ExportKind,
+        guards::{is_developer, is_prod_developer},
+    },
+    logic::{
+        boost_logic::BoostCalls, calendar_logic::CalendarCalls, event_logic::EventCalls,
+        export_logic::ExportCalls, group_logic::GroupCalls, id_logic::IDLogic,
+        websocket_logic::Websocket,
+    },
     storage::{
         reward_canister_storage::RewardCanisterStorage, storage_api::StorageQueryable,
         AttendeeStore, BoostedStore, CellStorage, EventAttendeeStore, EventStore,
@@ -28,6 +35,12 @@ pub fn post_upgrade() {
     Websocket::init();
     RewardTimerStore::start();
     BoostCalls::start_timers_after_upgrade();
+    GroupCalls::remap_legacy_role_indices_after_upgrade();
+    GroupCalls::start_transfer_request_timers_after_upgrade();
+    GroupCalls::start_moderation_timers_after_upgrade();
+    EventCalls::start_reminder_timers_after_upgrade();
+    EventCalls::start_publish_timers_after_upgrade();
+    GroupCalls::start_publish_timers_after_upgrade();
 }
 
 #[pre_upgrade]
@@ -185,7 +198,12 @@ fn _dev_clear() {
 
 #[query]
 fn http_request(req: HttpRequest) -> HttpResponse {
-    let mut path: Vec<&str> = req.url.as_str().split('/').collect();
+    let (url, query) = req.url.split_once('?').unwrap_or((req.url.as_str(), ""));
+    let token = query
+        .split('&')
+        .find_map(|param| param.strip_prefix("token="));
+
+    let mut path: Vec<&str> = url.split('/').collect();
     path.retain(|p| !p.is_empty());
 
     let permission_denied = HttpResponse {
@@ -194,13 +212,21 @@ fn http_request(req: HttpRequest) -> HttpResponse {
         body: vec![],
     };
     match req.method.as_str() {
-        "GET" => match path[0] {
-            "version" => HttpResponse {
+        "GET" => match path.as_slice() {
+            ["groups", group_id, "members.csv"] => {
+                ExportCalls::http_export(ExportKind::GroupMembers, group_id, token)
+            }
+            ["events", event_id, "attendees.csv"] => {
+                ExportCalls::http_export(ExportKind::EventAttendees, event_id, token)
+            }
+            ["events", file_name] => CalendarCalls::http_event_ics(file_name),
+            ["calendar", file_name] => CalendarCalls::http_calendar_ics(file_name),
+            ["version", ..] => HttpResponse {
                 status_code: 200,
                 headers: vec![],
                 body: env!("CARGO_PKG_VERSION").as_bytes().to_vec(),
             },
-            "changelog" => HttpResponse {
+            ["changelog", ..] => HttpResponse {
                 status_code: 200,
                 headers: vec![],
                 body: include_bytes!("../../../CHANGELOG.md").to_vec(),
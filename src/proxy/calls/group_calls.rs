@@ -1,21 +1,29 @@
 use crate::{
     helpers::{
-        group_permission::{can_delete, can_edit, can_read},
-        guards::has_access,
+        export_token::ExportKind,
+        group_permission::{
+            can_delete, can_edit, can_manage_member, can_manage_rank, can_manage_role, can_read,
+            has_permission_by_name,
+        },
+        guards::{has_access, is_application_admin},
     },
-    logic::group_logic::GroupCalls,
+    logic::{audit_log_logic::AuditLogCalls, export_logic::ExportCalls, group_logic::GroupCalls},
 };
 
 use candid::Principal;
 use canister_types::models::{
     api_error::ApiError,
+    application_role::ApplicationRole,
+    audit_log::{AuditLogFilter, AuditLogResponse},
     group::{GroupFilter, GroupResponse, GroupSort, GroupsCount, PostGroup, UpdateGroup},
+    group_creation_limit::{GroupCreationLimits, GroupCreationQuota},
+    group_moderation::ModerationRecord,
     group_transfer_request::GroupTransferRequest,
+    http_types::ExportTokenResponse,
     member::{InviteMemberResponse, JoinedMemberResponse, Member},
     paged_response::PagedResponse,
-    permission::{PermissionType, PostPermission},
+    permission::{CustomPermission, PermissionActionType, PermissionType, PostPermission},
     profile::ProfileResponse,
-    relation_type::RelationType,
     role::Role,
 };
 use ic_cdk::{caller, query, update};
@@ -38,6 +46,98 @@ pub async fn add_group(
     GroupCalls::add_group(post_group, account_identifier).await
 }
 
+/// Create a new group with the setup of an existing group - [`[update]`](update)
+/// # Arguments
+/// * `group_id` - The identifier of the group to clone
+/// * `new_name` - The name of the new group
+/// * `account_identifier` - Used to check ownership of the required neuron or NFT for gated groups
+/// # Returns
+/// * `GroupResponse` - The new group
+/// # Errors
+/// * `ApiError` - If the name is taken, the group creation limit is reached or something went wrong while adding the group
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+/// Descriptive fields, tags, images, the location, custom roles and permissions are copied, members and events are not.
+#[update(guard = "has_access")]
+pub async fn clone_group(
+    group_id: u64,
+    new_name: String,
+    account_identifier: Option<String>,
+) -> Result<GroupResponse, ApiError> {
+    can_edit(group_id, PermissionType::Group(None))?;
+    GroupCalls::clone_group(group_id, new_name, account_identifier).await
+}
+
+/// Get the number of groups the caller owns and is allowed to own - [`[query]`](query)
+/// # Returns
+/// * `GroupCreationQuota` - The limit and current usage of the caller
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+#[query(guard = "has_access")]
+pub fn get_group_creation_quota() -> GroupCreationQuota {
+    GroupCalls::get_group_creation_quota(caller())
+}
+
+/// Get the configured group creation limits - [`[query]`](query)
+/// # Returns
+/// * `GroupCreationLimits` - The default limit, the limits per application role and the principal overrides
+/// # Note
+/// This function is guarded by the [`is_application_admin`](is_application_admin) function.
+#[query(guard = "is_application_admin")]
+pub fn get_group_creation_limits() -> GroupCreationLimits {
+    GroupCalls::get_group_creation_limits()
+}
+
+/// Set the group creation limit used when no role limit or override applies - [`[update]`](update)
+/// # Arguments
+/// * `limit` - The maximum number of groups a principal can own
+/// # Returns
+/// * `GroupCreationLimits` - The updated limits
+/// # Errors
+/// * `ApiError` - If something went wrong while storing the limits
+/// # Note
+/// This function is guarded by the [`is_application_admin`](is_application_admin) function.
+#[update(guard = "is_application_admin")]
+pub fn set_group_creation_default_limit(limit: u64) -> Result<GroupCreationLimits, ApiError> {
+    GroupCalls::set_group_creation_default_limit(limit)
+}
+
+/// Set the group creation limit for an application role - [`[update]`](update)
+/// # Arguments
+/// * `role` - The application role
+/// * `limit` - The maximum number of groups, `None` removes the limit of the role
+/// # Returns
+/// * `GroupCreationLimits` - The updated limits
+/// # Errors
+/// * `ApiError` - If something went wrong while storing the limits
+/// # Note
+/// This function is guarded by the [`is_application_admin`](is_application_admin) function.
+#[update(guard = "is_application_admin")]
+pub fn set_group_creation_role_limit(
+    role: ApplicationRole,
+    limit: Option<u64>,
+) -> Result<GroupCreationLimits, ApiError> {
+    GroupCalls::set_group_creation_role_limit(role, limit)
+}
+
+/// Set the group creation limit for a single principal, takes precedence over the role limit - [`[update]`](update)
+/// # Arguments
+/// * `principal` - The principal to set the override for
+/// * `limit` - The maximum number of groups, `None` removes the override
+/// # Returns
+/// * `GroupCreationLimits` - The updated limits
+/// # Errors
+/// * `ApiError` - If something went wrong while storing the limits
+/// # Note
+/// This function is guarded by the [`is_application_admin`](is_application_admin) function.
+#[update(guard = "is_application_admin")]
+pub fn set_group_creation_limit_override(
+    principal: Principal,
+    limit: Option<u64>,
+) -> Result<GroupCreationLimits, ApiError> {
+    GroupCalls::set_group_creation_limit_override(principal, limit)
+}
+
 /// Get a group - [`[query]`](query)
 /// # Arguments
 /// * `group_id` - The identifier of the group
@@ -110,6 +210,23 @@ pub fn edit_group(group_id: u64, update_group: UpdateGroup) -> Result<GroupRespo
     GroupCalls::edit_group(group_id, update_group)
 }
 
+/// Publish a draft group, now or at a later moment - [`[update]`](update)
+/// # Arguments
+/// * `group_id` - The identifier of the group
+/// * `publish_at` - When to publish the group, `None` publishes it right away
+/// # Returns
+/// * `GroupResponse` - The (scheduled) group
+/// # Errors
+/// * `ApiError` - If the group is already published or `publish_at` is in the past
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+/// Members of the group are notified once the group is published.
+#[update(guard = "has_access")]
+pub fn publish_group(group_id: u64, publish_at: Option<u64>) -> Result<GroupResponse, ApiError> {
+    can_edit(group_id, PermissionType::Group(None))?;
+    GroupCalls::publish_group(group_id, publish_at)
+}
+
 /// Get groups by their identifiers - [`[query]`](query)
 /// # Arguments
 /// * `group_ids` - The identifiers of the groups
@@ -186,13 +303,16 @@ pub fn remove_wallet_from_group(
 /// * `group_id` - The identifier of the group
 /// * `role_name` - The name of the role
 /// * `color` - The color of the role
-/// * `index` - The index of the role
+/// * `index` - The index of the role, determines the rank of the role (higher is more senior)
+/// * `custom_permissions` - Optional custom permissions of the group to attach to the role
 /// # Returns
 /// * `Role` - The added role
 /// # Errors
 /// * `ApiError` - If something went wrong while adding the role
 /// # Note
 /// This function is guarded by the [`has_access`](has_access) function.
+/// The index needs to be lower than the index of the caller's highest role,
+/// and at least the index of the member role (100).
 /// Was `add_role`
 #[update(guard = "has_access")]
 pub fn add_role_to_group(
@@ -200,9 +320,11 @@ pub fn add_role_to_group(
     role_name: String,
     color: String,
     index: u64,
+    custom_permissions: Option<Vec<PostPermission>>,
 ) -> Result<Role, ApiError> {
     can_edit(group_id, PermissionType::Group(None))?;
-    GroupCalls::add_role_to_group(group_id, role_name, color, index)
+    can_manage_rank(group_id, index)?;
+    GroupCalls::add_role_to_group(group_id, role_name, color, index, custom_permissions)
 }
 
 /// Remove a role from the group - [`[update]`](update)
@@ -217,9 +339,11 @@ pub fn add_role_to_group(
 /// * `ApiError` - If something went wrong while removing the role
 /// # Note
 /// This function is guarded by the [`has_access`](has_access) function.
+/// The caller needs to rank higher than the role that is being removed.
 #[update(guard = "has_access")]
 pub fn remove_group_role(group_id: u64, role_name: String) -> Result<bool, ApiError> {
     can_edit(group_id, PermissionType::Group(None))?;
+    can_manage_role(group_id, &role_name)?;
     GroupCalls::remove_group_role(group_id, role_name)
 }
 
@@ -248,6 +372,7 @@ pub fn get_group_roles(group_id: u64) -> Result<Vec<Role>, ApiError> {
 /// * `ApiError` - If something went wrong while updating the permissions
 /// # Note
 /// This function is guarded by the [`has_access`](has_access) function.
+/// The caller needs to rank higher than the role that is being edited.
 #[update(guard = "has_access")]
 pub fn edit_role_permissions(
     group_id: u64,
@@ -255,9 +380,84 @@ pub fn edit_role_permissions(
     post_permissions: Vec<PostPermission>,
 ) -> Result<bool, ApiError> {
     can_edit(group_id, PermissionType::Group(None))?;
+    can_manage_role(group_id, &role_name)?;
     GroupCalls::edit_role_permissions(group_id, role_name, post_permissions)
 }
 
+/// Add a custom permission to the group - [`[update]`](update)
+/// # Arguments
+/// * `group_id` - The identifier of the group
+/// * `name` - The name of the permission, used as the key when checking the permission
+/// * `description` - The description of the permission
+/// # Returns
+/// * `CustomPermission` - The added custom permission
+/// # Errors
+/// * `ApiError` - If something went wrong while adding the custom permission
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+/// The owner and admin roles get full access to the custom permission, other roles need to get it attached
+#[update(guard = "has_access")]
+pub fn add_custom_permission_to_group(
+    group_id: u64,
+    name: String,
+    description: String,
+) -> Result<CustomPermission, ApiError> {
+    can_edit(group_id, PermissionType::Group(None))?;
+    GroupCalls::add_custom_permission_to_group(group_id, name, description)
+}
+
+/// Remove a custom permission from the group - [`[update]`](update)
+/// # Arguments
+/// * `group_id` - The identifier of the group
+/// * `name` - The name of the permission
+/// # Returns
+/// * `bool` - Whether the custom permission was removed
+/// # Errors
+/// * `ApiError` - If something went wrong while removing the custom permission
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+/// The custom permission is also removed from the roles it was attached to
+#[update(guard = "has_access")]
+pub fn remove_custom_permission_from_group(group_id: u64, name: String) -> Result<bool, ApiError> {
+    can_edit(group_id, PermissionType::Group(None))?;
+    GroupCalls::remove_custom_permission_from_group(group_id, name)
+}
+
+/// Get the custom permissions of the group - [`[query]`](query)
+/// # Arguments
+/// * `group_id` - The identifier of the group
+/// # Returns
+/// * `Vec<CustomPermission>` - The custom permissions of the group
+/// # Errors
+/// * `ApiError` - If something went wrong while getting the custom permissions
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+#[query(guard = "has_access")]
+pub fn get_group_custom_permissions(group_id: u64) -> Result<Vec<CustomPermission>, ApiError> {
+    can_read(group_id, PermissionType::Group(None))?;
+    GroupCalls::get_group_custom_permissions(group_id)
+}
+
+/// Check if a principal has a default or custom permission within a group - [`[query]`](query)
+/// # Arguments
+/// * `group_id` - The identifier of the group
+/// * `principal` - The principal to check the permission for
+/// * `permission` - The name of the permission
+/// * `action` - The action to check
+/// # Returns
+/// * `bool` - Whether the principal has the permission
+/// # Note
+/// This function is not guarded so other canisters can use it to check permissions
+#[query]
+pub fn has_group_permission(
+    group_id: u64,
+    principal: Principal,
+    permission: String,
+    action: PermissionActionType,
+) -> bool {
+    has_permission_by_name(principal, group_id, &permission, &action).is_ok()
+}
+
 /// Join a group - [`[update]`](update)
 /// # Arguments
 /// * `group_id` - The identifier of the group to join
@@ -268,6 +468,7 @@ pub fn edit_role_permissions(
 /// * `ApiError` - If something went wrong while joining the group
 /// # Note
 /// This function is guarded by the [`has_access`](has_access) function.
+/// When the group reached `max_members` the caller is put on the waitlist and `waitlist_position` is set
 #[update(guard = "has_access")]
 pub async fn join_group(
     group_id: u64,
@@ -298,7 +499,7 @@ pub fn invite_to_group(group_id: u64, member_principal: Principal) -> Result<Mem
 /// * `group_id` - The identifier of the group to accept the invite for
 /// * `member_principal` - The principal of the user to accept the invite for
 /// # Returns
-/// * `Member` - The updated member entry
+/// * `JoinedMemberResponse` - The joined member details, `waitlist_position` is set when the group is full
 /// # Errors
 /// * `ApiError` - If something went wrong while accepting the invite
 /// # Note
@@ -307,7 +508,7 @@ pub fn invite_to_group(group_id: u64, member_principal: Principal) -> Result<Mem
 pub fn accept_user_request_group_invite(
     group_id: u64,
     member_principal: Principal,
-) -> Result<Member, ApiError> {
+) -> Result<JoinedMemberResponse, ApiError> {
     can_edit(group_id, PermissionType::Invite(None))?;
     GroupCalls::accept_or_decline_user_request_group_invite(member_principal, group_id, true)
 }
@@ -317,7 +518,7 @@ pub fn accept_user_request_group_invite(
 /// * `group_id` - The identifier of the group to accept the invite for
 /// * `member_principal` - The principal of the user to accept the invite for
 /// # Returns
-/// * `Member` - The updated member entry
+/// * `JoinedMemberResponse` - The member details
 /// # Errors
 /// * `ApiError` - If something went wrong while declining the invite
 /// # Note
@@ -326,7 +527,7 @@ pub fn accept_user_request_group_invite(
 pub fn decline_user_request_group_invite(
     group_id: u64,
     member_principal: Principal,
-) -> Result<Member, ApiError> {
+) -> Result<JoinedMemberResponse, ApiError> {
     can_edit(group_id, PermissionType::Invite(None))?;
     GroupCalls::accept_or_decline_user_request_group_invite(member_principal, group_id, false)
 }
@@ -335,11 +536,11 @@ pub fn decline_user_request_group_invite(
 /// # Arguments
 /// * `group_id` - The identifier of the group to accept the invite for
 /// # Returns
-/// * `Member` - The updated member entry
+/// * `JoinedMemberResponse` - The joined member details, `waitlist_position` is set when the group is full
 /// # Errors
 /// * `ApiError` - If something went wrong while accepting the invite
 #[update(guard = "has_access")]
-pub fn accept_owner_request_group_invite(group_id: u64) -> Result<Member, ApiError> {
+pub fn accept_owner_request_group_invite(group_id: u64) -> Result<JoinedMemberResponse, ApiError> {
     GroupCalls::accept_or_decline_owner_request_group_invite(group_id, true)
 }
 
@@ -347,11 +548,11 @@ pub fn accept_owner_request_group_invite(group_id: u64) -> Result<Member, ApiErr
 /// # Arguments
 /// * `group_id` - The identifier of the group to accept the invite for
 /// # Returns
-/// * `Member` - The updated member entry
+/// * `JoinedMemberResponse` - The member details
 /// # Errors
 /// * `ApiError` - If something went wrong while declining the invite
 #[update(guard = "has_access")]
-pub fn decline_owner_request_group_invite(group_id: u64) -> Result<Member, ApiError> {
+pub fn decline_owner_request_group_invite(group_id: u64) -> Result<JoinedMemberResponse, ApiError> {
     GroupCalls::accept_or_decline_owner_request_group_invite(group_id, false)
 }
 
@@ -366,6 +567,7 @@ pub fn decline_owner_request_group_invite(group_id: u64) -> Result<Member, ApiEr
 /// * `ApiError` - If something went wrong while assigning the role
 /// # Note
 /// This function is guarded by the [`has_access`](has_access) function.
+/// The caller needs to rank higher than both the member and the role that is being assigned.
 #[update(guard = "has_access")]
 pub fn assign_role(
     group_id: u64,
@@ -373,6 +575,8 @@ pub fn assign_role(
     member_principal: Principal,
 ) -> Result<Member, ApiError> {
     can_edit(group_id, PermissionType::Group(None))?;
+    can_manage_member(group_id, member_principal)?;
+    can_manage_role(group_id, &role)?;
     GroupCalls::add_group_role_to_member(role, member_principal, group_id)
 }
 
@@ -389,7 +593,7 @@ pub fn assign_role(
 /// * `ApiError` - If something went wrong while removing the role
 /// # Note
 /// This function is guarded by the [`has_access`](has_access) function.
-/// TODO: This action is guarded by group role based authorization
+/// The caller needs to rank higher than both the member and the role that is being removed.
 #[update(guard = "has_access")]
 pub fn remove_member_role(
     group_id: u64,
@@ -397,6 +601,8 @@ pub fn remove_member_role(
     member_principal: Principal,
 ) -> Result<Member, ApiError> {
     can_edit(group_id, PermissionType::Group(None))?;
+    can_manage_member(group_id, member_principal)?;
+    can_manage_role(group_id, &role)?;
     GroupCalls::remove_group_role_from_member(role, member_principal, group_id)
 }
 
@@ -532,6 +738,35 @@ pub fn leave_group(group_id: u64) -> Result<(), ApiError> {
     GroupCalls::leave_group(group_id)
 }
 
+/// Leave the waitlist of a group as a caller - [`[update]`](update)
+/// # Arguments
+/// * `group_id` - The identifier of the group
+/// # Returns
+/// * `()` - Empty tuple
+/// # Errors
+/// * `ApiError` - If the caller is not on the waitlist
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+#[update(guard = "has_access")]
+pub fn leave_group_waitlist(group_id: u64) -> Result<(), ApiError> {
+    GroupCalls::leave_group_waitlist(group_id)
+}
+
+/// Get the waitlist of a group in order - [`[query]`](query)
+/// # Arguments
+/// * `group_id` - The identifier of the group
+/// # Returns
+/// * `Vec<Principal>` - The principals on the waitlist, first in line first
+/// # Errors
+/// * `ApiError` - If something went wrong while getting the waitlist
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+#[query(guard = "has_access")]
+pub fn get_group_waitlist(group_id: u64) -> Result<Vec<Principal>, ApiError> {
+    can_read(group_id, PermissionType::Member(None))?;
+    GroupCalls::get_group_waitlist(group_id)
+}
+
 /// Remove an invite for a group as a user
 /// # Arguments
 /// * `group_id` - The identifier of the group to remove the invite for
@@ -556,10 +791,11 @@ pub fn remove_invite(group_id: u64) -> Result<(), ApiError> {
 /// * `ApiError` - If something went wrong while removing the member
 /// # Note
 /// This function is guarded by the [`has_access`](has_access) function.
-/// TODO: This action is guarded by group role based authorization
+/// The caller needs to rank higher than the member that is being removed.
 #[update(guard = "has_access")]
 pub fn remove_member_from_group(group_id: u64, principal: Principal) -> Result<(), ApiError> {
     can_delete(group_id, PermissionType::Member(None))?;
+    can_manage_member(group_id, principal)?;
     GroupCalls::remove_member_from_group(principal, group_id)
 }
 
@@ -616,17 +852,123 @@ pub fn get_group_invites_with_profiles(
     GroupCalls::get_group_invites_with_profiles(group_id)
 }
 
+/// Get the ban records of a group - [`[query]`](query)
+/// # Arguments
+/// * `group_id` - The identifier of the group
+/// # Returns
+/// * `Vec<ModerationRecord>` - The ban records, including reason, issuer and expiry
+/// # Errors
+/// * `ApiError` - If the caller is not allowed to manage members
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
 #[query(guard = "has_access")]
-pub fn get_banned_group_members(group_id: u64) -> Result<Vec<Principal>, ApiError> {
+pub fn get_banned_group_members(group_id: u64) -> Result<Vec<ModerationRecord>, ApiError> {
     can_edit(group_id, PermissionType::Member(None))?;
     Ok(GroupCalls::get_banned_group_members(group_id))
 }
 
+/// Get the mute records of a group - [`[query]`](query)
+/// # Arguments
+/// * `group_id` - The identifier of the group
+/// # Returns
+/// * `Vec<ModerationRecord>` - The mute records, including reason, issuer and expiry
+/// # Errors
+/// * `ApiError` - If the caller is not allowed to manage members
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+#[query(guard = "has_access")]
+pub fn get_muted_group_members(group_id: u64) -> Result<Vec<ModerationRecord>, ApiError> {
+    can_edit(group_id, PermissionType::Member(None))?;
+    Ok(GroupCalls::get_muted_group_members(group_id))
+}
+
+/// Get the audit log of a group, newest entries first - [`[query]`](query)
+/// # Arguments
+/// * `group_id` - The identifier of the group
+/// * `limit` - The maximum number of entries to return per page
+/// * `page` - The page number
+/// * `filters` - The filters to apply, for example on actor or action
+/// # Returns
+/// * `PagedResponse<AuditLogResponse>` - The audit log entries
+/// # Errors
+/// * `ApiError` - If something went wrong while getting the audit log
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+#[query(guard = "has_access")]
+pub fn get_group_audit_logs(
+    group_id: u64,
+    limit: usize,
+    page: usize,
+    filters: Vec<AuditLogFilter>,
+) -> Result<PagedResponse<AuditLogResponse>, ApiError> {
+    can_read(group_id, PermissionType::Group(None))?;
+    AuditLogCalls::get_group_audit_logs(group_id, limit, page, filters)
+}
+
+/// Create a short-lived token to download the members of a group as CSV - [`[update]`](update)
+/// # Arguments
+/// * `group_id` - The identifier of the group
+/// # Returns
+/// * `ExportTokenResponse` - The token and the path to `GET /groups/{id}/members.csv` with the token
+/// # Errors
+/// * `ApiError` - If the caller is not allowed to manage members
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
 #[update(guard = "has_access")]
-pub fn ban_group_member(group_id: u64, member_principal: Principal) -> Result<(), ApiError> {
+pub async fn create_group_members_export_token(
+    group_id: u64,
+) -> Result<ExportTokenResponse, ApiError> {
     can_edit(group_id, PermissionType::Member(None))?;
-    GroupCalls::remove_member_from_group(member_principal, group_id)?;
-    GroupCalls::add_special_member_to_group(group_id, member_principal, RelationType::Blocked)
+    ExportCalls::create_export_token(ExportKind::GroupMembers, group_id, group_id).await
+}
+
+/// Ban a member from a group - [`[update]`](update)
+/// # Arguments
+/// * `group_id` - The identifier of the group
+/// * `member_principal` - The principal of the member to ban
+/// * `reason` - The reason of the ban
+/// * `expires_at` - Optional timestamp (nanoseconds) when the ban is lifted, permanent if empty
+/// # Returns
+/// * `ModerationRecord` - The ban record
+/// # Errors
+/// * `ApiError` - If something went wrong while banning the member
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+/// The member is removed from the group
+#[update(guard = "has_access")]
+pub fn ban_group_member(
+    group_id: u64,
+    member_principal: Principal,
+    reason: String,
+    expires_at: Option<u64>,
+) -> Result<ModerationRecord, ApiError> {
+    can_edit(group_id, PermissionType::Member(None))?;
+    can_manage_member(group_id, member_principal)?;
+    GroupCalls::ban_group_member(group_id, member_principal, reason, expires_at)
+}
+
+/// Mute a member of a group, a muted member can not create events or reports - [`[update]`](update)
+/// # Arguments
+/// * `group_id` - The identifier of the group
+/// * `member_principal` - The principal of the member to mute
+/// * `reason` - The reason of the mute
+/// * `expires_at` - Optional timestamp (nanoseconds) when the mute is lifted, permanent if empty
+/// # Returns
+/// * `ModerationRecord` - The mute record
+/// # Errors
+/// * `ApiError` - If something went wrong while muting the member
+/// # Note
+/// This function is guarded by the [`has_access`](has_access) function.
+#[update(guard = "has_access")]
+pub fn mute_group_member(
+    group_id: u64,
+    member_principal: Principal,
+    reason: String,
+    expires_at: Option<u64>,
+) -> Result<ModerationRecord, ApiError> {
+    can_edit(group_id, PermissionType::Member(None))?;
+    can_manage_member(group_id, member_principal)?;
+    GroupCalls::mute_group_member(group_id, member_principal, reason, expires_at)
 }
 
 #[update(guard = "has_access")]
@@ -635,7 +977,16 @@ pub fn remove_ban_from_group_member(
     member_principal: Principal,
 ) -> Result<(), ApiError> {
     can_edit(group_id, PermissionType::Member(None))?;
-    GroupCalls::remove_special_member_from_group(group_id, member_principal)
+    GroupCalls::remove_ban_from_group_member(group_id, member_principal)
+}
+
+#[update(guard = "has_access")]
+pub fn remove_mute_from_group_member(
+    group_id: u64,
+    member_principal: Principal,
+) -> Result<(), ApiError> {
+    can_edit(group_id, PermissionType::Member(None))?;
+    GroupCalls::remove_mute_from_group_member(group_id, member_principal)
 }
 
 #[query(guard = "has_access")]
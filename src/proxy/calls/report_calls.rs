@@ -9,7 +9,7 @@ use ic_cdk::{query, update};
 
 use crate::{
     helpers::{
-        group_permission::{can_read, can_write},
+        group_permission::{can_read, can_write, is_not_muted},
         guards::has_access,
     },
     logic::report_logic::ReportCalls,
@@ -32,6 +32,7 @@ use canister_types::models::{
 /// This function is guarded by the [`has_access`](has_access) function.
 #[update(guard = "has_access")]
 pub fn add_report(post_report: PostReport) -> Result<ReportResponse, ApiError> {
+    is_not_muted(post_report.group_id)?;
     ReportCalls::add_report(post_report)
 }
 
@@ -0,0 +1,218 @@
+use std::{cell::RefCell, fmt, str::FromStr};
+
+use candid::Principal;
+use canister_types::models::api_error::ApiError;
+use hmac::{Hmac, Mac};
+use ic_cdk::api::{management_canister::main::raw_rand, time};
+use sha2::Sha256;
+
+use super::time_helper::minutes_to_nanoseconds;
+use crate::EXPORT_TOKEN_TTL_MINUTES;
+
+type HmacSha256 = Hmac<Sha256>;
+
+thread_local! {
+    // Kept on the heap on purpose, an upgrade invalidates all issued tokens
+    static EXPORT_TOKEN_SECRET: RefCell<Option<Vec<u8>>> = const { RefCell::new(None) };
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExportKind {
+    GroupMembers,
+    EventAttendees,
+}
+
+impl fmt::Display for ExportKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ExportKind::*;
+        match self {
+            GroupMembers => write!(f, "members"),
+            EventAttendees => write!(f, "attendees"),
+        }
+    }
+}
+
+impl FromStr for ExportKind {
+    type Err = ApiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "members" => Ok(ExportKind::GroupMembers),
+            "attendees" => Ok(ExportKind::EventAttendees),
+            _ => Err(ApiError::bad_request().add_message("Unknown export kind")),
+        }
+    }
+}
+
+/// The signed content of an export token
+/// # Note
+/// `subject_id` is the group id for member exports and the event id for attendee exports
+#[derive(Clone, Debug)]
+pub struct ExportClaims {
+    pub kind: ExportKind,
+    pub subject_id: u64,
+    pub group_id: u64,
+    pub issued_to: Principal,
+    pub expires_at: u64,
+}
+
+impl ExportClaims {
+    pub fn new(kind: ExportKind, subject_id: u64, group_id: u64, issued_to: Principal) -> Self {
+        Self {
+            kind,
+            subject_id,
+            group_id,
+            issued_to,
+            expires_at: time() + minutes_to_nanoseconds(EXPORT_TOKEN_TTL_MINUTES),
+        }
+    }
+
+    fn is_expired_at(&self, now: u64) -> bool {
+        self.expires_at <= now
+    }
+
+    fn payload(&self) -> String {
+        format!(
+            "{}.{}.{}.{}.{}",
+            self.kind, self.subject_id, self.group_id, self.issued_to, self.expires_at
+        )
+    }
+}
+
+/// Create the secret used to sign export tokens if it doesn't exist yet
+pub async fn init_export_token_secret() -> Result<(), ApiError> {
+    if EXPORT_TOKEN_SECRET.with(|s| s.borrow().is_some()) {
+        return Ok(());
+    }
+
+    let (bytes,) = raw_rand().await.map_err(|(_, err)| {
+        ApiError::unexpected().add_message(&format!("Failed to create export secret: {err}"))
+    })?;
+
+    EXPORT_TOKEN_SECRET.with(|s| {
+        s.borrow_mut().get_or_insert(bytes);
+    });
+    Ok(())
+}
+
+/// Sign the claims, requires [`init_export_token_secret`] to be called first
+pub fn sign_export_token(claims: &ExportClaims) -> Result<String, ApiError> {
+    Ok(sign_token(claims, &get_secret()?))
+}
+
+/// Verify the signature and expiry of a token and return its claims
+pub fn verify_export_token(token: &str) -> Result<ExportClaims, ApiError> {
+    verify_token(token, &get_secret()?, time())
+}
+
+fn sign_token(claims: &ExportClaims, secret: &[u8]) -> String {
+    let payload = claims.payload();
+    let signature = hex::encode(mac(secret, &payload).finalize().into_bytes());
+    format!("{payload}.{signature}")
+}
+
+fn verify_token(token: &str, secret: &[u8], now: u64) -> Result<ExportClaims, ApiError> {
+    let claims = decode_token(token, secret)
+        .ok_or_else(|| ApiError::unauthorized().add_message("Invalid export token"))?;
+
+    if claims.is_expired_at(now) {
+        return Err(ApiError::unauthorized().add_message("Export token expired"));
+    }
+
+    Ok(claims)
+}
+
+// Returns the claims of a token with a valid signature, the expiry is not checked
+fn decode_token(token: &str, secret: &[u8]) -> Option<ExportClaims> {
+    let (payload, signature) = token.rsplit_once('.')?;
+    let signature = hex::decode(signature).ok()?;
+
+    // constant time comparison, so the signature can't be guessed byte by byte
+    mac(secret, payload).verify_slice(&signature).ok()?;
+
+    let parts: Vec<&str> = payload.split('.').collect();
+    if parts.len() != 5 {
+        return None;
+    }
+
+    Some(ExportClaims {
+        kind: parts[0].parse().ok()?,
+        subject_id: parts[1].parse().ok()?,
+        group_id: parts[2].parse().ok()?,
+        issued_to: Principal::from_text(parts[3]).ok()?,
+        expires_at: parts[4].parse().ok()?,
+    })
+}
+
+fn get_secret() -> Result<Vec<u8>, ApiError> {
+    EXPORT_TOKEN_SECRET
+        .with(|s| s.borrow().clone())
+        .ok_or_else(|| ApiError::unauthorized().add_message("No export tokens issued"))
+}
+
+fn mac(secret: &[u8], payload: &str) -> HmacSha256 {
+    let mut mac = HmacSha256::new_from_slice(secret).expect("HMAC accepts keys of any size");
+    mac.update(payload.as_bytes());
+    mac
+}
+
+#[cfg(test)]
+mod test {
+    use super::{decode_token, sign_token, ExportClaims, ExportKind};
+    use candid::Principal;
+
+    const SECRET: &[u8] = b"export-secret";
+
+    fn claims(expires_at: u64) -> ExportClaims {
+        ExportClaims {
+            kind: ExportKind::EventAttendees,
+            subject_id: 12,
+            group_id: 3,
+            issued_to: Principal::anonymous(),
+            expires_at,
+        }
+    }
+
+    #[test]
+    fn test_sign_and_decode() {
+        let token = sign_token(&claims(100), SECRET);
+        let decoded = decode_token(&token, SECRET).unwrap();
+
+        assert_eq!(ExportKind::EventAttendees, decoded.kind);
+        assert_eq!(12, decoded.subject_id);
+        assert_eq!(3, decoded.group_id);
+        assert_eq!(Principal::anonymous(), decoded.issued_to);
+        assert_eq!(100, decoded.expires_at);
+    }
+
+    #[test]
+    fn test_expiry() {
+        assert!(!claims(100).is_expired_at(99));
+        assert!(claims(100).is_expired_at(100));
+    }
+
+    #[test]
+    fn test_rejected_tokens() {
+        let token = sign_token(&claims(100), SECRET);
+
+        // signed with another secret
+        assert!(decode_token(&token, b"other-secret").is_none());
+        // changed claims
+        let tampered = token.replacen("attendees.12", "attendees.13", 1);
+        assert!(decode_token(&tampered, SECRET).is_none());
+        let extended = token.replacen(".100.", ".200.", 1);
+        assert!(decode_token(&extended, SECRET).is_none());
+        // truncated signature
+        assert!(decode_token(&token[..token.len() - 2], SECRET).is_none());
+        // malformed
+        assert!(decode_token("", SECRET).is_none());
+        assert!(decode_token("members.1.1.aaaaa-aa.1.nothex", SECRET).is_none());
+    }
+
+    #[test]
+    fn test_export_kind() {
+        for kind in [ExportKind::GroupMembers, ExportKind::EventAttendees] {
+            assert_eq!(kind, kind.to_string().parse::<ExportKind>().unwrap());
+        }
+    }
+}
@@ -1,12 +1,13 @@
 use candid::Principal;
 use canister_types::models::{
     api_error::ApiError,
+    attendee::{EventPermission, EventRole},
     permission::{PermissionActionType, PermissionType},
-    role::Role,
+    role::{highest_rank, Role},
 };
 use ic_cdk::caller;
 
-use crate::storage::{GroupStore, MemberStore, StorageQueryable};
+use crate::storage::{AttendeeStore, GroupStore, MemberStore, StorageQueryable};
 
 /// Determine if the caller has permission to perform an action on group based entities
 /// # Arguments
@@ -48,6 +49,64 @@ pub fn can_read(group_id: u64, permission_type: PermissionType) -> Result<(), Ap
     )
 }
 
+/// Determine if the caller can manage a part of an event, either through the group permission
+/// or through an event role that grants the event permission
+/// # Arguments
+/// * `group_id` - The group identifier
+/// * `event_id` - The event identifier
+/// * `permission_type` - The group permission type to check
+/// * `permission_action` - The group permission action to check
+/// * `event_permission` - The event permission to check
+pub fn can_manage_event(
+    group_id: u64,
+    event_id: u64,
+    permission_type: PermissionType,
+    permission_action: PermissionActionType,
+    event_permission: EventPermission,
+) -> Result<(), ApiError> {
+    let result = has_permission(caller(), group_id, &permission_type, &permission_action);
+
+    if result.is_err()
+        && AttendeeStore::get(caller())
+            .is_ok_and(|(_, attendee)| attendee.has_event_permission(&event_id, &event_permission))
+    {
+        return Ok(());
+    }
+
+    result
+}
+
+/// Determine if the caller can assign or remove an event role, which needs the group permissions
+/// behind every event permission the role grants
+/// # Arguments
+/// * `group_id` - The group identifier
+/// * `role` - The event role to assign or remove
+pub fn can_manage_event_role(group_id: u64, role: &EventRole) -> Result<(), ApiError> {
+    can_edit(group_id, PermissionType::Attendee(None))?;
+
+    for event_permission in role.get_permissions() {
+        for (permission_type, permission_action) in event_permission.get_group_permissions() {
+            has_permission(caller(), group_id, &permission_type, &permission_action)?;
+        }
+    }
+    Ok(())
+}
+
+/// Determine if the caller is not muted within the group
+/// # Arguments
+/// * `group_id` - The group identifier
+/// # Returns
+/// * `Result<(), ApiError>` - Returns an error if the caller is muted
+pub fn is_not_muted(group_id: u64) -> Result<(), ApiError> {
+    let (_, group) = GroupStore::get(group_id)?;
+
+    if group.is_muted_member(caller()) {
+        return Err(ApiError::unauthorized().add_message("You are muted in this group"));
+    }
+
+    Ok(())
+}
+
 /// Check if the caller has permission to perform an action on a group
 /// This function will check the caller's roles against the group's roles
 /// and return true if the caller has the permission
@@ -62,6 +121,23 @@ pub fn has_permission(
     group_id: u64,
     permission: &PermissionType,
     permission_action: &PermissionActionType,
+) -> Result<(), ApiError> {
+    has_permission_by_name(caller, group_id, &permission.to_string(), permission_action)
+}
+
+/// Check if a principal has permission to perform an action on a group by the permission name
+/// # Arguments
+/// * `caller` - The principal to check the permission for
+/// * `group_identifier` - The group identifier
+/// * `permission_name` - The name of the default or custom permission to check
+/// * `permission_action` - The permission action to check
+/// # Returns
+/// * `Result<(), String>` - Returns an error if the principal does not have permission
+pub fn has_permission_by_name(
+    caller: Principal,
+    group_id: u64,
+    permission_name: &str,
+    permission_action: &PermissionActionType,
 ) -> Result<(), ApiError> {
     let member_roles = MemberStore::get(caller)?.1.get_roles(group_id);
 
@@ -78,7 +154,7 @@ pub fn has_permission(
     let has_access = found_roles.iter().any(|v| {
         use PermissionActionType::*;
         v.permissions.iter().any(|p| {
-            p.name() == permission.to_string()
+            p.name() == permission_name
                 && match permission_action {
                     Write => p.actions().write(),
                     Read => p.actions().read(),
@@ -94,3 +170,61 @@ pub fn has_permission(
 
     Err(ApiError::unauthorized())
 }
+
+/// Get the rank of the highest role a principal has within a group
+/// # Arguments
+/// * `principal` - The principal to get the rank for
+/// * `group_id` - The group identifier
+/// # Returns
+/// * `Result<u64, ApiError>` - The rank of the highest role, `0` if the principal has no known roles
+pub fn get_highest_role_rank(principal: Principal, group_id: u64) -> Result<u64, ApiError> {
+    let member_roles = MemberStore::get(principal)?.1.get_roles(group_id);
+    let group_roles = GroupStore::get(group_id)?.1.get_roles();
+
+    Ok(highest_rank(&group_roles, &member_roles))
+}
+
+/// Determine if the caller ranks strictly above a group member
+/// # Arguments
+/// * `group_id` - The group identifier
+/// * `member_principal` - The principal of the member that is being managed
+pub fn can_manage_member(group_id: u64, member_principal: Principal) -> Result<(), ApiError> {
+    let caller_rank = get_highest_role_rank(caller(), group_id)?;
+    let member_rank = get_highest_role_rank(member_principal, group_id)?;
+
+    if caller_rank > member_rank {
+        return Ok(());
+    }
+
+    Err(ApiError::unauthorized()
+        .add_message("You can only manage members with a lower role than your own"))
+}
+
+/// Determine if the caller ranks strictly above a group role
+/// # Arguments
+/// * `group_id` - The group identifier
+/// * `role_name` - The name of the role that is being managed
+pub fn can_manage_role(group_id: u64, role_name: &str) -> Result<(), ApiError> {
+    let role_rank = GroupStore::get(group_id)?
+        .1
+        .get_roles()
+        .iter()
+        .find(|role| role.name == role_name)
+        .map(|role| role.rank())
+        .ok_or_else(|| ApiError::bad_request().add_message("Role does not exist"))?;
+
+    can_manage_rank(group_id, role_rank)
+}
+
+/// Determine if the caller ranks strictly above a role index
+/// # Arguments
+/// * `group_id` - The group identifier
+/// * `rank` - The rank that is being managed
+pub fn can_manage_rank(group_id: u64, rank: u64) -> Result<(), ApiError> {
+    if get_highest_role_rank(caller(), group_id)? > rank {
+        return Ok(());
+    }
+
+    Err(ApiError::unauthorized()
+        .add_message("You can only manage roles with a lower rank than your own"))
+}
@@ -105,6 +105,27 @@ pub fn is_developer() -> Result<(), String> {
     }
 }
 
+/// Checks if the caller is a developer or has the `Owner` or `Admin` application role
+/// # Note
+/// `Result<(), String>` type is required because of the usage as a guard in the `candid` attribute macro
+pub fn is_application_admin() -> Result<(), String> {
+    if is_developer().is_ok() {
+        return Ok(());
+    }
+
+    match ProfileStore::get(caller()) {
+        Ok((_, profile))
+            if [ApplicationRole::Owner, ApplicationRole::Admin]
+                .contains(&profile.application_role) =>
+        {
+            Ok(())
+        }
+        _ => Err(ApiError::unauthorized()
+            .add_message("Unauthorized")
+            .to_string()),
+    }
+}
+
 // TODO: add guards for group role based access
 // https://forum.dfinity.org/t/rust-guard-access-arguments/22229?u=rmcs
 // https://docs.rs/ic-cdk/latest/ic_cdk/api/call/fn.arg_data.html
@@ -1,3 +1,4 @@
+pub mod export_token;
 pub mod group_permission;
 pub mod guards;
 pub mod str;
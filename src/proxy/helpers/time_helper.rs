@@ -5,3 +5,14 @@ pub fn hours_to_nanoseconds(hours: u64) -> u64 {
 
     hours * minutes_per_hour * seconds_per_minute * nanoseconds_per_second
 }
+
+pub fn days_to_nanoseconds(days: u64) -> u64 {
+    hours_to_nanoseconds(days * 24)
+}
+
+pub fn minutes_to_nanoseconds(minutes: u64) -> u64 {
+    let seconds_per_minute = 60;
+    let nanoseconds_per_second = 1_000_000_000;
+
+    minutes * seconds_per_minute * nanoseconds_per_second
+}
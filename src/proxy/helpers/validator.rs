@@ -19,13 +19,7 @@ impl Validator {
     }
 
     pub fn validate(&self) -> Result<(), ApiError> {
-        let mut errors: Vec<ValidationResponse> = vec![];
-
-        self.fields.iter().for_each(|f| {
-            if let Err(err) = Self::validate_field(f) {
-                errors.push(err);
-            }
-        });
+        let errors = self.errors();
 
         if !errors.is_empty() {
             return Err(ApiError::validation_response(errors));
@@ -34,6 +28,13 @@ impl Validator {
         Ok(())
     }
 
+    pub fn errors(&self) -> Vec<ValidationResponse> {
+        self.fields
+            .iter()
+            .filter_map(|f| Self::validate_field(f).err())
+            .collect()
+    }
+
     fn validate_field(validation_field: &ValidateField) -> Result<(), ValidationResponse> {
         let ValidateField(validation_type, field) = validation_field;
 
@@ -7,7 +7,13 @@ use ic_cdk::query;
 pub static CATALYZE_MULTI_SIG: &str = "fcygz-gqaaa-aaaap-abpaa-cai";
 pub static MULTISIG_INDEX: &str = "o7ouu-niaaa-aaaap-ahhdq-cai";
 pub static E8S_PER_DAY_BOOST_COST: u64 = 3500000;
-pub static USER_GROUP_CREATION_LIMIT: usize = 10;
+// Fallback when no group creation limit is configured for the application role or principal
+pub static USER_GROUP_CREATION_LIMIT: u64 = 10;
+pub static GROUP_TRANSFER_REQUEST_TTL_HOURS: u64 = 7 * 24;
+pub static EXPORT_TOKEN_TTL_MINUTES: u64 = 5;
+pub static CHECK_IN_CODE_TTL_MINUTES: u64 = 2;
+// Attendees are reminded this many hours before an event (occurrence) starts
+pub static EVENT_REMINDER_HOURS: [u64; 2] = [24, 1];
 
 pub mod calls;
 pub mod helpers;
@@ -21,14 +27,22 @@ pub fn __export_did_tmp_() -> String {
     use candid::export_service;
 
     use canister_types::models::api_error::*;
+    use canister_types::models::application_role::ApplicationRole;
     use canister_types::models::attendee::*;
+    use canister_types::models::audit_log::*;
     use canister_types::models::boosted::Boost;
+    use canister_types::models::calendar_feed::*;
+    use canister_types::models::check_in::*;
+    use canister_types::models::date_range::*;
     use canister_types::models::event::*;
     use canister_types::models::event_collection::EventCollection;
+    use canister_types::models::feedback::*;
     use canister_types::models::friend_request::*;
     use canister_types::models::group::*;
-    use canister_types::models::group_transfer_request::GroupTransferRequest;
-    use canister_types::models::http_types::HttpRequest;
+    use canister_types::models::group_creation_limit::*;
+    use canister_types::models::group_moderation::*;
+    use canister_types::models::group_transfer_request::*;
+    use canister_types::models::http_types::{ExportTokenResponse, HttpRequest};
     use canister_types::models::icrc28_trusted_origin::Icrc28TrustedOriginsResponse;
     use canister_types::models::log::*;
     use canister_types::models::member::*;
@@ -41,7 +55,9 @@ pub fn __export_did_tmp_() -> String {
     use canister_types::models::report::*;
     use canister_types::models::reward::*;
     use canister_types::models::role::*;
+    use canister_types::models::session::*;
     use canister_types::models::subject::*;
+    use canister_types::models::ticket::*;
     use canister_types::models::topic::*;
     use canister_types::models::transaction_data::*;
     use canister_types::models::user_notifications::*;
@@ -0,0 +1,59 @@
+use canister_types::models::{
+    api_error::ApiError,
+    audit_log::{AuditAction, AuditLog, AuditLogFilter, AuditLogResponse},
+    paged_response::PagedResponse,
+    subject::Subject,
+};
+use std::cmp::Reverse;
+
+use crate::storage::{
+    AuditLogStore, GroupAuditLogStore, StorageInsertable, StorageInsertableByKey, StorageQueryable,
+    StorageUpdateable,
+};
+
+pub struct AuditLogCalls;
+
+impl AuditLogCalls {
+    /// Store an audit log entry for a group, failing to log should never block the mutation
+    pub fn log(group_id: u64, action: AuditAction, subject: Subject, details: &str) {
+        let Ok((id, _)) = AuditLogStore::insert(AuditLog::new(
+            group_id,
+            action,
+            subject,
+            details.to_string(),
+        )) else {
+            return;
+        };
+
+        let mut logs = GroupAuditLogStore::get_or_default(group_id);
+        logs.add_log(id);
+        GroupAuditLogStore::upsert_by_key(group_id, logs);
+    }
+
+    pub fn get_group_audit_logs(
+        group_id: u64,
+        limit: usize,
+        page: usize,
+        filters: Vec<AuditLogFilter>,
+    ) -> Result<PagedResponse<AuditLogResponse>, ApiError> {
+        let log_ids = GroupAuditLogStore::get_or_default(group_id).get_log_ids();
+
+        let mut logs: Vec<AuditLogResponse> = AuditLogStore::get_many(log_ids)
+            .into_iter()
+            .filter(|(id, log)| filters.iter().all(|f| f.is_match(id, log)))
+            .map(|(id, log)| AuditLogResponse::new(id, log))
+            .collect();
+
+        // newest entries first
+        logs.sort_by_key(|log| Reverse(log.id));
+
+        Ok(PagedResponse::new(page, limit, logs))
+    }
+
+    /// Remove the audit trail of a group
+    pub fn remove_group_audit_logs(group_id: u64) {
+        let log_ids = GroupAuditLogStore::get_or_default(group_id).get_log_ids();
+        AuditLogStore::remove_many(log_ids);
+        GroupAuditLogStore::remove(group_id);
+    }
+}
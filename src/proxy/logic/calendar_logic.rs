@@ -0,0 +1,484 @@
+use candid::Principal;
+use canister_types::models::{
+    api_error::ApiError,
+    calendar_feed::{CalendarFeed, CalendarFeedResponse},
+    event::Event,
+    http_types::{HeaderField, HttpResponse},
+    location::{Address, Location},
+    privacy::Privacy,
+    recurrence::{civil_from_days, RecurrenceFrequency, RecurrenceRule},
+    time_zone::{sunday, TimeZone},
+};
+use ic_cdk::{api::management_canister::main::raw_rand, caller};
+
+use crate::storage::{
+    AttendeeStore, CalendarFeedStore, EventStore, StorageInsertableByKey, StorageQueryable,
+    StorageUpdateable,
+};
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+// RFC 5545 lines should not be longer than 75 octets, excluding the line break
+const ICS_LINE_LENGTH: usize = 75;
+
+pub struct CalendarCalls;
+
+impl CalendarCalls {
+    /// Create a new feed token for the caller, an existing token stops working
+    pub async fn create_calendar_feed_token() -> Result<CalendarFeedResponse, ApiError> {
+        let (bytes,) = raw_rand().await.map_err(|(_, err)| {
+            ApiError::unexpected().add_message(&format!("Failed to create feed token: {err}"))
+        })?;
+
+        let feed = CalendarFeed::new(hex::encode(&bytes[..16]));
+
+        CalendarFeedStore::upsert_by_key(caller(), feed.clone());
+
+        Ok(feed.into())
+    }
+
+    pub fn get_calendar_feed_token() -> Result<CalendarFeedResponse, ApiError> {
+        let (_, feed) = CalendarFeedStore::get(caller())?;
+        Ok(feed.into())
+    }
+
+    pub fn revoke_calendar_feed_token() -> Result<(), ApiError> {
+        if !CalendarFeedStore::remove(caller()) {
+            return Err(ApiError::not_found().add_message("No calendar feed token found"));
+        }
+        Ok(())
+    }
+
+    /// Handles `GET /events/{id}.ics`, only public and published events are served
+    pub fn http_event_ics(file_name: &str) -> HttpResponse {
+        let event = file_name
+            .strip_suffix(".ics")
+            .and_then(|id| id.parse::<u64>().ok())
+            .and_then(|id| EventStore::get(id).ok())
+            .filter(|(_, event)| {
+                event.match_privacy(Privacy::Public) && !event.is_deleted && !event.is_draft()
+            });
+
+        match event {
+            Some(event) => ics_response(calendar(&[event])),
+            None => not_found(),
+        }
+    }
+
+    /// Handles `GET /calendar/{token}.ics` with the events the feed owner attends or is invited to
+    pub fn http_calendar_ics(file_name: &str) -> HttpResponse {
+        let principal = file_name
+            .strip_suffix(".ics")
+            .filter(|token| !token.is_empty())
+            .and_then(|token| CalendarFeedStore::find(|_, feed| feed.token == token))
+            .map(|(principal, _)| principal);
+
+        match principal {
+            Some(principal) => ics_response(calendar(&Self::get_feed_events(principal))),
+            None => not_found(),
+        }
+    }
+
+    fn get_feed_events(principal: Principal) -> Vec<(u64, Event)> {
+        let Ok((_, attendee)) = AttendeeStore::get(principal) else {
+            return vec![];
+        };
+
+        let mut event_ids: Vec<u64> = attendee
+            .joined
+            .keys()
+            .chain(attendee.invites.keys())
+            .cloned()
+            .collect();
+        event_ids.sort();
+        event_ids.dedup();
+
+        EventStore::get_many(event_ids)
+            .into_iter()
+            .filter(|(_, event)| !event.is_deleted)
+            .collect()
+    }
+}
+
+fn ics_response(body: String) -> HttpResponse {
+    HttpResponse {
+        status_code: 200,
+        headers: vec![
+            HeaderField(
+                "Content-Type".to_string(),
+                "text/calendar; charset=utf-8".to_string(),
+            ),
+            HeaderField("Cache-Control".to_string(), "no-store".to_string()),
+        ],
+        body: body.into_bytes(),
+    }
+}
+
+fn not_found() -> HttpResponse {
+    HttpResponse {
+        status_code: 404,
+        headers: vec![],
+        body: vec![],
+    }
+}
+
+fn calendar(events: &[(u64, Event)]) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//Catalyze//Events//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+    ];
+
+    let mut time_zones: Vec<TimeZone> = vec![];
+    for zone in events.iter().filter_map(|(_, event)| event.get_time_zone()) {
+        if !time_zones.iter().any(|known| known.name == zone.name) {
+            time_zones.push(zone);
+        }
+    }
+
+    for zone in &time_zones {
+        lines.extend(vtimezone(zone));
+    }
+
+    for (id, event) in events {
+        lines.extend(vevent(*id, event));
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+
+    lines
+        .iter()
+        .map(|line| fold_line(line))
+        .collect::<Vec<String>>()
+        .join("\r\n")
+        + "\r\n"
+}
+
+fn vevent(id: u64, event: &Event) -> Vec<String> {
+    let time_zone = event.get_time_zone();
+
+    let mut lines = vec![
+        "BEGIN:VEVENT".to_string(),
+        format!("UID:event-{id}@catalyze"),
+        format!("DTSTAMP:{}", ics_datetime(event.updated_on)),
+        format!(
+            "DTSTART{}",
+            ics_zoned_datetimes(&[event.date.start_date()], &time_zone)
+        ),
+        format!(
+            "DTEND{}",
+            ics_zoned_datetimes(&[event.date.end_date()], &time_zone)
+        ),
+        format!("SUMMARY:{}", escape_text(&event.name)),
+    ];
+
+    if !event.description.is_empty() {
+        lines.push(format!("DESCRIPTION:{}", escape_text(&event.description)));
+    }
+
+    if let Some(location) = location_text(&event.location) {
+        lines.push(format!("LOCATION:{}", escape_text(&location)));
+    }
+
+    let website = uri_value(&event.website);
+    if !website.is_empty() {
+        lines.push(format!("URL:{website}"));
+    }
+
+    if let Some(rule) = &event.recurrence {
+        lines.push(format!("RRULE:{}", rrule(rule)));
+        if !rule.exceptions.is_empty() {
+            lines.push(format!(
+                "EXDATE{}",
+                ics_zoned_datetimes(&rule.exceptions, &time_zone)
+            ));
+        }
+    }
+
+    let status = match event.is_canceled.0 {
+        true => "CANCELLED",
+        false => "CONFIRMED",
+    };
+    lines.push(format!("STATUS:{status}"));
+    lines.push("END:VEVENT".to_string());
+    lines
+}
+
+// Time zone definition based on the daylight saving time rules of the zone
+fn vtimezone(zone: &TimeZone) -> Vec<String> {
+    let mut lines = vec!["BEGIN:VTIMEZONE".to_string(), format!("TZID:{}", zone.name)];
+
+    match zone.dst {
+        None => lines.extend([
+            "BEGIN:STANDARD".to_string(),
+            format!("TZOFFSETFROM:{}", ics_offset(zone.standard_offset)),
+            format!("TZOFFSETTO:{}", ics_offset(zone.standard_offset)),
+            "DTSTART:19700101T000000".to_string(),
+            "END:STANDARD".to_string(),
+        ]),
+        Some(rule) => {
+            lines.extend(observance(
+                "DAYLIGHT",
+                zone.standard_offset,
+                zone.daylight_offset(),
+                rule.daylight_start(zone.standard_offset),
+            ));
+            lines.extend(observance(
+                "STANDARD",
+                zone.daylight_offset(),
+                zone.standard_offset,
+                rule.standard_start(zone.standard_offset),
+            ));
+        }
+    }
+
+    lines.push("END:VTIMEZONE".to_string());
+    lines
+}
+
+fn observance(
+    kind: &str,
+    offset_from: i64,
+    offset_to: i64,
+    (month, n, minutes): (u32, i32, i64),
+) -> Vec<String> {
+    let start =
+        sunday(1970, month, n) * 86_400 * NANOS_PER_SECOND + minutes as u64 * 60 * NANOS_PER_SECOND;
+
+    vec![
+        format!("BEGIN:{kind}"),
+        format!("TZOFFSETFROM:{}", ics_offset(offset_from)),
+        format!("TZOFFSETTO:{}", ics_offset(offset_to)),
+        format!("DTSTART:{}", ics_local_datetime(start)),
+        format!("RRULE:FREQ=YEARLY;BYMONTH={month};BYDAY={n}SU"),
+        format!("END:{kind}"),
+    ]
+}
+
+fn rrule(rule: &RecurrenceRule) -> String {
+    let frequency = match rule.frequency {
+        RecurrenceFrequency::Daily => "DAILY",
+        RecurrenceFrequency::Weekly => "WEEKLY",
+        RecurrenceFrequency::Monthly => "MONTHLY",
+    };
+
+    let mut parts = vec![
+        format!("FREQ={frequency}"),
+        format!("INTERVAL={}", rule.interval.max(1)),
+    ];
+
+    if let Some(count) = rule.count {
+        parts.push(format!("COUNT={count}"));
+    }
+
+    if let Some(until) = rule.until {
+        parts.push(format!("UNTIL={}", ics_datetime(until)));
+    }
+
+    parts.join(";")
+}
+
+fn location_text(location: &Location) -> Option<String> {
+    match location {
+        Location::None => None,
+        Location::Physical(physical) => Some(address_text(&physical.address)),
+        Location::Digital(url) => Some(url.clone()),
+        Location::MultiLocation(multi) => Some(format!(
+            "{} / {}",
+            address_text(&multi.physical.address),
+            multi.digital
+        )),
+    }
+    .filter(|text| !text.trim().is_empty())
+}
+
+fn address_text(address: &Address) -> String {
+    let street = [
+        address.street.as_str(),
+        address.house_number.as_str(),
+        address.house_number_addition.as_str(),
+    ]
+    .iter()
+    .filter(|part| !part.is_empty())
+    .cloned()
+    .collect::<Vec<&str>>()
+    .join(" ");
+
+    [
+        address.label.as_str(),
+        street.as_str(),
+        address.postal_code.as_str(),
+        address.city.as_str(),
+        address.state_or_province.as_str(),
+        address.country.as_str(),
+    ]
+    .iter()
+    .filter(|part| !part.is_empty())
+    .cloned()
+    .collect::<Vec<&str>>()
+    .join(", ")
+}
+
+// Formats nanoseconds since the epoch as an UTC date-time, e.g. 20240131T183000Z
+fn ics_datetime(nanos: u64) -> String {
+    format!("{}Z", ics_local_datetime(nanos))
+}
+
+// Property parameters and values, e.g. `;TZID=Europe/Amsterdam:20240131T193000` or `:20240131T183000Z`
+fn ics_zoned_datetimes(timestamps: &[u64], time_zone: &Option<TimeZone>) -> String {
+    match time_zone {
+        Some(zone) => {
+            let values: Vec<String> = timestamps
+                .iter()
+                .map(|timestamp| ics_local_datetime(zone.to_local(*timestamp)))
+                .collect();
+            format!(";TZID={}:{}", zone.name, values.join(","))
+        }
+        None => {
+            let values: Vec<String> = timestamps.iter().map(|t| ics_datetime(*t)).collect();
+            format!(":{}", values.join(","))
+        }
+    }
+}
+
+// Formats a date-time without a time zone, e.g. 20240131T183000
+fn ics_local_datetime(nanos: u64) -> String {
+    let seconds = nanos / NANOS_PER_SECOND;
+    let (year, month, day) = civil_from_days(seconds / 86_400);
+    let seconds_of_day = seconds % 86_400;
+
+    format!(
+        "{year:04}{month:02}{day:02}T{:02}{:02}{:02}",
+        seconds_of_day / 3600,
+        seconds_of_day % 3600 / 60,
+        seconds_of_day % 60
+    )
+}
+
+// UTC offset in minutes as used by VTIMEZONE, e.g. +0100 or -0330
+fn ics_offset(minutes: i64) -> String {
+    let sign = if minutes < 0 { '-' } else { '+' };
+    format!("{sign}{:02}{:02}", minutes.abs() / 60, minutes.abs() % 60)
+}
+
+// Line breaks are escaped and other control characters are dropped, so a value can not start a new property
+fn escape_text(value: &str) -> String {
+    let mut escaped = String::new();
+
+    for c in value.replace("\r\n", "\n").replace('\r', "\n").chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            c if c.is_control() => {}
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+// URI values are not escaped, control characters are dropped so the value stays on a single line
+fn uri_value(value: &str) -> String {
+    value.chars().filter(|c| !c.is_control()).collect()
+}
+
+// Long lines are continued on the next line, starting with a space
+fn fold_line(line: &str) -> String {
+    let mut folded = String::new();
+    let mut line_length = 0;
+
+    for c in line.chars() {
+        if line_length + c.len_utf8() > ICS_LINE_LENGTH {
+            folded.push_str("\r\n ");
+            line_length = 1;
+        }
+        folded.push(c);
+        line_length += c.len_utf8();
+    }
+
+    folded
+}
+
+#[cfg(test)]
+mod test {
+    use super::{escape_text, fold_line, ics_datetime, ics_offset, uri_value, vevent};
+    use canister_types::models::event::Event;
+
+    #[test]
+    fn test_escape_text() {
+        assert_eq!("a\\, b\\; c\\\\d", escape_text("a, b; c\\d"));
+        assert_eq!(
+            "one\\ntwo\\nthree\\nfour",
+            escape_text("one\r\ntwo\nthree\rfour")
+        );
+        assert_eq!("nobell", escape_text("no\u{7}bell\u{0}"));
+    }
+
+    #[test]
+    fn test_uri_value() {
+        assert_eq!("https://example.com", uri_value("https://example.com"));
+        assert_eq!(
+            "https://example.comATTENDEE:evil",
+            uri_value("https://example.com\r\nATTENDEE:evil")
+        );
+        assert_eq!("", uri_value("\r\n"));
+    }
+
+    #[test]
+    fn test_vevent_does_not_inject_properties() {
+        let event = Event {
+            name: "Meetup\r\nATTENDEE:mailto:evil@example.com".to_string(),
+            description: "Line\rEND:VEVENT".to_string(),
+            website: "https://example.com\r\nBEGIN:VEVENT".to_string(),
+            ..Default::default()
+        };
+
+        let lines = vevent(1, &event);
+        assert!(lines
+            .iter()
+            .all(|line| !line.contains('\r') && !line.contains('\n')));
+        assert_eq!(
+            1,
+            lines.iter().filter(|line| *line == "BEGIN:VEVENT").count()
+        );
+        assert_eq!(1, lines.iter().filter(|line| *line == "END:VEVENT").count());
+        assert!(lines.contains(&"URL:https://example.comBEGIN:VEVENT".to_string()));
+    }
+
+    #[test]
+    fn test_fold_line() {
+        let short = "SUMMARY:short";
+        assert_eq!(short, fold_line(short));
+
+        let long = format!("DESCRIPTION:{}", "a".repeat(100));
+        let folded = fold_line(&long);
+        let lines: Vec<&str> = folded.split("\r\n").collect();
+        assert_eq!(2, lines.len());
+        assert_eq!(75, lines[0].len());
+        assert!(lines[1].starts_with(' '));
+        assert_eq!(long, folded.replace("\r\n ", ""));
+
+        // multi-byte characters are not split over lines
+        let emoji = format!("SUMMARY:{}", "🍏".repeat(30));
+        for line in fold_line(&emoji).split("\r\n") {
+            assert!(line.len() <= 75);
+        }
+        assert_eq!(emoji, fold_line(&emoji).replace("\r\n ", ""));
+    }
+
+    #[test]
+    fn test_ics_datetime() {
+        assert_eq!("19700101T000000Z", ics_datetime(0));
+        // 2024-02-29 13:45:30 UTC
+        assert_eq!("20240229T134530Z", ics_datetime(1_709_214_330_000_000_000));
+    }
+
+    #[test]
+    fn test_ics_offset() {
+        assert_eq!("+0100", ics_offset(60));
+        assert_eq!("-0330", ics_offset(-210));
+        assert_eq!("+0000", ics_offset(0));
+    }
+}
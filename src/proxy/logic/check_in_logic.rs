@@ -0,0 +1,182 @@
+use crate::{
+    helpers::time_helper::minutes_to_nanoseconds,
+    storage::{
+        AttendeeStore, EventAttendeeStore, EventCheckInStore, EventStore, RewardBufferStore,
+        StorageInsertableByKey, StorageQueryable,
+    },
+    CHECK_IN_CODE_TTL_MINUTES,
+};
+
+use super::{audit_log_logic::AuditLogCalls, event_logic::EventCalls};
+use candid::Principal;
+use canister_types::models::{
+    api_error::ApiError,
+    attendee::RsvpStatus,
+    audit_log::AuditAction,
+    check_in::{CheckIn, CheckInCode, CheckInMethod, EventCheckInSummary, EventCheckIns},
+    date_range::DateRange,
+    event::Event,
+    subject::Subject,
+};
+use ic_cdk::{
+    api::{management_canister::main::raw_rand, time},
+    caller,
+};
+
+// Characters that can not be confused with each other when read out loud or from a screen
+const CHECK_IN_CODE_ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+const CHECK_IN_CODE_LENGTH: usize = 8;
+
+pub struct CheckInCalls;
+
+impl CheckInCalls {
+    pub async fn create_check_in_code(
+        event_id: u64,
+        group_id: u64,
+    ) -> Result<CheckInCode, ApiError> {
+        EventCalls::get_hosted_event(event_id, group_id)?;
+
+        let (bytes,) = raw_rand().await.map_err(|(_, err)| {
+            ApiError::unexpected().add_message(&format!("Failed to create check-in code: {err}"))
+        })?;
+
+        let code: String = bytes
+            .iter()
+            .take(CHECK_IN_CODE_LENGTH)
+            .map(|byte| {
+                CHECK_IN_CODE_ALPHABET[*byte as usize % CHECK_IN_CODE_ALPHABET.len()] as char
+            })
+            .collect();
+
+        let mut check_ins = EventCheckInStore::get_or_default(event_id);
+        let code = check_ins.add_code(
+            code,
+            caller(),
+            time(),
+            time() + minutes_to_nanoseconds(CHECK_IN_CODE_TTL_MINUTES),
+        );
+        EventCheckInStore::upsert_by_key(event_id, check_ins);
+
+        Ok(code)
+    }
+
+    pub fn redeem_check_in_code(event_id: u64, code: String) -> Result<CheckIn, ApiError> {
+        let (_, event) = EventStore::get(event_id)?;
+        let (mut check_ins, occurrence_start) =
+            Self::validate_check_in(&event, event_id, caller())?;
+
+        if !check_ins.redeem_code(&code.to_uppercase(), caller(), time()) {
+            return Err(ApiError::bad_request().add_message("Invalid or expired check-in code"));
+        }
+
+        let check_in =
+            check_ins.add_check_in(caller(), CheckInMethod::Code, occurrence_start, time());
+        EventCheckInStore::upsert_by_key(event_id, check_ins);
+
+        RewardBufferStore::notify_event_attended(event_id, caller());
+        Ok(check_in)
+    }
+
+    pub fn check_in_attendee(
+        event_id: u64,
+        group_id: u64,
+        attendee_principal: Principal,
+    ) -> Result<CheckIn, ApiError> {
+        let event = EventCalls::get_hosted_event(event_id, group_id)?;
+
+        let (mut check_ins, occurrence_start) =
+            Self::validate_check_in(&event, event_id, attendee_principal)?;
+        let check_in = check_ins.add_check_in(
+            attendee_principal,
+            CheckInMethod::Manual(caller()),
+            occurrence_start,
+            time(),
+        );
+        EventCheckInStore::upsert_by_key(event_id, check_ins);
+
+        AuditLogCalls::log(
+            group_id,
+            AuditAction::AttendeeCheckedIn,
+            Subject::Attendee(attendee_principal),
+            "",
+        );
+
+        RewardBufferStore::notify_event_attended(event_id, attendee_principal);
+        Ok(check_in)
+    }
+
+    pub fn get_event_check_ins(
+        event_id: u64,
+        group_id: u64,
+        occurrence_start: Option<u64>,
+    ) -> Result<EventCheckInSummary, ApiError> {
+        let event = EventCalls::get_hosted_event(event_id, group_id)?;
+
+        let occurrence_start = match occurrence_start {
+            Some(start) if !event.is_occurrence(start) => {
+                return Err(ApiError::not_found().add_message("Occurrence not found"));
+            }
+            Some(start) => start,
+            // the last occurrence that started, or the first one when none has started yet
+            None => event
+                .occurrences(&DateRange::new(0, time()))
+                .last()
+                .map_or(event.date.start_date(), |date| date.start_date()),
+        };
+
+        let check_ins = EventCheckInStore::get_or_default(event_id);
+
+        let going: Vec<Principal> = EventAttendeeStore::get(event_id)
+            .map(|(_, attendees)| {
+                AttendeeStore::get_many(attendees.get_member_principals())
+                    .into_iter()
+                    .filter(|(_, attendee)| {
+                        attendee.get_joined(&event_id).is_some_and(|join| {
+                            join.get_rsvp() == RsvpStatus::Going
+                                && join.is_occurrence_joined(occurrence_start)
+                        })
+                    })
+                    .map(|(principal, _)| principal)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let occurrence_check_ins = check_ins.get_occurrence_check_ins(occurrence_start);
+
+        Ok(EventCheckInSummary {
+            occurrence_start,
+            going_count: going.len() as u64,
+            checked_in_count: occurrence_check_ins.len() as u64,
+            no_show_rate: check_ins.no_show_rate(&going, occurrence_start),
+            check_ins: occurrence_check_ins,
+        })
+    }
+
+    // Only joined attendees can be checked in, once per occurrence and only while it is ongoing,
+    // returns the check-ins with the start of the ongoing occurrence
+    fn validate_check_in(
+        event: &Event,
+        event_id: u64,
+        principal: Principal,
+    ) -> Result<(EventCheckIns, u64), ApiError> {
+        let Some(occurrence) = event.ongoing_occurrence(time()) else {
+            return Err(
+                ApiError::bad_request().add_message("Check-in is only possible during the event")
+            );
+        };
+
+        let (_, attendee) = AttendeeStore::get(principal)?;
+        let has_joined = attendee
+            .get_joined(&event_id)
+            .is_some_and(|join| join.is_occurrence_joined(occurrence.start_date()));
+        if !has_joined {
+            return Err(ApiError::not_found().add_message("Attendee has not joined this event"));
+        }
+
+        let check_ins = EventCheckInStore::get_or_default(event_id);
+        if check_ins.is_checked_in(&principal, occurrence.start_date()) {
+            return Err(ApiError::duplicate().add_message("Attendee is already checked in"));
+        }
+        Ok((check_ins, occurrence.start_date()))
+    }
+}
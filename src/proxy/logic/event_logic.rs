@@ -1,41 +1,119 @@
 use crate::{
-    helpers::time_helper::hours_to_nanoseconds,
+    helpers::{
+        group_permission::has_permission,
+        time_helper::{days_to_nanoseconds, hours_to_nanoseconds},
+        validator::Validator,
+    },
     storage::{
-        AttendeeStore, BoostedStore, EventAttendeeStore, EventStore, GroupEventsStore, MemberStore,
-        ProfileStore, StorageInsertable, StorageInsertableByKey, StorageQueryable,
-        StorageUpdateable,
+        AttendeeStore, BoostedStore, EventAttendeeStore, EventCheckInStore, EventFeedbackStore,
+        EventSessionStore, EventStore, EventWaitlistStore, GroupEventsStore, GroupMemberStore,
+        GroupStore, MemberStore, ProfileStore, StorageInsertable, StorageInsertableByKey,
+        StorageQueryable, StorageUpdateable, TicketReceiptStore,
     },
+    EVENT_REMINDER_HOURS,
 };
 
 use super::{
-    boost_logic::BoostCalls, notification_logic::NotificationCalls, profile_logic::ProfileCalls,
+    audit_log_logic::AuditLogCalls, boost_logic::BoostCalls, feedback_logic::FeedbackCalls,
+    group_logic::GroupValidation, ledger_logic::Ledger, notification_logic::NotificationCalls,
+    profile_logic::ProfileCalls,
 };
 use candid::Principal;
 use canister_types::models::{
     api_error::ApiError,
-    attendee::{Attendee, InviteAttendeeResponse, JoinedAttendeeResponse},
+    attendee::{
+        Attendee, AttendeeJoin, EventPermission, EventRole, EventRoleResponse,
+        InviteAttendeeResponse, JoinedAttendeeResponse, RsvpCounts, RsvpStatus,
+    },
+    audit_log::AuditAction,
     boosted::Boost,
     date_range::DateRange,
     event::{
-        Event, EventCallerData, EventFilter, EventResponse, EventSort, EventsCount, PostEvent,
-        UpdateEvent,
+        Event, EventCallerData, EventEditScope, EventFilter, EventResponse, EventSort, EventsCount,
+        PostEvent, UpdateEvent,
     },
     event_collection::EventCollection,
     invite_type::InviteType,
     member_collection::MemberCollection,
+    notification::{
+        EventCanceledResponse, EventReminderResponse, EventRescheduledResponse,
+        EventTransferredResponse,
+    },
     paged_response::PagedResponse,
-    privacy::Privacy,
+    permission::{PermissionActionType, PermissionType},
+    privacy::{GatedType, Privacy},
     profile::ProfileResponse,
+    recurrence::{RecurrenceRule, MAX_RECURRENCE_OCCURRENCES},
     subject::{Subject, SubjectType},
+    ticket::{TicketPrice, TicketReceipt, TicketSales},
+    time_zone::TimeZone,
+    validation::{ValidateField, ValidationType},
 };
 use ic_cdk::{api::time, caller};
-use std::collections::HashMap;
+use ic_cdk_timers::{clear_timer, set_timer, TimerId};
+use std::{cell::RefCell, collections::HashMap, time::Duration};
+
+thread_local! {
+    pub static EVENT_REMINDER_TIMERS: RefCell<HashMap<u64, Vec<TimerId>>> = RefCell::new(HashMap::default());
+    pub static EVENT_PUBLISH_TIMERS: RefCell<HashMap<u64, TimerId>> = RefCell::new(HashMap::default());
+}
 
 pub struct EventCalls;
+pub struct EventValidation;
 
 impl EventCalls {
     pub fn add_event(post_event: PostEvent) -> Result<EventResponse, ApiError> {
-        let (new_event_id, new_event) = EventStore::insert(Event::from(post_event.clone()))?;
+        EventValidation::validate_recurrence(post_event.date(), post_event.recurrence())?;
+        EventValidation::validate_time_zone(post_event.time_zone())?;
+        EventValidation::validate_max_attendees(post_event.max_attendees())?;
+        EventValidation::validate_publish_at(post_event.publish_at())?;
+        EventValidation::validate_ticket_price(
+            post_event.group_id,
+            post_event.privacy(),
+            post_event.ticket_price(),
+        )?;
+
+        Self::insert_event(Event::from(post_event))
+    }
+
+    /// Copy an event to a new date, attendees, invites and co-hosts are not copied
+    pub fn clone_event(event_id: u64, new_date: DateRange) -> Result<EventResponse, ApiError> {
+        let (_, event) = EventStore::get(event_id)?;
+
+        Validator::new(vec![ValidateField(
+            ValidationType::DateRange(new_date.clone()),
+            "new_date".to_string(),
+        )])
+        .validate()?;
+
+        let event = event.copy_to_date(new_date, caller(), time());
+        EventValidation::validate_recurrence(&event.date, &event.recurrence)?;
+        EventValidation::validate_ticket_price(
+            event.group_id,
+            &event.privacy,
+            &event.ticket_price,
+        )?;
+
+        Self::insert_event(event)
+    }
+
+    /// Get an event that is hosted (owned or co-hosted) by the group
+    pub fn get_hosted_event(event_id: u64, group_id: u64) -> Result<Event, ApiError> {
+        let (_, event) = EventStore::get(event_id)?;
+
+        if !event.is_hosted_by(group_id) {
+            return Err(ApiError::unauthorized());
+        }
+        Ok(event)
+    }
+
+    pub fn get_event_group_id(event_id: u64) -> Result<u64, ApiError> {
+        let (_, event) = EventStore::get(event_id)?;
+        Ok(event.group_id)
+    }
+
+    fn insert_event(event: Event) -> Result<EventResponse, ApiError> {
+        let (new_event_id, new_event) = EventStore::insert(event)?;
 
         let (_, mut attendee) = AttendeeStore::get(caller())?;
 
@@ -50,7 +128,17 @@ impl EventCalls {
         // initialize group events with the new event
         let mut group_events = EventCollection::new();
         group_events.add_event(new_event_id);
-        GroupEventsStore::update(post_event.group_id, group_events)?;
+        GroupEventsStore::update(new_event.group_id, group_events)?;
+
+        AuditLogCalls::log(
+            new_event.group_id,
+            AuditAction::EventCreated,
+            Subject::Event(new_event_id),
+            &new_event.name,
+        );
+
+        Self::schedule_event_reminders(new_event_id);
+        Self::schedule_event_publish(new_event_id);
 
         Ok(EventResponse::new(
             new_event_id,
@@ -58,12 +146,18 @@ impl EventCalls {
             Self::get_boosted_event(new_event_id),
             Self::get_event_caller_data(new_event_id, new_event.group_id),
             Self::get_attendees_count(new_event_id),
+            Self::get_waitlist_count(new_event_id),
+            Self::get_rsvp_counts(new_event_id),
         ))
     }
 
     pub fn get_event(event_id: u64) -> Result<EventResponse, ApiError> {
         let (_, event) = EventStore::get(event_id)?;
 
+        if !Self::is_visible(&event) {
+            return Err(ApiError::not_found());
+        }
+
         if event.match_privacy(Privacy::InviteOnly) {
             let (_, caller_attendee) = AttendeeStore::get(caller())?;
 
@@ -74,6 +168,8 @@ impl EventCalls {
                     Self::get_boosted_event(event_id),
                     Self::get_event_caller_data(event_id, event.group_id),
                     Self::get_attendees_count(event_id),
+                    Self::get_waitlist_count(event_id),
+                    Self::get_rsvp_counts(event_id),
                 ))
             } else {
                 Err(ApiError::unauthorized())
@@ -85,6 +181,8 @@ impl EventCalls {
                 Self::get_boosted_event(event_id),
                 Self::get_event_caller_data(event_id, event.group_id),
                 Self::get_attendees_count(event_id),
+                Self::get_waitlist_count(event_id),
+                Self::get_rsvp_counts(event_id),
             ))
         }
     }
@@ -95,9 +193,18 @@ impl EventCalls {
         sort: EventSort,
         filters: Vec<EventFilter>,
     ) -> Result<PagedResponse<EventResponse>, ApiError> {
+        let filters = filters
+            .into_iter()
+            .map(EventFilter::resolve_local_dates)
+            .collect::<Result<Vec<EventFilter>, ApiError>>()?;
+
         // get all the events and filter them based on the privacy
         // exclude all InviteOnly events that the caller is not a attendee of
         let mut events = EventStore::filter(|event_id, event| {
+            if !Self::is_visible(event) {
+                return false;
+            }
+
             if event.match_privacy(Privacy::InviteOnly) {
                 if let Ok((_, caller_attendee)) = AttendeeStore::get(caller()) {
                     return caller_attendee.is_event_joined(event_id);
@@ -109,7 +216,27 @@ impl EventCalls {
         .into_iter()
         .collect::<HashMap<u64, Event>>();
 
+        // recurring events are expanded into their occurrences when filtering on the start date
+        let windows: Vec<DateRange> = filters
+            .iter()
+            .filter_map(|filter| match filter {
+                EventFilter::StartDate(date) => Some(date.clone()),
+                _ => None,
+            })
+            .collect();
+
         for filter in filters {
+            if let EventFilter::Rsvp(status) = &filter {
+                let caller_attendee = AttendeeStore::get(caller()).ok();
+                events.retain(|id, _| {
+                    caller_attendee
+                        .as_ref()
+                        .and_then(|(_, attendee)| attendee.get_joined(id))
+                        .is_some_and(|join| join.get_rsvp() == *status)
+                });
+                continue;
+            }
+
             for (id, event) in &events.clone() {
                 if !filter.is_match(id, event) {
                     events.remove(id);
@@ -117,21 +244,42 @@ impl EventCalls {
             }
         }
 
-        let sorted_events = sort.sort(events);
-        let result: Vec<EventResponse> = sorted_events
-            .into_iter()
-            .map(|data| {
-                EventResponse::new(
-                    data.0,
-                    data.1.clone(),
-                    Self::get_boosted_event(data.0),
-                    Self::get_event_caller_data(data.0, data.1.group_id),
-                    Self::get_attendees_count(data.0),
-                )
-            })
-            .collect();
+        let mut entries: Vec<(u64, Event)> = vec![];
+        for (id, event) in events {
+            match windows.split_first() {
+                Some((window, rest)) if event.is_recurring() => {
+                    for date in event.occurrences(window) {
+                        if rest.iter().all(|w| w.is_within(date.start_date())) {
+                            let mut occurrence = event.clone();
+                            occurrence.date = date;
+                            entries.push((id, occurrence));
+                        }
+                    }
+                }
+                _ => entries.push((id, event)),
+            }
+        }
 
-        Ok(PagedResponse::new(page, limit, result))
+        let expanded = !windows.is_empty();
+        let sorted_events = sort.sort_list(entries);
+
+        // the responses are only built for the returned page
+        Ok(PagedResponse::new(page, limit, sorted_events).map(|data| {
+            let response = EventResponse::new(
+                data.0,
+                data.1.clone(),
+                Self::get_boosted_event(data.0),
+                Self::get_event_caller_data(data.0, data.1.group_id),
+                Self::get_attendees_count(data.0),
+                Self::get_waitlist_count(data.0),
+                Self::get_rsvp_counts(data.0),
+            );
+
+            if expanded && data.1.is_recurring() {
+                return response.with_occurrence_start(data.1.date.start_date());
+            }
+            response
+        }))
     }
 
     pub fn edit_event(
@@ -141,40 +289,269 @@ impl EventCalls {
     ) -> Result<EventResponse, ApiError> {
         let (_, mut event) = EventStore::get(event_id)?;
 
-        if !event.is_from_group(group_id) {
+        if !event.is_hosted_by(group_id) {
             return Err(ApiError::unauthorized());
         }
 
+        if !event.is_from_group(group_id) && event.changes_owner_fields(&update_event) {
+            return Err(ApiError::unauthorized().add_message(
+                "Only the owning group can change the privacy, ticket price, date or recurrence",
+            ));
+        }
+
+        EventValidation::validate_recurrence(&update_event.date, &update_event.recurrence)?;
+        EventValidation::validate_time_zone(&update_event.time_zone)?;
+        EventValidation::validate_max_attendees(update_event.max_attendees)?;
+        // the ticket revenue always goes to a wallet of the owning group
+        EventValidation::validate_ticket_price(
+            event.group_id,
+            &update_event.privacy,
+            &update_event.ticket_price,
+        )?;
+
+        let old_date = event.date.clone();
         event = event.update(update_event);
         EventStore::update(event_id, event.clone())?;
 
+        // a raised attendee limit can make room for people on the waitlist
+        Self::promote_from_waitlist(event_id);
+        Self::schedule_event_reminders(event_id);
+        Self::notify_event_rescheduled(event_id, &event, old_date);
+
+        AuditLogCalls::log(
+            group_id,
+            AuditAction::EventEdited,
+            Subject::Event(event_id),
+            &event.name,
+        );
+
         Ok(EventResponse::new(
             event_id,
             event.clone(),
             Self::get_boosted_event(event_id),
             Self::get_event_caller_data(event_id, event.group_id),
             Self::get_attendees_count(event_id),
+            Self::get_waitlist_count(event_id),
+            Self::get_rsvp_counts(event_id),
+        ))
+    }
+
+    pub fn edit_event_occurrence(
+        event_id: u64,
+        group_id: u64,
+        occurrence_start: u64,
+        scope: EventEditScope,
+        update_event: UpdateEvent,
+    ) -> Result<EventResponse, ApiError> {
+        let (_, mut event) = EventStore::get(event_id)?;
+
+        // splitting the series changes its dates, which is up to the owning group
+        if !event.is_from_group(group_id) {
+            return Err(ApiError::unauthorized());
+        }
+
+        let Some(mut rule) = event.recurrence.clone() else {
+            return Err(ApiError::bad_request().add_message("Event is not recurring"));
+        };
+
+        let occurrence_date = DateRange::new(
+            occurrence_start,
+            occurrence_start
+                + event
+                    .date
+                    .end_date()
+                    .saturating_sub(event.date.start_date()),
+        );
+
+        EventValidation::validate_ticket_price(
+            event.group_id,
+            &update_event.privacy,
+            &update_event.ticket_price,
+        )?;
+        EventValidation::validate_time_zone(&update_event.time_zone)?;
+
+        if !event.is_occurrence(occurrence_start) {
+            return Err(ApiError::not_found().add_message("Occurrence not found"));
+        }
+
+        // the occurrences that move from the series to the new event
+        let (new_event, moved_range) = match scope {
+            EventEditScope::ThisOccurrence => {
+                // skip the occurrence in the series and detach it as a standalone event
+                rule.add_exception(occurrence_start);
+
+                let mut detached = event.clone();
+                detached.update(update_event);
+                detached.recurrence = None;
+                detached.detached_from = Some((event_id, occurrence_start));
+                detached.created_on = time();
+
+                (detached, DateRange::new(occurrence_start, occurrence_start))
+            }
+            EventEditScope::AllFuture => {
+                if occurrence_start == event.date.start_date() {
+                    return Self::edit_event(event_id, update_event, group_id);
+                }
+
+                // the new series can't overlap the occurrences kept by the current series
+                if update_event.date.start_date() < occurrence_start {
+                    return Err(ApiError::bad_request()
+                        .add_message("The new series can't start before the edited occurrence"));
+                }
+
+                EventValidation::validate_recurrence(&update_event.date, &update_event.recurrence)?;
+
+                // end the current series right before the occurrence and continue in a new one
+                rule.until = Some(occurrence_start - 1);
+
+                let mut future = event.clone();
+                future.update(update_event);
+                future.created_on = time();
+
+                (future, DateRange::new(occurrence_start, 0))
+            }
+        };
+
+        let series = event.clone();
+        event.recurrence = Some(rule);
+        event.updated_on = time();
+        EventStore::update(event_id, event.clone())?;
+
+        let (new_event_id, new_event) = EventStore::insert(new_event)?;
+        Self::split_attendees(event_id, &series, new_event_id, &new_event, &moved_range)?;
+
+        Self::schedule_event_reminders(event_id);
+        Self::schedule_event_reminders(new_event_id);
+        Self::notify_event_rescheduled(new_event_id, &new_event, occurrence_date);
+
+        for host_group_id in new_event.get_host_group_ids() {
+            Self::add_to_group_events(host_group_id, new_event_id)?;
+        }
+
+        AuditLogCalls::log(
+            group_id,
+            AuditAction::EventEdited,
+            Subject::Event(event_id),
+            &event.name,
+        );
+
+        AuditLogCalls::log(
+            group_id,
+            AuditAction::EventCreated,
+            Subject::Event(new_event_id),
+            &new_event.name,
+        );
+
+        Ok(EventResponse::new(
+            new_event_id,
+            new_event.clone(),
+            Self::get_boosted_event(new_event_id),
+            Self::get_event_caller_data(new_event_id, new_event.group_id),
+            Self::get_attendees_count(new_event_id),
+            Self::get_waitlist_count(new_event_id),
+            Self::get_rsvp_counts(new_event_id),
         ))
     }
 
+    // Moves the attendees of the split off occurrences from the series to the new event,
+    // attendees of the whole series are kept on the series and also join the new event.
+    // Joined occurrences move to the occurrence at the same position in a new series, or to the
+    // whole new event when it has no such occurrence
+    fn split_attendees(
+        series_id: u64,
+        series: &Event,
+        new_event_id: u64,
+        new_event: &Event,
+        moved_range: &DateRange,
+    ) -> Result<(), ApiError> {
+        let (_, mut series_attendees) = EventAttendeeStore::get(series_id)?;
+        let mut new_attendees = MemberCollection::new();
+
+        for principal in series_attendees.get_member_principals() {
+            let Ok((_, mut attendee)) = AttendeeStore::get(principal) else {
+                continue;
+            };
+
+            let Some(join) = attendee.get_joined(&series_id) else {
+                continue;
+            };
+
+            match join.occurrences {
+                None => attendee.add_joined(new_event_id, new_event.group_id),
+                Some(occurrences) => {
+                    let (moved, kept): (Vec<u64>, Vec<u64>) = occurrences
+                        .into_iter()
+                        .partition(|start| moved_range.is_within(*start));
+
+                    if moved.is_empty() {
+                        continue;
+                    }
+
+                    let continued_starts: Option<Vec<u64>> = moved
+                        .iter()
+                        .map(|start| {
+                            new_event.continued_occurrence_start(
+                                series,
+                                moved_range.start_date(),
+                                *start,
+                            )
+                        })
+                        .collect();
+
+                    match continued_starts {
+                        Some(starts) if new_event.is_recurring() => {
+                            for start in starts {
+                                attendee.add_joined_occurrence(
+                                    new_event_id,
+                                    new_event.group_id,
+                                    start,
+                                );
+                            }
+                        }
+                        _ => attendee.add_joined(new_event_id, new_event.group_id),
+                    }
+
+                    if kept.is_empty() {
+                        attendee.remove_joined(series_id);
+                        series_attendees.remove_member(&principal);
+                    } else if let Some(join) = attendee.joined.get_mut(&series_id) {
+                        join.occurrences = Some(kept);
+                    }
+                }
+            }
+
+            if attendee.is_event_joined(&new_event_id) {
+                new_attendees.add_member(principal);
+            }
+            AttendeeStore::update(principal, attendee)?;
+        }
+
+        EventAttendeeStore::update(series_id, series_attendees)?;
+        EventAttendeeStore::insert_by_key(new_event_id, new_attendees)?;
+        Ok(())
+    }
+
     pub fn get_boosted_events() -> Vec<EventResponse> {
         BoostCalls::get_boosts_by_subject(SubjectType::Event)
             .into_iter()
-            .map(|(_, boost)| Self::get_event(*boost.subject.get_id()).unwrap())
+            .filter_map(|(_, boost)| Self::get_event(*boost.subject.get_id()).ok())
             .collect()
     }
 
     pub fn get_events_count(group_ids: Option<Vec<u64>>, query: Option<String>) -> EventsCount {
         let events = match group_ids {
             Some(ids) => EventStore::filter(|_, event| {
-                ids.contains(&event.group_id)
+                ids.iter().any(|id| event.is_hosted_by(*id))
                     && match &query {
                         Some(q) => event.name.to_lowercase().contains(&q.to_lowercase()),
                         None => true,
                     }
             }),
             None => EventStore::get_all(),
-        };
+        }
+        .into_iter()
+        .filter(|(_, event)| Self::is_visible(event))
+        .collect::<Vec<(u64, Event)>>();
 
         let (attending, invited) = match AttendeeStore::get(caller()) {
             Ok((_, attendee)) => {
@@ -268,12 +645,26 @@ impl EventCalls {
 
         // remove attendees from the event
         EventAttendeeStore::remove(event_id);
+        EventWaitlistStore::remove(event_id);
+        EventCheckInStore::remove(event_id);
+        EventSessionStore::remove(event_id);
+        Self::clear_event_reminders(event_id);
+        Self::clear_event_publish_timer(event_id);
+
+        // remove event from the group events of the host and co-hosts
+        for host_group_id in event.get_host_group_ids() {
+            Self::remove_from_group_events(host_group_id, event_id)?;
+        }
 
-        // remove event from group events
-        let mut group_events =
-            GroupEventsStore::get(group_id).map_or(EventCollection::new(), |(_, m)| m);
-        group_events.remove_event(&event_id);
-        GroupEventsStore::update(group_id, group_events)?;
+        // after the group events, so the ratings are taken out of the group ratings
+        EventFeedbackStore::remove(event_id);
+
+        AuditLogCalls::log(
+            group_id,
+            AuditAction::EventDeleted,
+            Subject::Event(event_id),
+            &event.name,
+        );
         Ok(())
     }
 
@@ -284,14 +675,498 @@ impl EventCalls {
             return Err(ApiError::unauthorized());
         }
 
-        event = event.cancel(reason);
+        event = event.cancel(reason.clone());
         EventStore::update(event_id, event.clone())?;
+        Self::clear_event_reminders(event_id);
+
+        NotificationCalls::notification_event_canceled(
+            Self::get_notification_receivers(event_id),
+            EventCanceledResponse {
+                event_id,
+                group_id: event.group_id,
+                reason: reason.clone(),
+            },
+        );
+
+        AuditLogCalls::log(
+            group_id,
+            AuditAction::EventCancelled,
+            Subject::Event(event_id),
+            &reason,
+        );
 
         Ok(())
     }
 
+    pub fn transfer_event_to_group(
+        event_id: u64,
+        target_group_id: u64,
+    ) -> Result<EventResponse, ApiError> {
+        let (_, mut event) = EventStore::get(event_id)?;
+        let source_group_id = event.group_id;
+
+        if source_group_id == target_group_id {
+            return Err(ApiError::bad_request().add_message("Event already belongs to this group"));
+        }
+
+        // the ticket revenue should go to a wallet of the new group
+        EventValidation::validate_ticket_price(
+            target_group_id,
+            &event.privacy,
+            &event.ticket_price,
+        )?;
+
+        // a co-host already has the event in its group events
+        let was_co_host = event.is_hosted_by(target_group_id);
+
+        event.transfer_to_group(target_group_id);
+        let (_, event) = EventStore::update(event_id, event)?;
+
+        Self::remove_from_group_events(source_group_id, event_id)?;
+        if !was_co_host {
+            Self::add_to_group_events(target_group_id, event_id)?;
+        }
+
+        if let Ok((_, attendees)) = EventAttendeeStore::get(event_id) {
+            let principals = attendees
+                .get_member_principals()
+                .into_iter()
+                .chain(attendees.get_invite_principals())
+                .collect();
+
+            for (principal, mut attendee) in AttendeeStore::get_many(principals) {
+                attendee.transfer_event_to_group(event_id, target_group_id);
+                AttendeeStore::update(principal, attendee)?;
+            }
+        }
+
+        NotificationCalls::notification_event_transferred(
+            Self::get_notification_receivers(event_id),
+            EventTransferredResponse {
+                event_id,
+                old_group_id: source_group_id,
+                new_group_id: target_group_id,
+            },
+        );
+
+        for group_id in [source_group_id, target_group_id] {
+            AuditLogCalls::log(
+                group_id,
+                AuditAction::EventTransferred,
+                Subject::Event(event_id),
+                &format!("{source_group_id} -> {target_group_id}"),
+            );
+        }
+
+        Ok(EventResponse::new(
+            event_id,
+            event.clone(),
+            Self::get_boosted_event(event_id),
+            Self::get_event_caller_data(event_id, event.group_id),
+            Self::get_attendees_count(event_id),
+            Self::get_waitlist_count(event_id),
+            Self::get_rsvp_counts(event_id),
+        ))
+    }
+
     // Attendee methods
-    pub fn join_event(event_id: u64) -> Result<JoinedAttendeeResponse, ApiError> {
+    pub fn invite_event_co_host(
+        event_id: u64,
+        group_id: u64,
+        co_host_group_id: u64,
+    ) -> Result<(), ApiError> {
+        let (_, mut event) = EventStore::get(event_id)?;
+
+        // only the owning group manages the co-hosts
+        if !event.is_from_group(group_id) {
+            return Err(ApiError::unauthorized());
+        }
+
+        if event.is_hosted_by(co_host_group_id) {
+            return Err(ApiError::duplicate().add_message("Group already hosts this event"));
+        }
+
+        if event.is_co_host_invited(co_host_group_id) {
+            return Err(
+                ApiError::duplicate().add_message("Group is already invited to co-host this event")
+            );
+        }
+
+        let (_, co_host_group) = GroupStore::get(co_host_group_id)?;
+
+        event.add_co_host_invite(co_host_group_id);
+        EventStore::update(event_id, event)?;
+
+        NotificationCalls::notification_event_co_host_invite(
+            vec![co_host_group.owner],
+            co_host_group_id,
+            event_id,
+        );
+
+        AuditLogCalls::log(
+            group_id,
+            AuditAction::EventCoHostInvited,
+            Subject::Event(event_id),
+            &co_host_group.name,
+        );
+        Ok(())
+    }
+
+    pub fn accept_or_decline_event_co_host_invite(
+        event_id: u64,
+        group_id: u64,
+        accept: bool,
+    ) -> Result<(), ApiError> {
+        let (_, mut event) = EventStore::get(event_id)?;
+
+        if !event.is_co_host_invited(group_id) {
+            return Err(
+                ApiError::not_found().add_message("Group is not invited to co-host this event")
+            );
+        }
+
+        event.remove_co_host_invite(group_id);
+        if accept {
+            event.add_co_host(group_id);
+            Self::add_to_group_events(group_id, event_id)?;
+
+            AuditLogCalls::log(
+                group_id,
+                AuditAction::EventCoHostAdded,
+                Subject::Event(event_id),
+                &event.name,
+            );
+        }
+
+        EventStore::update(event_id, event)?;
+        Ok(())
+    }
+
+    /// Remove a co-host, either by the owning group or by the co-host itself
+    pub fn remove_event_co_host(
+        event_id: u64,
+        group_id: u64,
+        co_host_group_id: u64,
+    ) -> Result<(), ApiError> {
+        let (_, mut event) = EventStore::get(event_id)?;
+
+        if !event.is_from_group(group_id) && group_id != co_host_group_id {
+            return Err(ApiError::unauthorized());
+        }
+
+        if event.is_from_group(co_host_group_id) || !event.is_hosted_by(co_host_group_id) {
+            return Err(ApiError::not_found().add_message("Group is not a co-host of this event"));
+        }
+
+        event.remove_co_host(co_host_group_id);
+        EventStore::update(event_id, event.clone())?;
+        Self::remove_from_group_events(co_host_group_id, event_id)?;
+
+        AuditLogCalls::log(
+            event.group_id,
+            AuditAction::EventCoHostRemoved,
+            Subject::Event(event_id),
+            &co_host_group_id.to_string(),
+        );
+        Ok(())
+    }
+
+    pub fn assign_event_role(
+        event_id: u64,
+        group_id: u64,
+        attendee_principal: Principal,
+        role: EventRole,
+    ) -> Result<EventRoleResponse, ApiError> {
+        let (_, event) = EventStore::get(event_id)?;
+
+        if !event.is_hosted_by(group_id) {
+            return Err(ApiError::unauthorized());
+        }
+
+        let (_, mut attendee) = AttendeeStore::get(attendee_principal)?;
+        let join = attendee
+            .add_event_role(event_id, role.clone())
+            .ok_or(ApiError::not_found().add_message("Attendee has not joined the event"))?;
+        AttendeeStore::update(attendee_principal, attendee)?;
+
+        AuditLogCalls::log(
+            group_id,
+            AuditAction::AttendeeRoleAssigned,
+            Subject::Attendee(attendee_principal),
+            &role.name(),
+        );
+
+        NotificationCalls::notification_change_event_attendee_role(JoinedAttendeeResponse::new(
+            event_id,
+            group_id,
+            attendee_principal,
+        ));
+
+        Ok(EventRoleResponse {
+            event_id,
+            principal: attendee_principal,
+            roles: join.get_roles(),
+        })
+    }
+
+    pub fn remove_event_role(
+        event_id: u64,
+        group_id: u64,
+        attendee_principal: Principal,
+        role: EventRole,
+    ) -> Result<EventRoleResponse, ApiError> {
+        let (_, event) = EventStore::get(event_id)?;
+
+        if !event.is_hosted_by(group_id) {
+            return Err(ApiError::unauthorized());
+        }
+
+        let (_, mut attendee) = AttendeeStore::get(attendee_principal)?;
+        let join = attendee
+            .remove_event_role(event_id, &role)
+            .ok_or(ApiError::not_found().add_message("Attendee has not joined the event"))?;
+        AttendeeStore::update(attendee_principal, attendee)?;
+
+        AuditLogCalls::log(
+            group_id,
+            AuditAction::AttendeeRoleRemoved,
+            Subject::Attendee(attendee_principal),
+            &role.name(),
+        );
+
+        Ok(EventRoleResponse {
+            event_id,
+            principal: attendee_principal,
+            roles: join.get_roles(),
+        })
+    }
+
+    /// Attendees with a role that is listed publicly, only the listed roles are returned
+    pub fn get_event_role_holders(
+        event_id: u64,
+    ) -> Result<Vec<(ProfileResponse, EventRoleResponse)>, ApiError> {
+        let (_, event) = EventStore::get(event_id)?;
+
+        if !Self::is_visible(&event) {
+            return Err(ApiError::not_found());
+        }
+
+        if event.match_privacy(Privacy::InviteOnly)
+            && !AttendeeStore::get(caller())
+                .is_ok_and(|(_, attendee)| attendee.is_event_joined(&event_id))
+        {
+            return Err(ApiError::unauthorized());
+        }
+
+        let principals = EventAttendeeStore::get(event_id)
+            .map(|(_, attendees)| attendees.get_member_principals())
+            .unwrap_or_default();
+
+        let mut result = vec![];
+        for (principal, attendee) in AttendeeStore::get_many(principals) {
+            let roles: Vec<EventRole> = attendee
+                .get_joined(&event_id)
+                .map(|join| join.get_roles())
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|role| {
+                    role.get_permissions()
+                        .contains(&EventPermission::ListedPublicly)
+                })
+                .collect();
+
+            if roles.is_empty() {
+                continue;
+            }
+
+            if let Ok((_, profile)) = ProfileStore::get(principal) {
+                result.push((
+                    ProfileResponse::new(principal, profile),
+                    EventRoleResponse {
+                        event_id,
+                        principal,
+                        roles,
+                    },
+                ));
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn add_to_group_events(group_id: u64, event_id: u64) -> Result<(), ApiError> {
+        let mut group_events =
+            GroupEventsStore::get(group_id).map_or(EventCollection::new(), |(_, m)| m);
+        if !group_events.has_event(&event_id) {
+            FeedbackCalls::add_event_to_group_rating(group_id, event_id);
+        }
+        group_events.add_event(event_id);
+        GroupEventsStore::update(group_id, group_events)?;
+        Ok(())
+    }
+
+    fn remove_from_group_events(group_id: u64, event_id: u64) -> Result<(), ApiError> {
+        let mut group_events =
+            GroupEventsStore::get(group_id).map_or(EventCollection::new(), |(_, m)| m);
+        if group_events.has_event(&event_id) {
+            FeedbackCalls::remove_event_from_group_rating(group_id, event_id);
+        }
+        group_events.remove_event(&event_id);
+        GroupEventsStore::update(group_id, group_events)?;
+        Ok(())
+    }
+
+    /// Join an event, ticketed events need the block height of the ICP transfer for the ticket
+    pub async fn join_event(
+        event_id: u64,
+        block_height: Option<u64>,
+        account_identifier: Option<String>,
+    ) -> Result<JoinedAttendeeResponse, ApiError> {
+        let (_, event) = EventStore::get(event_id)?;
+
+        Self::validate_published(&event)?;
+        Self::validate_group_tenure(&event)?;
+
+        if let Privacy::Gated(gated_type) = event.privacy.clone() {
+            Self::validate_gated_join(gated_type, &account_identifier).await?;
+        }
+
+        let Some(ticket_price) = event.ticket_price else {
+            return Self::join(event_id);
+        };
+
+        let Some(block_height) = block_height else {
+            return Err(ApiError::bad_request()
+                .add_message("A block height is required to join a ticketed event"));
+        };
+
+        Self::validate_ticket_purchase(event_id, block_height)?;
+
+        let tokens =
+            Ledger::validate_transfer(caller(), ticket_price.recipient, block_height).await?;
+        if tokens.e8s() < ticket_price.amount_e8s {
+            return Err(ApiError::bad_request()
+                .add_message("The transferred amount is lower than the ticket price"));
+        }
+
+        // the block or the last seat could have been taken during the ledger call
+        Self::validate_ticket_purchase(event_id, block_height)?;
+
+        let response = Self::join(event_id)?;
+
+        TicketReceiptStore::insert_by_key(
+            block_height,
+            TicketReceipt::new(
+                event_id,
+                caller(),
+                block_height,
+                tokens.e8s(),
+                ticket_price.recipient,
+            ),
+        )?;
+
+        Ok(response)
+    }
+
+    // Uses the same rules as joining a gated group, owning one of the neurons or tokens is enough
+    async fn validate_gated_join(
+        gated_type: GatedType,
+        account_identifier: &Option<String>,
+    ) -> Result<(), ApiError> {
+        use GatedType::*;
+        match gated_type {
+            Neuron(neuron_canisters) => {
+                for neuron_canister in neuron_canisters {
+                    if GroupValidation::validate_neuron_gated(
+                        caller(),
+                        neuron_canister.governance_canister,
+                        neuron_canister.rules,
+                    )
+                    .await
+                    {
+                        return Ok(());
+                    }
+                }
+                Err(ApiError::unauthorized()
+                    .add_message("You are not owning the required neuron to join this event"))
+            }
+            Token(nft_canisters) => {
+                for nft_canister in nft_canisters {
+                    if GroupValidation::validate_nft_gated(
+                        &caller(),
+                        account_identifier,
+                        &nft_canister,
+                    )
+                    .await
+                    {
+                        return Ok(());
+                    }
+                }
+                Err(ApiError::unauthorized()
+                    .add_message("You are not owning the required NFT to join this event"))
+            }
+        }
+    }
+
+    // The membership of any of the hosting groups counts towards the tenure
+    fn validate_group_tenure(event: &Event) -> Result<(), ApiError> {
+        let Some(min_group_tenure_days) = event.min_group_tenure_days else {
+            return Ok(());
+        };
+
+        let joined_before = time().saturating_sub(days_to_nanoseconds(min_group_tenure_days));
+        let has_tenure = MemberStore::get(caller()).is_ok_and(|(_, member)| {
+            event
+                .get_host_group_ids()
+                .iter()
+                .filter_map(|group_id| member.get_joined(group_id))
+                .any(|join| join.created_at <= joined_before)
+        });
+
+        if !has_tenure {
+            return Err(ApiError::unauthorized().add_message(&format!(
+                "You need to be a group member for at least {min_group_tenure_days} days to join this event"
+            )));
+        }
+        Ok(())
+    }
+
+    fn validate_ticket_purchase(event_id: u64, block_height: u64) -> Result<(), ApiError> {
+        if TicketReceiptStore::get(block_height).is_ok() {
+            return Err(
+                ApiError::duplicate().add_message("This block is already used for a ticket")
+            );
+        }
+
+        let (_, attendee) = AttendeeStore::get(caller())?;
+        if attendee.is_event_joined(&event_id) {
+            return Err(ApiError::duplicate().add_message("Already joined this event"));
+        }
+
+        let (_, event) = EventStore::get(event_id)?;
+        let (_, attendees) = EventAttendeeStore::get(event_id)?;
+        if !event.has_capacity(attendees.get_member_count()) {
+            return Err(ApiError::bad_request().add_message("This event is sold out"));
+        }
+        Ok(())
+    }
+
+    pub fn get_event_ticket_sales(event_id: u64, group_id: u64) -> Result<TicketSales, ApiError> {
+        let (_, event) = EventStore::get(event_id)?;
+
+        if !event.is_from_group(group_id) {
+            return Err(ApiError::unauthorized());
+        }
+
+        let receipts = TicketReceiptStore::filter(|_, receipt| receipt.event_id == event_id)
+            .into_iter()
+            .map(|(_, receipt)| receipt)
+            .collect();
+
+        Ok(TicketSales::new(receipts))
+    }
+
+    fn join(event_id: u64) -> Result<JoinedAttendeeResponse, ApiError> {
         let (attendee_principal, mut attendee) = AttendeeStore::get(caller())?;
         let (_, mut attendees) = EventAttendeeStore::get(event_id)?;
         let (_, event) = EventStore::get(event_id)?;
@@ -309,28 +1184,87 @@ impl EventCalls {
                     invite_attendee_response,
                 )?;
 
-                attendee.add_invite(
-                    event_id,
-                    event.group_id,
-                    InviteType::UserRequest,
-                    Some(notification_id),
-                );
-                attendees.add_invite(caller());
-            }
-            Privacy::Public => {
-                NotificationCalls::notification_join_public_event(
-                    vec![event.owner],
-                    event.group_id,
-                    event_id,
-                );
-                attendee.add_joined(event_id, event.group_id);
-                attendees.add_member(caller());
-            }
-            _ => {
-                return Err(ApiError::unsupported()
-                    .add_message("This privacy type is not supported for events"));
-            }
+                attendee.add_invite(
+                    event_id,
+                    event.group_id,
+                    InviteType::UserRequest,
+                    Some(notification_id),
+                );
+                attendees.add_invite(caller());
+            }
+            // gated events are validated before joining
+            Privacy::Public | Privacy::Gated(_) => {
+                if !event.has_capacity(attendees.get_member_count()) {
+                    return Ok(Self::add_to_waitlist(event_id, event.group_id, caller()));
+                }
+
+                NotificationCalls::notification_join_public_event(
+                    vec![event.owner],
+                    event.group_id,
+                    event_id,
+                );
+                attendee.add_joined(event_id, event.group_id);
+                attendees.add_member(caller());
+            }
+            _ => {
+                return Err(ApiError::unsupported()
+                    .add_message("This privacy type is not supported for events"));
+            }
+        }
+        AttendeeStore::update(attendee_principal, attendee)?;
+        EventAttendeeStore::update(event_id, attendees)?;
+
+        Ok(JoinedAttendeeResponse::new(
+            event_id,
+            event.group_id,
+            attendee_principal,
+        ))
+    }
+
+    pub fn join_event_occurrence(
+        event_id: u64,
+        occurrence_start: u64,
+    ) -> Result<JoinedAttendeeResponse, ApiError> {
+        let (_, event) = EventStore::get(event_id)?;
+
+        if !event.is_recurring() {
+            return Err(ApiError::bad_request().add_message("Event is not recurring"));
+        }
+
+        if !event.is_occurrence(occurrence_start) {
+            return Err(ApiError::not_found().add_message("Occurrence not found"));
+        }
+
+        Self::validate_published(&event)?;
+        Self::validate_group_tenure(&event)?;
+
+        if !event.match_privacy(Privacy::Public) {
+            return Err(ApiError::unsupported()
+                .add_message("Joining a single occurrence is only supported for public events"));
+        }
+
+        if event.ticket_price.is_some() {
+            return Err(ApiError::unsupported()
+                .add_message("Ticketed events can only be joined with `join_event`"));
+        }
+
+        let (attendee_principal, mut attendee) = AttendeeStore::get(caller())?;
+        let (_, mut attendees) = EventAttendeeStore::get(event_id)?;
+
+        if !attendee.is_event_joined(&event_id) && !event.has_capacity(attendees.get_member_count())
+        {
+            return Ok(Self::add_to_waitlist(event_id, event.group_id, caller()));
         }
+
+        NotificationCalls::notification_join_public_event(
+            vec![event.owner],
+            event.group_id,
+            event_id,
+        );
+
+        attendee.add_joined_occurrence(event_id, event.group_id, occurrence_start);
+        attendees.add_member(caller());
+
         AttendeeStore::update(attendee_principal, attendee)?;
         EventAttendeeStore::update(event_id, attendees)?;
 
@@ -341,6 +1275,17 @@ impl EventCalls {
         ))
     }
 
+    pub fn set_rsvp(event_id: u64, status: RsvpStatus) -> Result<AttendeeJoin, ApiError> {
+        let (_, mut attendee) = AttendeeStore::get(caller())?;
+
+        let Some(join) = attendee.set_rsvp(event_id, status) else {
+            return Err(ApiError::not_found().add_message("You have not joined this event"));
+        };
+
+        AttendeeStore::update(caller(), attendee)?;
+        Ok(join)
+    }
+
     pub fn invite_to_event(
         event_id: u64,
         attendee_principal: Principal,
@@ -348,7 +1293,7 @@ impl EventCalls {
     ) -> Result<InviteAttendeeResponse, ApiError> {
         let (_, event) = EventStore::get(event_id)?;
 
-        if !event.is_from_group(group_id) {
+        if !event.is_hosted_by(group_id) {
             return Err(ApiError::unauthorized());
         }
 
@@ -390,7 +1335,7 @@ impl EventCalls {
     ) -> Result<JoinedAttendeeResponse, ApiError> {
         let (_, event) = EventStore::get(event_id)?;
 
-        if !event.is_from_group(group_id) {
+        if !event.is_hosted_by(group_id) {
             return Err(ApiError::unauthorized());
         }
 
@@ -402,6 +1347,20 @@ impl EventCalls {
 
         let (_, mut attendees) = EventAttendeeStore::get(event_id)?;
 
+        // an accepted request for a full event is moved to the waitlist
+        if accept && !event.has_capacity(attendees.get_member_count()) {
+            attendee.remove_invite(event_id);
+            attendees.remove_invite(&attendee_principal);
+            AttendeeStore::update(attendee_principal, attendee)?;
+            EventAttendeeStore::update(event_id, attendees)?;
+
+            return Ok(Self::add_to_waitlist(
+                event_id,
+                event.group_id,
+                attendee_principal,
+            ));
+        }
+
         if let Some(invite) = attendee.get_invite(&event_id) {
             if accept {
                 attendee.turn_invite_into_joined(event_id);
@@ -432,7 +1391,7 @@ impl EventCalls {
     pub fn accept_or_decline_owner_request_event_invite(
         event_id: u64,
         accept: bool,
-    ) -> Result<Attendee, ApiError> {
+    ) -> Result<JoinedAttendeeResponse, ApiError> {
         let (_, mut attendee) = AttendeeStore::get(caller())?;
 
         if !attendee.has_pending_invite(event_id) {
@@ -442,6 +1401,17 @@ impl EventCalls {
         }
 
         let (_, mut attendees) = EventAttendeeStore::get(event_id)?;
+        let (_, event) = EventStore::get(event_id)?;
+
+        // accepting an invite for a full event puts the caller on the waitlist
+        if accept && !event.has_capacity(attendees.get_member_count()) {
+            attendee.remove_invite(event_id);
+            attendees.remove_invite(&caller());
+            AttendeeStore::update(caller(), attendee)?;
+            EventAttendeeStore::update(event_id, attendees)?;
+
+            return Ok(Self::add_to_waitlist(event_id, event.group_id, caller()));
+        }
 
         if let Some(invite) = attendee.get_invite(&event_id) {
             if accept {
@@ -452,7 +1422,7 @@ impl EventCalls {
                 attendees.remove_invite(&caller());
             }
 
-            AttendeeStore::update(caller(), attendee.clone())?;
+            AttendeeStore::update(caller(), attendee)?;
             EventAttendeeStore::update(event_id, attendees.clone())?;
 
             NotificationCalls::notification_owner_join_request_event_accept_or_decline(
@@ -462,7 +1432,12 @@ impl EventCalls {
                 accept,
             )?;
         }
-        Ok(attendee)
+
+        Ok(JoinedAttendeeResponse::new(
+            event_id,
+            event.group_id,
+            caller(),
+        ))
     }
 
     pub fn get_event_attendees(event_id: u64) -> Result<Vec<JoinedAttendeeResponse>, ApiError> {
@@ -492,10 +1467,24 @@ impl EventCalls {
 
         for principal in event_attendees.get_member_principals() {
             if let Ok((_, profile)) = ProfileStore::get(principal) {
-                if let Ok((_, member)) = MemberStore::get(principal) {
-                    let roles = member.get_roles(event.group_id);
-                    result.push((ProfileResponse::new(principal, profile), roles));
+                let member = MemberStore::get(principal).ok();
+                let event_roles = AttendeeStore::get(principal)
+                    .ok()
+                    .and_then(|(_, attendee)| attendee.get_joined(&event_id))
+                    .map(|join| join.get_roles())
+                    .unwrap_or_default();
+
+                // attendees that are not a member are only listed with an event role
+                if member.is_none() && event_roles.is_empty() {
+                    continue;
                 }
+
+                let mut roles = member
+                    .map(|(_, member)| member.get_roles(event.group_id))
+                    .unwrap_or_default();
+                roles.extend(event_roles.iter().map(|role| role.name()));
+
+                result.push((ProfileResponse::new(principal, profile), roles));
             }
         }
 
@@ -575,6 +1564,8 @@ impl EventCalls {
                     Self::get_boosted_event(data.0),
                     Self::get_event_caller_data(data.0, data.1.group_id),
                     Self::get_attendees_count(data.0),
+                    Self::get_waitlist_count(data.0),
+                    Self::get_rsvp_counts(data.0),
                 )
             })
             .collect()
@@ -610,6 +1601,7 @@ impl EventCalls {
         attendees.remove_member(&caller());
         EventAttendeeStore::update(event_id, attendees)?;
 
+        Self::promote_from_waitlist(event_id);
         Ok(())
     }
 
@@ -647,11 +1639,19 @@ impl EventCalls {
         attendees.remove_member(&attendee_principal);
         EventAttendeeStore::update(event_id, attendees.clone())?;
 
+        AuditLogCalls::log(
+            event.group_id,
+            AuditAction::AttendeeRemoved,
+            Subject::Attendee(attendee_principal),
+            "",
+        );
+
         NotificationCalls::notification_remove_event_attendee(
             JoinedAttendeeResponse::new(event_id, event.group_id, attendee_principal),
             attendees.get_member_principals(),
         );
 
+        Self::promote_from_waitlist(event_id);
         Ok(())
     }
 
@@ -701,9 +1701,11 @@ impl EventCalls {
 
         let mut joined: Option<JoinedAttendeeResponse> = None;
         let mut invite: Option<InviteAttendeeResponse> = None;
+        let mut rsvp: Option<RsvpStatus> = None;
         if let Ok((_, attendee)) = AttendeeStore::get(caller()) {
-            if attendee.is_event_joined(&event_id) {
+            if let Some(join) = attendee.get_joined(&event_id) {
                 joined = Some(JoinedAttendeeResponse::new(event_id, group_id, caller()));
+                rsvp = Some(join.get_rsvp());
             };
 
             if attendee.is_event_invited(&event_id) {
@@ -717,7 +1719,17 @@ impl EventCalls {
             }
         }
 
-        Some(EventCallerData::new(joined, invite, is_starred))
+        let waitlist_position = EventWaitlistStore::get(event_id)
+            .ok()
+            .and_then(|(_, waitlist)| waitlist.get_position(&caller()));
+
+        Some(EventCallerData::new(
+            joined,
+            invite,
+            is_starred,
+            waitlist_position,
+            rsvp,
+        ))
     }
 
     pub fn get_attendees_count(event_id: u64) -> u64 {
@@ -726,4 +1738,483 @@ impl EventCalls {
             Err(_) => 0,
         }
     }
+
+    pub fn get_rsvp_counts(event_id: u64) -> RsvpCounts {
+        let mut counts = RsvpCounts::default();
+        if let Ok((_, attendees)) = EventAttendeeStore::get(event_id) {
+            for (_, attendee) in AttendeeStore::get_many(attendees.get_member_principals()) {
+                if let Some(join) = attendee.get_joined(&event_id) {
+                    counts.add(&join.get_rsvp());
+                }
+            }
+        }
+        counts
+    }
+
+    pub fn get_waitlist_count(event_id: u64) -> u64 {
+        match EventWaitlistStore::get(event_id) {
+            Ok((_, waitlist)) => waitlist.get_count(),
+            Err(_) => 0,
+        }
+    }
+
+    pub fn get_event_waitlist(event_id: u64, group_id: u64) -> Result<Vec<Principal>, ApiError> {
+        let (_, event) = EventStore::get(event_id)?;
+
+        if !event.is_hosted_by(group_id) {
+            return Err(ApiError::unauthorized());
+        }
+
+        Ok(EventWaitlistStore::get(event_id)
+            .map(|(_, waitlist)| waitlist.get_principals())
+            .unwrap_or_default())
+    }
+
+    pub fn leave_event_waitlist(event_id: u64) -> Result<(), ApiError> {
+        let (_, mut waitlist) = EventWaitlistStore::get(event_id)?;
+
+        if waitlist.get_position(&caller()).is_none() {
+            return Err(ApiError::not_found().add_message("You are not on the waitlist"));
+        }
+
+        waitlist.remove(&caller());
+        EventWaitlistStore::upsert_by_key(event_id, waitlist);
+        Ok(())
+    }
+
+    /// Put the principal on the waitlist of a full event
+    fn add_to_waitlist(
+        event_id: u64,
+        group_id: u64,
+        principal: Principal,
+    ) -> JoinedAttendeeResponse {
+        let mut waitlist = EventWaitlistStore::get_or_default(event_id);
+        let position = waitlist.add(principal);
+        EventWaitlistStore::upsert_by_key(event_id, waitlist);
+
+        JoinedAttendeeResponse::waitlisted(event_id, group_id, principal, position)
+    }
+
+    /// Schedule the reminders of the next (occurrence of the) event that still has reminders ahead
+    pub fn schedule_event_reminders(event_id: u64) {
+        Self::clear_event_reminders(event_id);
+
+        let Ok((_, event)) = EventStore::get(event_id) else {
+            return;
+        };
+
+        // drafts get their reminders once they are published
+        if event.is_canceled.0 || event.is_deleted || event.is_draft() {
+            return;
+        }
+
+        let now = time();
+        let next = event
+            .occurrences(&DateRange::new(now, 0))
+            .into_iter()
+            .find_map(|date| {
+                let remind_at: Vec<u64> = EVENT_REMINDER_HOURS
+                    .iter()
+                    .map(|hours| {
+                        date.start_date()
+                            .saturating_sub(hours_to_nanoseconds(*hours))
+                    })
+                    .filter(|at| *at > now)
+                    .collect();
+                (!remind_at.is_empty()).then_some((date.start_date(), remind_at))
+            });
+
+        let Some((occurrence_start, remind_at)) = next else {
+            return;
+        };
+
+        let timer_ids = remind_at
+            .into_iter()
+            .map(|at| {
+                set_timer(Duration::from_nanos(at - now), move || {
+                    Self::send_event_reminder(event_id, occurrence_start)
+                })
+            })
+            .collect();
+
+        EVENT_REMINDER_TIMERS.with(|t| {
+            t.borrow_mut().insert(event_id, timer_ids);
+        });
+    }
+
+    fn clear_event_reminders(event_id: u64) {
+        if let Some(timer_ids) = EVENT_REMINDER_TIMERS.with(|t| t.borrow_mut().remove(&event_id)) {
+            timer_ids.into_iter().for_each(clear_timer);
+        }
+    }
+
+    fn send_event_reminder(event_id: u64, occurrence_start: u64) {
+        if let Ok((_, attendees)) = EventAttendeeStore::get(event_id) {
+            let receivers = AttendeeStore::get_many(attendees.get_member_principals())
+                .into_iter()
+                .filter(|(_, attendee)| {
+                    attendee.get_joined(&event_id).is_some_and(|join| {
+                        join.get_rsvp() != RsvpStatus::NotGoing
+                            && join.is_occurrence_joined(occurrence_start)
+                    })
+                })
+                .map(|(principal, _)| principal)
+                .collect();
+
+            let local_start = EventStore::get(event_id)
+                .ok()
+                .and_then(|(_, event)| event.get_time_zone())
+                .map(|zone| zone.format_local(occurrence_start));
+
+            NotificationCalls::notification_event_reminder(
+                receivers,
+                EventReminderResponse {
+                    event_id,
+                    start_date: occurrence_start,
+                    local_start,
+                },
+            );
+        }
+
+        // move on to the remaining reminder or the next occurrence
+        Self::schedule_event_reminders(event_id);
+    }
+
+    pub fn start_reminder_timers_after_upgrade() {
+        EventStore::get_all()
+            .into_iter()
+            .for_each(|(event_id, _)| Self::schedule_event_reminders(event_id));
+    }
+
+    fn notify_event_rescheduled(event_id: u64, event: &Event, old_date: DateRange) {
+        if old_date.start_date() == event.date.start_date()
+            && old_date.end_date() == event.date.end_date()
+        {
+            return;
+        }
+
+        NotificationCalls::notification_event_rescheduled(
+            Self::get_notification_receivers(event_id),
+            EventRescheduledResponse {
+                event_id,
+                group_id: event.group_id,
+                old_date,
+                new_date: event.date.clone(),
+            },
+        );
+    }
+
+    // the attendees and pending invitees of the event, except the caller
+    fn get_notification_receivers(event_id: u64) -> Vec<Principal> {
+        let Ok((_, attendees)) = EventAttendeeStore::get(event_id) else {
+            return vec![];
+        };
+
+        attendees
+            .get_member_principals()
+            .into_iter()
+            .chain(attendees.get_invite_principals())
+            .filter(|principal| principal != &caller())
+            .collect()
+    }
+
+    /// Publish the draft now, or schedule it to be published at `publish_at`
+    pub fn publish_event(
+        event_id: u64,
+        group_id: u64,
+        publish_at: Option<u64>,
+    ) -> Result<EventResponse, ApiError> {
+        let (_, mut event) = EventStore::get(event_id)?;
+
+        if !event.is_from_group(group_id) {
+            return Err(ApiError::unauthorized());
+        }
+
+        if !event.is_draft() {
+            return Err(ApiError::bad_request().add_message("Event is already published"));
+        }
+
+        EventValidation::validate_publish_at(publish_at)?;
+
+        match publish_at {
+            Some(publish_at) => {
+                EventStore::update(event_id, event.schedule_publish(publish_at))?;
+                Self::schedule_event_publish(event_id);
+            }
+            None => {
+                Self::clear_event_publish_timer(event_id);
+                EventStore::update(event_id, event.publish())?;
+                Self::schedule_event_reminders(event_id);
+                Self::notify_event_published(event_id, &event);
+            }
+        }
+
+        Self::get_event(event_id)
+    }
+
+    fn schedule_event_publish(event_id: u64) {
+        Self::clear_event_publish_timer(event_id);
+
+        let Some(publish_at) = EventStore::get(event_id)
+            .ok()
+            .and_then(|(_, event)| event.publish_at)
+        else {
+            return;
+        };
+
+        let timer_id = set_timer(
+            Duration::from_nanos(publish_at.saturating_sub(time())),
+            move || Self::publish_scheduled_event(event_id),
+        );
+
+        EVENT_PUBLISH_TIMERS.with(|t| {
+            t.borrow_mut().insert(event_id, timer_id);
+        });
+    }
+
+    fn clear_event_publish_timer(event_id: u64) {
+        if let Some(timer_id) = EVENT_PUBLISH_TIMERS.with(|t| t.borrow_mut().remove(&event_id)) {
+            clear_timer(timer_id);
+        }
+    }
+
+    fn publish_scheduled_event(event_id: u64) {
+        EVENT_PUBLISH_TIMERS.with(|t| t.borrow_mut().remove(&event_id));
+
+        let Ok((_, mut event)) = EventStore::get(event_id) else {
+            return;
+        };
+
+        if !event.is_draft() || event.publish_at.is_none() {
+            return;
+        }
+
+        if EventStore::update(event_id, event.publish()).is_ok() {
+            Self::schedule_event_reminders(event_id);
+            Self::notify_event_published(event_id, &event);
+        }
+    }
+
+    // notify the members of the hosting groups that the event is available
+    fn notify_event_published(event_id: u64, event: &Event) {
+        let mut receivers: Vec<Principal> = GroupMemberStore::get_many(event.get_host_group_ids())
+            .into_iter()
+            .flat_map(|(_, members)| members.get_member_principals())
+            .filter(|principal| principal != &caller())
+            .collect();
+        receivers.sort();
+        receivers.dedup();
+
+        NotificationCalls::notification_event_published(receivers, event.group_id, event_id);
+    }
+
+    pub fn start_publish_timers_after_upgrade() {
+        EventStore::filter(|_, event| event.publish_at.is_some())
+            .into_iter()
+            .for_each(|(event_id, _)| Self::schedule_event_publish(event_id));
+    }
+
+    /// Drafts are only visible to principals that can edit events in one of the hosting groups
+    pub fn is_visible(event: &Event) -> bool {
+        !event.is_draft()
+            || event.get_host_group_ids().into_iter().any(|group_id| {
+                has_permission(
+                    caller(),
+                    group_id,
+                    &PermissionType::Event(None),
+                    &PermissionActionType::Edit,
+                )
+                .is_ok()
+            })
+    }
+
+    pub fn validate_published(event: &Event) -> Result<(), ApiError> {
+        if event.is_draft() {
+            return Err(ApiError::bad_request().add_message("Event is not published yet"));
+        }
+        Ok(())
+    }
+
+    /// Move principals from the waitlist into the event, in order, while there is room
+    fn promote_from_waitlist(event_id: u64) {
+        let (Ok((_, event)), Ok((_, mut attendees)), Ok((_, mut waitlist))) = (
+            EventStore::get(event_id),
+            EventAttendeeStore::get(event_id),
+            EventWaitlistStore::get(event_id),
+        ) else {
+            return;
+        };
+
+        // seats of ticketed events are only handed out against a payment
+        if event.ticket_price.is_some() {
+            return;
+        }
+
+        while event.has_capacity(attendees.get_member_count()) {
+            let Some(principal) = waitlist.pop() else {
+                break;
+            };
+
+            let Ok((_, mut attendee)) = AttendeeStore::get(principal) else {
+                continue;
+            };
+
+            if attendee.is_event_joined(&event_id) {
+                continue;
+            }
+
+            attendee.remove_invite(event_id);
+            attendee.add_joined(event_id, event.group_id);
+            if AttendeeStore::update(principal, attendee).is_err() {
+                continue;
+            }
+
+            attendees.create_member_from_invite(principal);
+
+            NotificationCalls::notification_event_waitlist_promoted(JoinedAttendeeResponse::new(
+                event_id,
+                event.group_id,
+                principal,
+            ));
+        }
+
+        let _ = EventAttendeeStore::update(event_id, attendees);
+        let _ = EventWaitlistStore::update(event_id, waitlist);
+    }
+}
+
+impl EventValidation {
+    pub fn validate_ticket_price(
+        group_id: u64,
+        privacy: &Privacy,
+        ticket_price: &Option<TicketPrice>,
+    ) -> Result<(), ApiError> {
+        let Some(ticket_price) = ticket_price else {
+            return Ok(());
+        };
+
+        if ticket_price.amount_e8s == 0 {
+            return Err(ApiError::bad_request().add_message("Ticket price should be more than 0"));
+        }
+
+        if privacy != &Privacy::Public {
+            return Err(ApiError::unsupported()
+                .add_message("Ticket prices are only supported for public events"));
+        }
+
+        let (_, group) = GroupStore::get(group_id)?;
+        if !group.wallets.contains_key(&ticket_price.recipient) {
+            return Err(ApiError::bad_request()
+                .add_message("Ticket recipient should be one of the group wallets"));
+        }
+        Ok(())
+    }
+
+    pub fn validate_publish_at(publish_at: Option<u64>) -> Result<(), ApiError> {
+        if publish_at.is_some_and(|publish_at| publish_at <= time()) {
+            return Err(ApiError::bad_request().add_message("publish_at should be in the future"));
+        }
+        Ok(())
+    }
+
+    pub fn validate_time_zone(time_zone: &Option<String>) -> Result<(), ApiError> {
+        if let Some(time_zone) = time_zone {
+            TimeZone::validate(time_zone)?;
+        }
+        Ok(())
+    }
+
+    pub fn validate_max_attendees(max_attendees: Option<u64>) -> Result<(), ApiError> {
+        if max_attendees == Some(0) {
+            return Err(ApiError::bad_request().add_message("max_attendees should be at least 1"));
+        }
+        Ok(())
+    }
+
+    pub fn validate_recurrence(
+        date: &DateRange,
+        recurrence: &Option<RecurrenceRule>,
+    ) -> Result<(), ApiError> {
+        let Some(rule) = recurrence else {
+            return Ok(());
+        };
+
+        if let Some(message) = Self::recurrence_error(date, rule) {
+            return Err(ApiError::bad_request().add_message(message));
+        }
+
+        Validator::new(Self::recurrence_fields(rule)).validate()
+    }
+
+    fn recurrence_error(date: &DateRange, rule: &RecurrenceRule) -> Option<&'static str> {
+        if rule.interval == 0 {
+            return Some("Recurrence interval must be at least 1");
+        }
+
+        if rule.until.is_some_and(|until| until < date.start_date()) {
+            return Some("Recurrence end date is before the event start");
+        }
+
+        None
+    }
+
+    fn recurrence_fields(rule: &RecurrenceRule) -> Vec<ValidateField> {
+        vec![
+            ValidateField(
+                ValidationType::Count(
+                    rule.count.unwrap_or(1) as usize,
+                    1,
+                    MAX_RECURRENCE_OCCURRENCES as usize,
+                ),
+                "recurrence.count".to_string(),
+            ),
+            ValidateField(
+                ValidationType::Count(rule.exceptions.len(), 0, 100),
+                "recurrence.exceptions".to_string(),
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::EventValidation;
+    use crate::helpers::validator::Validator;
+    use canister_types::models::{
+        date_range::DateRange,
+        recurrence::{RecurrenceFrequency, RecurrenceRule},
+    };
+
+    fn rule(interval: u64, count: Option<u64>, until: Option<u64>) -> Option<RecurrenceRule> {
+        Some(RecurrenceRule {
+            frequency: RecurrenceFrequency::Weekly,
+            interval,
+            count,
+            until,
+            exceptions: vec![],
+        })
+    }
+
+    fn is_valid(date: &DateRange, rule: &Option<RecurrenceRule>) -> bool {
+        let rule = rule.as_ref().unwrap();
+        EventValidation::recurrence_error(date, rule).is_none()
+            && Validator::new(EventValidation::recurrence_fields(rule))
+                .errors()
+                .is_empty()
+    }
+
+    #[test]
+    fn test_validate_recurrence() {
+        let date = DateRange::new(100, 200);
+
+        assert!(EventValidation::validate_recurrence(&date, &None).is_ok());
+        assert!(is_valid(&date, &rule(1, None, None)));
+        assert!(is_valid(&date, &rule(2, Some(1), Some(100))));
+        assert!(is_valid(&date, &rule(1, Some(1000), None)));
+
+        assert!(!is_valid(&date, &rule(0, None, None)));
+        assert!(!is_valid(&date, &rule(1, Some(0), None)));
+        assert!(!is_valid(&date, &rule(1, Some(1001), None)));
+        assert!(!is_valid(&date, &rule(1, None, Some(99))));
+    }
 }
@@ -0,0 +1,239 @@
+use candid::Principal;
+use canister_types::models::{
+    api_error::ApiError,
+    http_types::{ExportTokenResponse, HeaderField, HttpResponse},
+    permission::{PermissionActionType, PermissionType},
+    profile::Profile,
+    profile_privacy::ProfilePrivacy,
+};
+use ic_cdk::caller;
+
+use crate::{
+    helpers::{
+        export_token::{
+            init_export_token_secret, sign_export_token, verify_export_token, ExportClaims,
+            ExportKind,
+        },
+        group_permission::has_permission,
+    },
+    storage::{
+        AttendeeStore, EventAttendeeStore, EventStore, GroupMemberStore, MemberStore, ProfileStore,
+        StorageQueryable,
+    },
+};
+
+pub struct ExportCalls;
+
+impl ExportCalls {
+    pub async fn create_export_token(
+        kind: ExportKind,
+        subject_id: u64,
+        group_id: u64,
+    ) -> Result<ExportTokenResponse, ApiError> {
+        init_export_token_secret().await?;
+
+        let claims = ExportClaims::new(kind.clone(), subject_id, group_id, caller());
+        let token = sign_export_token(&claims)?;
+
+        let path = match kind {
+            ExportKind::GroupMembers => format!("/groups/{subject_id}/members.csv?token={token}"),
+            ExportKind::EventAttendees => {
+                format!("/events/{subject_id}/attendees.csv?token={token}")
+            }
+        };
+
+        Ok(ExportTokenResponse {
+            token,
+            path,
+            expires_at: claims.expires_at,
+        })
+    }
+
+    /// Handles `GET /groups/{id}/members.csv` and `GET /events/{id}/attendees.csv`
+    pub fn http_export(kind: ExportKind, subject_id: &str, token: Option<&str>) -> HttpResponse {
+        match Self::export_csv(kind, subject_id, token) {
+            Ok(body) => HttpResponse {
+                status_code: 200,
+                headers: vec![
+                    HeaderField(
+                        "Content-Type".to_string(),
+                        "text/csv; charset=utf-8".to_string(),
+                    ),
+                    HeaderField("Cache-Control".to_string(), "no-store".to_string()),
+                ],
+                body: body.into_bytes(),
+            },
+            Err(_) => HttpResponse {
+                status_code: 403,
+                headers: vec![],
+                body: vec![],
+            },
+        }
+    }
+
+    fn export_csv(
+        kind: ExportKind,
+        subject_id: &str,
+        token: Option<&str>,
+    ) -> Result<String, ApiError> {
+        let claims = verify_export_token(token.unwrap_or_default())?;
+
+        if claims.kind != kind || claims.subject_id.to_string() != subject_id {
+            return Err(ApiError::unauthorized());
+        }
+
+        // the issuer could have lost access since the token was created
+        match kind {
+            ExportKind::GroupMembers => {
+                has_permission(
+                    claims.issued_to,
+                    claims.group_id,
+                    &PermissionType::Member(None),
+                    &PermissionActionType::Edit,
+                )?;
+                Self::group_members_csv(claims.group_id)
+            }
+            ExportKind::EventAttendees => {
+                has_permission(
+                    claims.issued_to,
+                    claims.group_id,
+                    &PermissionType::Event(None),
+                    &PermissionActionType::Edit,
+                )?;
+                Self::event_attendees_csv(claims.subject_id, claims.group_id)
+            }
+        }
+    }
+
+    fn group_members_csv(group_id: u64) -> Result<String, ApiError> {
+        let (_, members) = GroupMemberStore::get(group_id)?;
+
+        let mut rows = vec![csv_row(&[
+            "principal",
+            "username",
+            "display_name",
+            "roles",
+            "joined_on",
+        ])];
+
+        for (principal, member) in MemberStore::get_many(members.get_member_principals()) {
+            let profile = Self::get_profile(principal);
+            let joined_on = member
+                .get_joined(&group_id)
+                .map(|join| join.created_at.to_string())
+                .unwrap_or_default();
+
+            rows.push(csv_row(&[
+                &principal.to_string(),
+                &profile.username,
+                &profile.display_name,
+                &member.get_roles(group_id).join(";"),
+                &joined_on,
+            ]));
+        }
+
+        Ok(rows.join("\r\n"))
+    }
+
+    fn event_attendees_csv(event_id: u64, group_id: u64) -> Result<String, ApiError> {
+        let (_, event) = EventStore::get(event_id)?;
+        if !event.is_hosted_by(group_id) {
+            return Err(ApiError::unauthorized());
+        }
+
+        let (_, attendees) = EventAttendeeStore::get(event_id)?;
+
+        let mut rows = vec![csv_row(&[
+            "principal",
+            "username",
+            "display_name",
+            "first_name",
+            "last_name",
+            "email",
+            "joined_on",
+        ])];
+
+        for (principal, attendee) in AttendeeStore::get_many(attendees.get_member_principals()) {
+            let profile = Self::get_profile(principal);
+            let joined_on = attendee
+                .get_joined(&event_id)
+                .map(|join| join.created_at.to_string())
+                .unwrap_or_default();
+
+            // personal details are only exported for public profiles
+            let (first_name, last_name, email) = match profile.privacy {
+                ProfilePrivacy::Public => (profile.first_name, profile.last_name, profile.email),
+                ProfilePrivacy::Private => Default::default(),
+            };
+
+            rows.push(csv_row(&[
+                &principal.to_string(),
+                &profile.username,
+                &profile.display_name,
+                &first_name,
+                &last_name,
+                &email,
+                &joined_on,
+            ]));
+        }
+
+        Ok(rows.join("\r\n"))
+    }
+
+    fn get_profile(principal: Principal) -> Profile {
+        ProfileStore::get(principal)
+            .map(|(_, profile)| profile)
+            .unwrap_or_default()
+    }
+}
+
+fn csv_row(values: &[&str]) -> String {
+    values
+        .iter()
+        .map(|value| csv_cell(value))
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+// Values that a spreadsheet would read as a formula are prefixed with a quote to keep them text
+fn csv_cell(value: &str) -> String {
+    let value = match value.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        true => format!("'{value}"),
+        false => value.to_string(),
+    };
+
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{csv_cell, csv_row};
+
+    #[test]
+    fn test_csv_quoting() {
+        assert_eq!("plain", csv_cell("plain"));
+        assert_eq!("\"a,b\"", csv_cell("a,b"));
+        assert_eq!("\"say \"\"hi\"\"\"", csv_cell("say \"hi\""));
+        assert_eq!("\"two\nlines\"", csv_cell("two\nlines"));
+        assert_eq!("a,\"b,c\",", csv_row(&["a", "b,c", ""]));
+    }
+
+    #[test]
+    fn test_csv_formula_injection() {
+        assert_eq!("'=SUM(A1:A2)", csv_cell("=SUM(A1:A2)"));
+        assert_eq!("'+1", csv_cell("+1"));
+        assert_eq!("'-1", csv_cell("-1"));
+        assert_eq!("'@SUM(A1)", csv_cell("@SUM(A1)"));
+        assert_eq!("'\tvalue", csv_cell("\tvalue"));
+        assert_eq!("\"'\rvalue\"", csv_cell("\rvalue"));
+        assert_eq!("\"'=1,2\"", csv_cell("=1,2"));
+
+        // only the first character matters
+        assert_eq!("a=1", csv_cell("a=1"));
+        assert_eq!("2vkzq-aaaaa-aaaaa", csv_cell("2vkzq-aaaaa-aaaaa"));
+    }
+}
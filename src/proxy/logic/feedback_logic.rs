@@ -0,0 +1,155 @@
+use crate::{
+    helpers::validator::Validator,
+    storage::{
+        AttendeeStore, EventFeedbackStore, EventStore, GroupRatingStore, StorageInsertableByKey,
+        StorageQueryable,
+    },
+};
+
+use super::event_logic::EventCalls;
+use canister_types::models::{
+    api_error::ApiError,
+    feedback::{EventFeedback, EventFeedbackSummary, MAX_EVENT_RATING, MIN_EVENT_RATING},
+    group_rating::GroupRating,
+    validation::{ValidateField, ValidationType},
+};
+use ic_cdk::{api::time, caller};
+
+pub struct FeedbackCalls;
+
+impl FeedbackCalls {
+    pub fn submit_event_feedback(
+        event_id: u64,
+        rating: u8,
+        comment: Option<String>,
+    ) -> Result<EventFeedback, ApiError> {
+        let (_, event) = EventStore::get(event_id)?;
+
+        EventCalls::validate_published(&event)?;
+
+        if event.date.end_date() > time() {
+            return Err(ApiError::bad_request()
+                .add_message("Feedback can only be given after the event has ended"));
+        }
+
+        if event.is_canceled.0 {
+            return Err(ApiError::bad_request()
+                .add_message("Feedback can not be given on a canceled event"));
+        }
+
+        Self::validate_feedback(rating, &comment)?;
+
+        let (_, attendee) = AttendeeStore::get(caller())?;
+        if !attendee.is_event_joined(&event_id) {
+            return Err(ApiError::unauthorized()
+                .add_message("Only attendees of the event can give feedback"));
+        }
+
+        let mut feedbacks = EventFeedbackStore::get_or_default(event_id);
+        if feedbacks.has_feedback(&caller()) {
+            return Err(
+                ApiError::duplicate().add_message("Feedback is already given for this event")
+            );
+        }
+
+        let feedback = feedbacks.add_feedback(caller(), rating, comment, time());
+        EventFeedbackStore::upsert_by_key(event_id, feedbacks);
+
+        for group_id in event.get_host_group_ids() {
+            Self::update_group_rating(group_id, |group_rating| group_rating.add(rating as u64, 1));
+        }
+
+        Ok(feedback)
+    }
+
+    pub fn get_event_feedback(
+        event_id: u64,
+        group_id: u64,
+    ) -> Result<EventFeedbackSummary, ApiError> {
+        let event = EventCalls::get_hosted_event(event_id, group_id)?;
+
+        if !EventCalls::is_visible(&event) {
+            return Err(ApiError::not_found());
+        }
+
+        Ok(EventFeedbackSummary::new(
+            event_id,
+            EventFeedbackStore::get_or_default(event_id),
+        ))
+    }
+
+    /// Average rating over all feedback given on the events of the group
+    pub fn get_group_average_rating(group_id: u64) -> Option<f64> {
+        GroupRatingStore::get_or_default(group_id).get_average_rating()
+    }
+
+    /// Count the ratings of an event that is added to the events of a group
+    pub fn add_event_to_group_rating(group_id: u64, event_id: u64) {
+        let feedbacks = EventFeedbackStore::get_or_default(event_id);
+        if feedbacks.get_ratings_count() > 0 {
+            Self::update_group_rating(group_id, |group_rating| {
+                group_rating.add(feedbacks.get_ratings_total(), feedbacks.get_ratings_count())
+            });
+        }
+    }
+
+    /// Stop counting the ratings of an event that is removed from the events of a group
+    pub fn remove_event_from_group_rating(group_id: u64, event_id: u64) {
+        let feedbacks = EventFeedbackStore::get_or_default(event_id);
+        if feedbacks.get_ratings_count() > 0 {
+            Self::update_group_rating(group_id, |group_rating| {
+                group_rating.subtract(feedbacks.get_ratings_total(), feedbacks.get_ratings_count())
+            });
+        }
+    }
+
+    fn update_group_rating(group_id: u64, f: impl FnOnce(&mut GroupRating)) {
+        let mut group_rating = GroupRatingStore::get_or_default(group_id);
+        f(&mut group_rating);
+        GroupRatingStore::upsert_by_key(group_id, group_rating);
+    }
+
+    fn validate_feedback(rating: u8, comment: &Option<String>) -> Result<(), ApiError> {
+        if !Self::is_valid_rating(rating) {
+            return Err(ApiError::bad_request().add_message(&format!(
+                "Rating should be between {MIN_EVENT_RATING} and {MAX_EVENT_RATING}"
+            )));
+        }
+
+        Validator::new(Self::feedback_fields(comment)).validate()
+    }
+
+    fn is_valid_rating(rating: u8) -> bool {
+        (MIN_EVENT_RATING..=MAX_EVENT_RATING).contains(&rating)
+    }
+
+    fn feedback_fields(comment: &Option<String>) -> Vec<ValidateField> {
+        vec![ValidateField(
+            ValidationType::StringLength(comment.clone().unwrap_or_default(), 0, 1000),
+            "comment".to_string(),
+        )]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::FeedbackCalls;
+    use crate::helpers::validator::Validator;
+
+    fn is_valid(rating: u8, comment: &Option<String>) -> bool {
+        FeedbackCalls::is_valid_rating(rating)
+            && Validator::new(FeedbackCalls::feedback_fields(comment))
+                .errors()
+                .is_empty()
+    }
+
+    #[test]
+    fn test_validate_feedback() {
+        assert!(FeedbackCalls::validate_feedback(1, &None).is_ok());
+        assert!(is_valid(5, &Some("great event".to_string())));
+
+        assert!(!is_valid(0, &None));
+        assert!(!is_valid(6, &None));
+        assert!(!is_valid(3, &Some("a".repeat(1001))));
+    }
+}
@@ -1,5 +1,6 @@
 use super::{
-    boost_logic::BoostCalls, event_logic::EventCalls, history_event_logic::HistoryEventLogic,
+    audit_log_logic::AuditLogCalls, boost_logic::BoostCalls, event_logic::EventCalls,
+    feedback_logic::FeedbackCalls, history_event_logic::HistoryEventLogic,
     notification_logic::NotificationCalls, profile_logic::ProfileCalls,
 };
 use crate::{
@@ -13,17 +14,24 @@ use crate::{
         validator::Validator,
     },
     storage::{
-        group_transfer_request_storage::GroupTransferRequestStore, BoostedStore, GroupEventsStore,
-        GroupMemberStore, GroupStore, MemberStore, ProfileStore, RewardBufferStore,
-        StorageInsertable, StorageInsertableByKey, StorageQueryable, StorageUpdateable,
+        group_creation_limit_storage::GroupCreationLimitStorage,
+        group_transfer_request_storage::GroupTransferRequestStore, BoostedStore, CellStorage,
+        GroupEventsStore, GroupMemberStore, GroupRatingStore, GroupStore, MemberStore,
+        ProfileStore, RewardBufferStore, StorageInsertable, StorageInsertableByKey,
+        StorageQueryable, StorageUpdateable,
     },
-    USER_GROUP_CREATION_LIMIT,
+    GROUP_TRANSFER_REQUEST_TTL_HOURS, USER_GROUP_CREATION_LIMIT,
 };
 use candid::Principal;
 use canister_types::{
-    misc::role_misc::{default_roles, read_only_permissions, MEMBER_ROLE, OWNER_ROLE},
+    misc::role_misc::{
+        default_permission_names, default_roles, read_only_permissions, MEMBER_ROLE,
+        MEMBER_ROLE_INDEX, OWNER_ROLE,
+    },
     models::{
         api_error::ApiError,
+        application_role::ApplicationRole,
+        audit_log::AuditAction,
         boosted::Boost,
         date_range::DateRange,
         event_collection::EventCollection,
@@ -31,14 +39,18 @@ use canister_types::{
             Group, GroupCallerData, GroupFilter, GroupResponse, GroupSort, GroupsCount, PostGroup,
             UpdateGroup,
         },
-        group_transfer_request::GroupTransferRequest,
+        group_creation_limit::{GroupCreationLimits, GroupCreationQuota},
+        group_moderation::{ModerationKind, ModerationRecord},
+        group_transfer_request::{GroupTransferRequest, GroupTransferRequestResponse},
         history_event::GroupRoleChangeKind,
         invite_type::InviteType,
         member::{InviteMemberResponse, JoinedMemberResponse, Member},
         member_collection::MemberCollection,
         neuron::{DissolveState, ListNeurons, ListNeuronsResponse},
         paged_response::PagedResponse,
-        permission::{Permission, PermissionActionType, PermissionType, PostPermission},
+        permission::{
+            CustomPermission, Permission, PermissionActionType, PermissionType, PostPermission,
+        },
         privacy::{GatedType, NeuronGatedRules, Privacy, TokenGated},
         profile::ProfileResponse,
         relation_type::RelationType,
@@ -51,7 +63,14 @@ use ic_cdk::{
     api::{call, time},
     caller,
 };
-use std::collections::HashMap;
+use ic_cdk_timers::{clear_timer, set_timer, TimerId};
+use std::{cell::RefCell, collections::HashMap, time::Duration};
+
+thread_local! {
+    pub static TRANSFER_REQUEST_TIMERS: RefCell<HashMap<u64, TimerId>> = RefCell::new(HashMap::default());
+    pub static MODERATION_TIMERS: RefCell<HashMap<(u64, Principal), TimerId>> = RefCell::new(HashMap::default());
+    pub static GROUP_PUBLISH_TIMERS: RefCell<HashMap<u64, TimerId>> = RefCell::new(HashMap::default());
+}
 
 pub struct GroupCalls;
 pub struct GroupValidation;
@@ -77,10 +96,12 @@ impl GroupCalls {
         // Get the member and add the group to the member
         let (_, mut member) = MemberStore::get(caller())?;
 
-        if member.get_owned().len() >= USER_GROUP_CREATION_LIMIT {
-            return Err(ApiError::bad_request().add_message(
-                format!("You can only own {} groups", USER_GROUP_CREATION_LIMIT).as_str(),
-            ));
+        let quota = Self::get_group_creation_quota(caller());
+        if quota.usage >= quota.limit {
+            return Err(ApiError::bad_request().add_message(&format!(
+                "Group creation limit reached, you own {} of {} allowed groups",
+                quota.usage, quota.limit
+            )));
         }
 
         // Create and store the group
@@ -102,22 +123,111 @@ impl GroupCalls {
         // initialze the group event collection
         GroupEventsStore::insert_by_key(new_group_id, EventCollection::new())?;
 
+        Self::schedule_group_publish(new_group_id);
+
         GroupResponse::from_result(
             Ok((new_group_id, new_group)),
             None,
             0,
             1, // the owner is a member
+            None,
             Self::get_group_caller_data(new_group_id),
         )
     }
 
+    /// Create a new group with the setup of an existing group, members and events are not copied
+    pub async fn clone_group(
+        group_id: u64,
+        new_name: String,
+        account_identifier: Option<String>,
+    ) -> Result<GroupResponse, ApiError> {
+        let (_, group) = GroupStore::get(group_id)?;
+
+        let post_group = PostGroup {
+            name: new_name,
+            description: group.description,
+            website: group.website,
+            matrix_space_id: "".to_string(),
+            location: group.location,
+            privacy: group.privacy,
+            privacy_gated_type_amount: group.privacy_gated_type_amount,
+            image: group.image,
+            banner_image: group.banner_image,
+            tags: group.tags,
+            max_members: group.max_members,
+            is_draft: None,
+            publish_at: None,
+        };
+
+        // same checks as a new group, including the group creation limit
+        let new_group = Self::add_group(post_group, account_identifier).await?;
+
+        let (_, mut cloned_group) = GroupStore::get(new_group.id)?;
+        cloned_group.roles = group.roles;
+        cloned_group.custom_permissions = group.custom_permissions;
+        GroupStore::update(new_group.id, cloned_group)?;
+
+        Self::get_group(new_group.id)
+    }
+
+    pub fn get_group_creation_limits() -> GroupCreationLimits {
+        GroupCreationLimitStorage::get()
+            .unwrap_or_else(|_| GroupCreationLimits::new(USER_GROUP_CREATION_LIMIT))
+    }
+
+    pub fn set_group_creation_default_limit(limit: u64) -> Result<GroupCreationLimits, ApiError> {
+        let mut limits = Self::get_group_creation_limits();
+        limits.default_limit = limit;
+        GroupCreationLimitStorage::set(limits)
+    }
+
+    pub fn set_group_creation_role_limit(
+        role: ApplicationRole,
+        limit: Option<u64>,
+    ) -> Result<GroupCreationLimits, ApiError> {
+        let mut limits = Self::get_group_creation_limits();
+        limits.set_role_limit(role, limit);
+        GroupCreationLimitStorage::set(limits)
+    }
+
+    pub fn set_group_creation_limit_override(
+        principal: Principal,
+        limit: Option<u64>,
+    ) -> Result<GroupCreationLimits, ApiError> {
+        let mut limits = Self::get_group_creation_limits();
+        limits.set_principal_override(principal, limit);
+        GroupCreationLimitStorage::set(limits)
+    }
+
+    pub fn get_group_creation_quota(principal: Principal) -> GroupCreationQuota {
+        let role = ProfileStore::get(principal)
+            .map(|(_, profile)| profile.application_role)
+            .unwrap_or_default();
+
+        let usage = MemberStore::get(principal)
+            .map(|(_, member)| member.get_owned().len() as u64)
+            .unwrap_or_default();
+
+        GroupCreationQuota {
+            limit: Self::get_group_creation_limits().get_limit(&principal, &role),
+            usage,
+        }
+    }
+
     pub fn get_group(id: u64) -> Result<GroupResponse, ApiError> {
+        let (_, group) = GroupStore::get(id)?;
+
+        if !Self::is_visible(id, &group) {
+            return Err(ApiError::not_found().add_message("Group not found"));
+        }
+
         let (members_count, events_count) = Self::get_group_count_data(&id);
         GroupResponse::from_result(
-            GroupStore::get(id),
+            Ok((id, group)),
             Self::get_boosted_group(id),
             events_count,
             members_count,
+            FeedbackCalls::get_group_average_rating(id),
             Self::get_group_caller_data(id),
         )
     }
@@ -143,7 +253,9 @@ impl GroupCalls {
         // get all the groups and filter them based on the privacy
         // exclude all InviteOnly groups that the caller is not a member of
         let mut groups = GroupStore::filter(|group_id, group| {
-            if group.privacy == Privacy::InviteOnly {
+            if !Self::is_visible(*group_id, group) {
+                false
+            } else if group.privacy == Privacy::InviteOnly {
                 if let Ok((_, caller_member)) = MemberStore::get(caller()) {
                     return caller_member.is_group_joined(group_id);
                 }
@@ -177,6 +289,7 @@ impl GroupCalls {
                     Self::get_boosted_group(group_id),
                     events_count,
                     members_count,
+                    FeedbackCalls::get_group_average_rating(group_id),
                     Self::get_group_caller_data(group_id),
                 )
             })
@@ -188,14 +301,17 @@ impl GroupCalls {
     pub fn get_boosted_groups() -> Vec<GroupResponse> {
         BoostCalls::get_boosts_by_subject(SubjectType::Group)
             .into_iter()
-            .map(|(_, boost)| Self::get_group(*boost.subject.get_id()).unwrap())
+            .filter_map(|(_, boost)| Self::get_group(*boost.subject.get_id()).ok())
             .collect()
     }
 
     pub fn get_groups_count(query: Option<String>) -> GroupsCount {
-        let groups = GroupStore::filter(|_, group| match &query {
-            Some(query) => group.name.to_lowercase().contains(&query.to_lowercase()),
-            None => true,
+        let groups = GroupStore::filter(|group_id, group| {
+            Self::is_visible(*group_id, group)
+                && match &query {
+                    Some(query) => group.name.to_lowercase().contains(&query.to_lowercase()),
+                    None => true,
+                }
         });
 
         let (joined, invited) = match MemberStore::get(caller()) {
@@ -225,13 +341,20 @@ impl GroupCalls {
     pub fn edit_group(id: u64, update_group: UpdateGroup) -> Result<GroupResponse, ApiError> {
         let (id, mut group) = GroupStore::get(id)?;
         group.update(update_group);
+        let result = GroupStore::update(id, group);
+
+        // a raised member limit can make room for people on the waitlist
+        Self::promote_from_waitlist(id);
         let (members_count, events_count) = Self::get_group_count_data(&id);
 
+        AuditLogCalls::log(id, AuditAction::GroupEdited, Subject::Group(id), "");
+
         GroupResponse::from_result(
-            GroupStore::update(id, group),
+            result,
             Self::get_boosted_group(id),
             events_count,
             members_count,
+            FeedbackCalls::get_group_average_rating(id),
             Self::get_group_caller_data(id),
         )
     }
@@ -244,6 +367,7 @@ impl GroupCalls {
     pub fn get_groups_by_id(group_ids: Vec<u64>) -> Vec<GroupResponse> {
         GroupStore::get_many(group_ids)
             .into_iter()
+            .filter(|(group_id, group)| Self::is_visible(*group_id, group))
             .map(|(group_id, group)| {
                 let (members_count, events_count) = Self::get_group_count_data(&group_id);
                 GroupResponse::new(
@@ -252,6 +376,7 @@ impl GroupCalls {
                     Self::get_boosted_group(group_id),
                     events_count,
                     members_count,
+                    FeedbackCalls::get_group_average_rating(group_id),
                     Self::get_group_caller_data(group_id),
                 )
             })
@@ -295,11 +420,20 @@ impl GroupCalls {
             }
         }
 
-        // remove all events from group
+        // remove all events from group, events the group co-hosts stay with their owning group
         for event_id in events.events {
-            let _ = EventCalls::delete_event(event_id, group_id);
+            if EventCalls::delete_event(event_id, group_id).is_err() {
+                let _ = EventCalls::remove_event_co_host(event_id, group_id, group_id);
+            }
         }
 
+        Self::clear_group_publish_timer(group_id);
+
+        // remove the audit trail of the group
+        AuditLogCalls::remove_group_audit_logs(group_id);
+
+        GroupRatingStore::remove(group_id);
+
         // remove all references to the group
         (
             GroupStore::remove(group_id),
@@ -323,6 +457,7 @@ impl GroupCalls {
             Self::get_boosted_group(id),
             events_count,
             members_count,
+            FeedbackCalls::get_group_average_rating(id),
             Self::get_group_caller_data(id),
         )
     }
@@ -341,6 +476,7 @@ impl GroupCalls {
             Self::get_boosted_group(id),
             events_count,
             members_count,
+            FeedbackCalls::get_group_average_rating(id),
             Self::get_group_caller_data(id),
         )
     }
@@ -351,17 +487,38 @@ impl GroupCalls {
         role_name: String,
         color: String,
         index: u64,
+        custom_permissions: Option<Vec<PostPermission>>,
     ) -> Result<Role, ApiError> {
+        if index < MEMBER_ROLE_INDEX {
+            return Err(ApiError::bad_request().add_message(&format!(
+                "Role index should be at least {MEMBER_ROLE_INDEX}, the index of the member role"
+            )));
+        }
+
         let (id, mut group) = GroupStore::get(group_id)?;
-        let role = Role::new(
-            role_name,
-            false,
-            read_only_permissions(),
-            color,
-            Some(index),
-        );
+
+        let custom_permissions: Vec<Permission> = custom_permissions
+            .unwrap_or_default()
+            .into_iter()
+            .map(Permission::from)
+            .collect();
+
+        if let Some(permission) = custom_permissions
+            .iter()
+            .find(|p| !group.has_custom_permission(&p.name()))
+        {
+            return Err(ApiError::bad_request()
+                .add_message(&format!("Unknown custom permission: {}", permission.name())));
+        }
+
+        let mut permissions = read_only_permissions();
+        permissions.extend(custom_permissions);
+
+        let role = Role::new(role_name, false, permissions, color, Some(index));
         group.roles.push(role.clone());
         GroupStore::update(id, group)?;
+
+        AuditLogCalls::log(id, AuditAction::RoleAdded, Subject::Group(id), &role.name);
         Ok(role)
     }
 
@@ -408,6 +565,12 @@ impl GroupCalls {
                     Ok(())
                 })?;
 
+            AuditLogCalls::log(
+                group_id,
+                AuditAction::RoleRemoved,
+                Subject::Group(group_id),
+                &role_name,
+            );
             return Ok(true);
         }
 
@@ -426,27 +589,109 @@ impl GroupCalls {
     ) -> Result<bool, ApiError> {
         let (id, mut group) = GroupStore::get(group_id)?;
 
+        let permissions: Vec<Permission> =
+            post_permissions.into_iter().map(Permission::from).collect();
+
+        // only default permissions and the custom permissions of the group can be attached to a role
+        let default_permission_names = default_permission_names();
+        if let Some(permission) = permissions.iter().find(|p| {
+            !default_permission_names.contains(&p.name()) && !group.has_custom_permission(&p.name())
+        }) {
+            return Err(ApiError::bad_request()
+                .add_message(&format!("Unknown permission: {}", permission.name())));
+        }
+
         // get the index of the role
         let index = group.roles.iter().position(|r| r.name == role_name);
         // remove the actual role from the group based on the index
         if let Some(index) = index {
             let role = group.roles.get_mut(index).unwrap();
-            role.permissions = post_permissions.into_iter().map(Permission::from).collect();
+            role.permissions = permissions;
 
             GroupStore::update(id, group)?;
+
+            AuditLogCalls::log(
+                id,
+                AuditAction::RolePermissionsEdited,
+                Subject::Group(id),
+                &role_name,
+            );
             return Ok(true);
         }
 
         Ok(false)
     }
 
+    pub fn add_custom_permission_to_group(
+        group_id: u64,
+        name: String,
+        description: String,
+    ) -> Result<CustomPermission, ApiError> {
+        let (id, mut group) = GroupStore::get(group_id)?;
+
+        GroupValidation::validate_custom_permission(&group, &name, &description)?;
+
+        let custom_permission = CustomPermission::new(name, description);
+        group.add_custom_permission(custom_permission.clone());
+        GroupStore::update(id, group)?;
+
+        AuditLogCalls::log(
+            id,
+            AuditAction::CustomPermissionAdded,
+            Subject::Group(id),
+            &custom_permission.name,
+        );
+
+        Ok(custom_permission)
+    }
+
+    pub fn remove_custom_permission_from_group(
+        group_id: u64,
+        name: String,
+    ) -> Result<bool, ApiError> {
+        let (id, mut group) = GroupStore::get(group_id)?;
+
+        if !group.remove_custom_permission(&name) {
+            return Ok(false);
+        }
+
+        GroupStore::update(id, group)?;
+
+        AuditLogCalls::log(
+            id,
+            AuditAction::CustomPermissionRemoved,
+            Subject::Group(id),
+            &name,
+        );
+        Ok(true)
+    }
+
+    pub fn get_group_custom_permissions(group_id: u64) -> Result<Vec<CustomPermission>, ApiError> {
+        let (_, group) = GroupStore::get(group_id)?;
+        Ok(group.get_custom_permissions())
+    }
+
     pub async fn join_group(
         group_id: u64,
         account_identifier: Option<String>,
     ) -> Result<JoinedMemberResponse, ApiError> {
-        let member =
+        let (_, group) = GroupStore::get(group_id)?;
+        if group.is_draft() {
+            return Err(ApiError::bad_request().add_message("Group is not published yet"));
+        }
+
+        let (member, waitlist_position) =
             GroupValidation::validate_member_join(caller(), group_id, &account_identifier).await?;
 
+        if let Some(position) = waitlist_position {
+            return Ok(JoinedMemberResponse::waitlisted(
+                caller(),
+                member,
+                group_id,
+                position,
+            ));
+        }
+
         if member.joined.iter().filter(|f| f.0 != &group_id).count() == 0 {
             RewardBufferStore::notify_first_group_joined(caller());
         }
@@ -499,7 +744,7 @@ impl GroupCalls {
         principal: Principal,
         group_id: u64,
         accept: bool,
-    ) -> Result<Member, ApiError> {
+    ) -> Result<JoinedMemberResponse, ApiError> {
         let (_, mut member) = MemberStore::get(principal)?;
         let invite = member.get_invite(&group_id);
 
@@ -509,7 +754,22 @@ impl GroupCalls {
             );
         }
 
-        let (_, members_collection) = GroupMemberStore::get(group_id)?;
+        let (_, mut members_collection) = GroupMemberStore::get(group_id)?;
+        let (_, group) = GroupStore::get(group_id)?;
+
+        // an accepted request for a full group is moved to the waitlist
+        if accept && !group.has_capacity(members_collection.get_member_count()) {
+            member.remove_invite(group_id);
+            MemberStore::update(principal, member.clone())?;
+
+            members_collection.remove_invite(&principal);
+            let position = members_collection.add_to_waitlist(principal);
+            GroupMemberStore::update(group_id, members_collection)?;
+
+            return Ok(JoinedMemberResponse::waitlisted(
+                principal, member, group_id, position,
+            ));
+        }
 
         if let Some(invite) = invite {
             NotificationCalls::notification_user_join_request_group_accept_or_decline(
@@ -543,20 +803,42 @@ impl GroupCalls {
             MemberStore::update(principal, member.clone())?;
         }
 
-        Ok(member)
+        Ok(JoinedMemberResponse::new(principal, member, group_id))
     }
 
     // user accepts invite to the group
     pub fn accept_or_decline_owner_request_group_invite(
         group_id: u64,
         accept: bool,
-    ) -> Result<Member, ApiError> {
+    ) -> Result<JoinedMemberResponse, ApiError> {
         let (_, mut member) = MemberStore::get(caller())?;
 
         // Check if the member has a pending join request for the group
         if !member.has_pending_group_invite(group_id) {
             return Err(ApiError::not_found().add_message("Member does not have a pending invite"));
         }
+
+        // an accepted invite for a full group is moved to the waitlist, like an accepted join request
+        if accept {
+            let (_, group) = GroupStore::get(group_id)?;
+            let (_, mut members_collection) = GroupMemberStore::get(group_id)?;
+            if !group.has_capacity(members_collection.get_member_count()) {
+                member.remove_invite(group_id);
+                MemberStore::update(caller(), member.clone())?;
+
+                members_collection.remove_invite(&caller());
+                let position = members_collection.add_to_waitlist(caller());
+                GroupMemberStore::update(group_id, members_collection)?;
+
+                return Ok(JoinedMemberResponse::waitlisted(
+                    caller(),
+                    member,
+                    group_id,
+                    position,
+                ));
+            }
+        }
+
         if let Some(invite) = member.get_invite(&group_id) {
             // Add the group to the member and set the role
             if accept {
@@ -589,7 +871,7 @@ impl GroupCalls {
             MemberStore::update(caller(), member.clone())?;
         }
 
-        Ok(member)
+        Ok(JoinedMemberResponse::new(caller(), member, group_id))
     }
 
     // was assign_role
@@ -613,6 +895,13 @@ impl GroupCalls {
 
         let (principal, member) = MemberStore::update(member_principal, member.clone())?;
 
+        AuditLogCalls::log(
+            group_id,
+            AuditAction::MemberRoleAssigned,
+            Subject::Member(member_principal),
+            &role,
+        );
+
         HistoryEventLogic::send(
             group_id,
             member_principal,
@@ -652,6 +941,13 @@ impl GroupCalls {
 
         MemberStore::update(member_principal, member.clone())?;
 
+        AuditLogCalls::log(
+            group_id,
+            AuditAction::MemberRoleRemoved,
+            Subject::Member(member_principal),
+            &role,
+        );
+
         HistoryEventLogic::send(
             group_id,
             member_principal,
@@ -818,9 +1114,70 @@ impl GroupCalls {
         member_collection.remove_member(&caller());
         GroupMemberStore::update(id, member_collection)?;
 
+        Self::promote_from_waitlist(group_id);
+
         Ok(())
     }
 
+    pub fn get_group_waitlist(group_id: u64) -> Result<Vec<Principal>, ApiError> {
+        let (_, member_collection) = GroupMemberStore::get(group_id)?;
+        Ok(member_collection.get_waitlist_principals())
+    }
+
+    pub fn leave_group_waitlist(group_id: u64) -> Result<(), ApiError> {
+        let (id, mut member_collection) = GroupMemberStore::get(group_id)?;
+
+        if member_collection.get_waitlist_position(&caller()).is_none() {
+            return Err(ApiError::not_found().add_message("You are not on the waitlist"));
+        }
+
+        member_collection.remove_from_waitlist(&caller());
+        GroupMemberStore::update(id, member_collection)?;
+        Ok(())
+    }
+
+    /// Move principals from the waitlist into the group, in order, while there is room
+    fn promote_from_waitlist(group_id: u64) {
+        let (Ok((_, group)), Ok((_, mut member_collection))) =
+            (GroupStore::get(group_id), GroupMemberStore::get(group_id))
+        else {
+            return;
+        };
+
+        while group.has_capacity(member_collection.get_member_count()) {
+            let Some(principal) = member_collection.pop_waitlist() else {
+                break;
+            };
+
+            if group.is_banned_member(principal) {
+                continue;
+            }
+
+            let Ok((_, mut member)) = MemberStore::get(principal) else {
+                continue;
+            };
+
+            if member.is_group_joined(&group_id) {
+                continue;
+            }
+
+            member.remove_invite(group_id);
+            member.add_joined(group_id, vec![MEMBER_ROLE.to_string()]);
+            if MemberStore::update(principal, member.clone()).is_err() {
+                continue;
+            }
+
+            member_collection.create_member_from_invite(principal);
+
+            NotificationCalls::notification_group_waitlist_promoted(JoinedMemberResponse::new(
+                principal, member, group_id,
+            ));
+            RewardBufferStore::notify_group_member_count_changed(group_id);
+        }
+
+        let _ = GroupMemberStore::update(group_id, member_collection);
+    }
+
     pub fn remove_invite(group_id: u64) -> Result<(), ApiError> {
         let (_, mut member) = MemberStore::get(caller())?;
 
@@ -841,19 +1198,305 @@ impl GroupCalls {
         Ok(())
     }
 
-    pub fn get_banned_group_members(group_id: u64) -> Vec<Principal> {
+    pub fn get_banned_group_members(group_id: u64) -> Vec<ModerationRecord> {
         if let Ok((_, group)) = GroupStore::get(group_id) {
-            return group
-                .special_members
-                .into_iter()
-                .filter(|m| m.1 == RelationType::Blocked.to_string())
-                .map(|m| m.0)
-                .collect();
+            return group.get_moderation_records(ModerationKind::Ban);
         }
 
         Default::default()
     }
 
+    pub fn get_muted_group_members(group_id: u64) -> Vec<ModerationRecord> {
+        if let Ok((_, group)) = GroupStore::get(group_id) {
+            return group.get_moderation_records(ModerationKind::Mute);
+        }
+
+        Default::default()
+    }
+
+    pub fn ban_group_member(
+        group_id: u64,
+        principal: Principal,
+        reason: String,
+        expires_at: Option<u64>,
+    ) -> Result<ModerationRecord, ApiError> {
+        GroupValidation::validate_moderation(&reason, expires_at)?;
+        Self::remove_member_from_group(principal, group_id)?;
+        Self::add_moderation_record(
+            group_id,
+            ModerationRecord::new(principal, ModerationKind::Ban, reason, expires_at),
+        )
+    }
+
+    pub fn mute_group_member(
+        group_id: u64,
+        principal: Principal,
+        reason: String,
+        expires_at: Option<u64>,
+    ) -> Result<ModerationRecord, ApiError> {
+        GroupValidation::validate_moderation(&reason, expires_at)?;
+
+        let (_, member) = MemberStore::get(principal)?;
+        if !member.is_group_joined(&group_id) {
+            return Err(ApiError::bad_request().add_message("Member is not in the group"));
+        }
+
+        Self::add_moderation_record(
+            group_id,
+            ModerationRecord::new(principal, ModerationKind::Mute, reason, expires_at),
+        )
+    }
+
+    pub fn remove_ban_from_group_member(
+        group_id: u64,
+        principal: Principal,
+    ) -> Result<(), ApiError> {
+        let (_, mut group) = GroupStore::get(group_id)?;
+
+        group.remove_special_member_from_group(principal);
+        group.remove_moderation_record(principal, ModerationKind::Ban);
+        GroupStore::update(group_id, group)?;
+
+        AuditLogCalls::log(
+            group_id,
+            AuditAction::MemberBanLifted,
+            Subject::Member(principal),
+            "",
+        );
+
+        Self::clear_moderation_timer(group_id, principal);
+        Ok(())
+    }
+
+    pub fn remove_mute_from_group_member(
+        group_id: u64,
+        principal: Principal,
+    ) -> Result<(), ApiError> {
+        let (_, mut group) = GroupStore::get(group_id)?;
+
+        if group
+            .remove_moderation_record(principal, ModerationKind::Mute)
+            .is_none()
+        {
+            return Err(ApiError::not_found().add_message("Member is not muted"));
+        }
+
+        GroupStore::update(group_id, group)?;
+
+        AuditLogCalls::log(
+            group_id,
+            AuditAction::MemberMuteLifted,
+            Subject::Member(principal),
+            "",
+        );
+
+        Self::clear_moderation_timer(group_id, principal);
+        Ok(())
+    }
+
+    fn add_moderation_record(
+        group_id: u64,
+        record: ModerationRecord,
+    ) -> Result<ModerationRecord, ApiError> {
+        let (_, mut group) = GroupStore::get(group_id)?;
+
+        group.add_moderation_record(record.clone());
+        GroupStore::update(group_id, group)?;
+
+        let action = match record.kind {
+            ModerationKind::Ban => AuditAction::MemberBanned,
+            ModerationKind::Mute => AuditAction::MemberMuted,
+        };
+        AuditLogCalls::log(
+            group_id,
+            action,
+            Subject::Member(record.principal),
+            &record.reason,
+        );
+
+        match record.expires_at {
+            Some(expires_at) => Self::set_moderation_timer(
+                group_id,
+                record.principal,
+                expires_at.saturating_sub(time()),
+            ),
+            None => Self::clear_moderation_timer(group_id, record.principal),
+        }
+
+        Ok(record)
+    }
+
+    pub fn lift_expired_moderation(group_id: u64, principal: Principal) {
+        if let Ok((_, mut group)) = GroupStore::get(group_id) {
+            let expired_kind = group
+                .moderation_records
+                .as_ref()
+                .and_then(|records| records.iter().find(|r| r.principal == principal))
+                .filter(|r| r.is_expired())
+                .map(|r| r.kind.clone());
+
+            if let Some(kind) = expired_kind {
+                group.remove_moderation_record(principal, kind);
+                let _ = GroupStore::update(group_id, group);
+            }
+        }
+
+        Self::clear_moderation_timer(group_id, principal);
+    }
+
+    fn set_moderation_timer(group_id: u64, principal: Principal, nanos: u64) {
+        Self::clear_moderation_timer(group_id, principal);
+
+        let timer_id = set_timer(Duration::from_nanos(nanos), move || {
+            Self::lift_expired_moderation(group_id, principal)
+        });
+
+        MODERATION_TIMERS.with(|t| {
+            t.borrow_mut().insert((group_id, principal), timer_id);
+        });
+    }
+
+    fn clear_moderation_timer(group_id: u64, principal: Principal) {
+        if let Some(timer_id) =
+            MODERATION_TIMERS.with(|t| t.borrow_mut().remove(&(group_id, principal)))
+        {
+            clear_timer(timer_id);
+        }
+    }
+
+    /// Publish the draft now, or schedule it to be published at `publish_at`
+    pub fn publish_group(
+        group_id: u64,
+        publish_at: Option<u64>,
+    ) -> Result<GroupResponse, ApiError> {
+        let (_, mut group) = GroupStore::get(group_id)?;
+
+        if !group.is_draft() {
+            return Err(ApiError::bad_request().add_message("Group is already published"));
+        }
+
+        GroupValidation::validate_publish_at(publish_at)?;
+
+        match publish_at {
+            Some(publish_at) => {
+                GroupStore::update(group_id, group.schedule_publish(publish_at))?;
+                Self::schedule_group_publish(group_id);
+            }
+            None => {
+                Self::clear_group_publish_timer(group_id);
+                GroupStore::update(group_id, group.publish())?;
+                Self::notify_group_published(group_id);
+            }
+        }
+
+        Self::get_group(group_id)
+    }
+
+    fn schedule_group_publish(group_id: u64) {
+        Self::clear_group_publish_timer(group_id);
+
+        let Some(publish_at) = GroupStore::get(group_id)
+            .ok()
+            .and_then(|(_, group)| group.publish_at)
+        else {
+            return;
+        };
+
+        let timer_id = set_timer(
+            Duration::from_nanos(publish_at.saturating_sub(time())),
+            move || Self::publish_scheduled_group(group_id),
+        );
+
+        GROUP_PUBLISH_TIMERS.with(|t| {
+            t.borrow_mut().insert(group_id, timer_id);
+        });
+    }
+
+    fn clear_group_publish_timer(group_id: u64) {
+        if let Some(timer_id) = GROUP_PUBLISH_TIMERS.with(|t| t.borrow_mut().remove(&group_id)) {
+            clear_timer(timer_id);
+        }
+    }
+
+    fn publish_scheduled_group(group_id: u64) {
+        GROUP_PUBLISH_TIMERS.with(|t| t.borrow_mut().remove(&group_id));
+
+        let Ok((_, mut group)) = GroupStore::get(group_id) else {
+            return;
+        };
+
+        if !group.is_draft() || group.publish_at.is_none() {
+            return;
+        }
+
+        if GroupStore::update(group_id, group.publish()).is_ok() {
+            Self::notify_group_published(group_id);
+        }
+    }
+
+    fn notify_group_published(group_id: u64) {
+        let receivers = GroupMemberStore::get(group_id)
+            .map(|(_, members)| members.get_member_principals())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|principal| principal != &caller())
+            .collect();
+
+        NotificationCalls::notification_group_published(receivers, group_id);
+    }
+
+    pub fn start_publish_timers_after_upgrade() {
+        GroupStore::filter(|_, group| group.publish_at.is_some())
+            .into_iter()
+            .for_each(|(group_id, _)| Self::schedule_group_publish(group_id));
+    }
+
+    /// Drafts are only visible to principals that can edit the group
+    fn is_visible(group_id: u64, group: &Group) -> bool {
+        !group.is_draft()
+            || has_permission(
+                caller(),
+                group_id,
+                &PermissionType::Group(None),
+                &PermissionActionType::Edit,
+            )
+            .is_ok()
+    }
+
+    /// Move custom roles with an index from before the role hierarchy above the member role
+    pub fn remap_legacy_role_indices_after_upgrade() {
+        for (group_id, mut group) in GroupStore::get_all() {
+            let mut changed = false;
+            for role in group.roles.iter_mut() {
+                changed |= role.remap_legacy_index();
+            }
+
+            if changed {
+                let _ = GroupStore::update(group_id, group);
+            }
+        }
+    }
+
+    pub fn start_moderation_timers_after_upgrade() {
+        GroupStore::get_all()
+            .into_iter()
+            .for_each(|(group_id, group)| {
+                group
+                    .moderation_records
+                    .unwrap_or_default()
+                    .into_iter()
+                    .for_each(|record| {
+                        if let Some(expires_at) = record.expires_at {
+                            Self::set_moderation_timer(
+                                group_id,
+                                record.principal,
+                                expires_at.saturating_sub(time()),
+                            );
+                        }
+                    });
+            });
+    }
+
     pub fn remove_member_from_group(principal: Principal, group_id: u64) -> Result<(), ApiError> {
         let (_, mut member) = MemberStore::get(principal)?;
 
@@ -871,6 +1514,15 @@ impl GroupCalls {
         member_collection.remove_member(&principal);
         GroupMemberStore::update(id, member_collection)?;
 
+        AuditLogCalls::log(
+            group_id,
+            AuditAction::MemberRemoved,
+            Subject::Member(principal),
+            "",
+        );
+
+        Self::promote_from_waitlist(group_id);
+
         NotificationCalls::notification_remove_group_member(
             JoinedMemberResponse::new(principal, member, group_id),
             Self::get_higher_role_members(group_id),
@@ -953,6 +1605,10 @@ impl GroupCalls {
         let is_pinned = ProfileStore::get(caller())
             .is_ok_and(|(_, profile)| profile.is_pinned(&Subject::Group(group_id)));
 
+        let waitlist_position = GroupMemberStore::get(group_id)
+            .ok()
+            .and_then(|(_, member_collection)| member_collection.get_waitlist_position(&caller()));
+
         let mut joined: Option<JoinedMemberResponse> = None;
         let mut invite: Option<InviteMemberResponse> = None;
         if let Ok((_, member)) = MemberStore::get(caller()) {
@@ -969,7 +1625,13 @@ impl GroupCalls {
             }
         }
 
-        Some(GroupCallerData::new(joined, invite, is_starred, is_pinned))
+        Some(GroupCallerData::new(
+            joined,
+            invite,
+            is_starred,
+            is_pinned,
+            waitlist_position,
+        ))
     }
 
     pub fn get_group_count_data(group_id: &u64) -> (u64, u64) {
@@ -1030,7 +1692,7 @@ impl GroupCalls {
     pub fn get_to_group_transfer_requests(
         principal: Principal,
     ) -> Vec<(u64, GroupTransferRequest)> {
-        GroupTransferRequestStore::filter(|_, r| r.to == principal)
+        GroupTransferRequestStore::filter(|_, r| r.to == principal && !r.is_expired())
     }
 
     pub fn create_transfer_group_ownership_request(
@@ -1039,8 +1701,12 @@ impl GroupCalls {
         to: Principal,
     ) -> Result<(u64, GroupTransferRequest), ApiError> {
         // if there already is a transfer request for this group, return an error
-        if GroupTransferRequestStore::get(group_id).is_ok() {
-            return Err(ApiError::bad_request().add_message("Transfer request already exists"));
+        if let Ok((_, existing_request)) = GroupTransferRequestStore::get(group_id) {
+            if !existing_request.is_expired() {
+                return Err(ApiError::bad_request().add_message("Transfer request already exists"));
+            }
+            // the timer did not clean up the expired request yet
+            Self::expire_transfer_group_ownership_request(group_id);
         }
 
         // if the caller is not the owner of the group, return an error
@@ -1054,8 +1720,28 @@ impl GroupCalls {
             return Err(ApiError::bad_request().add_message("Recipient is not in the group"));
         }
 
-        let transfer_request = GroupTransferRequest::new(from, to);
-        GroupTransferRequestStore::insert_by_key(group_id, transfer_request)
+        let ttl = hours_to_nanoseconds(GROUP_TRANSFER_REQUEST_TTL_HOURS);
+        let (_, mut transfer_request) = GroupTransferRequestStore::insert_by_key(
+            group_id,
+            GroupTransferRequest::new(from, to, ttl),
+        )?;
+
+        let notification_id = NotificationCalls::notification_transfer_group_ownership_request(
+            GroupTransferRequestResponse::new(group_id, transfer_request.clone()),
+        )?;
+        transfer_request.set_notification_id(notification_id);
+
+        let result = GroupTransferRequestStore::update(group_id, transfer_request)?;
+        Self::set_transfer_request_timer(group_id, ttl);
+
+        AuditLogCalls::log(
+            group_id,
+            AuditAction::OwnershipTransferRequested,
+            Subject::Member(to),
+            "",
+        );
+
+        Ok(result)
     }
 
     pub fn cancel_transfer_group_ownership_request(
@@ -1063,7 +1749,7 @@ impl GroupCalls {
         from: Principal,
     ) -> Result<bool, ApiError> {
         // if there is no transfer request for this group, return an error
-        GroupTransferRequestStore::get(group_id)?;
+        let (_, request) = GroupTransferRequestStore::get(group_id)?;
 
         // if the caller is not the owner of the group, return an error
         let (_, group) = GroupStore::get(group_id)?;
@@ -1072,6 +1758,11 @@ impl GroupCalls {
                 .add_message("You don't have the ability to cancel the transfer request"));
         }
 
+        NotificationCalls::notification_cancel_transfer_group_ownership_request((
+            group_id, request,
+        ));
+        Self::clear_transfer_request_timer(group_id);
+
         Ok(GroupTransferRequestStore::remove(group_id))
     }
 
@@ -1087,18 +1778,82 @@ impl GroupCalls {
             return Err(ApiError::unauthorized().add_message("You are not the intended recipient"));
         }
 
+        // if the request is expired but the timer did not clean it up yet, return an error
+        if request.is_expired() {
+            Self::expire_transfer_group_ownership_request(group_id);
+            return Err(ApiError::bad_request().add_message("Transfer request has expired"));
+        }
+
         // if the recipient is not in the group, return an error
         let (_, member) = MemberStore::get(request.to)?;
         if !member.is_group_joined(&group_id) {
             GroupTransferRequestStore::remove(group_id);
+            Self::clear_transfer_request_timer(group_id);
             return Err(ApiError::bad_request().add_message("Recipient is not in the group"));
         }
 
-        if accept {
-            Self::transfer_group_ownership(group_id, request.from, request.to)
+        let result = if accept {
+            let result = Self::transfer_group_ownership(group_id, request.from, request.to)?;
+            AuditLogCalls::log(
+                group_id,
+                AuditAction::OwnershipTransferred,
+                Subject::Member(request.to),
+                "",
+            );
+            result
         } else {
-            Ok(GroupTransferRequestStore::remove(group_id))
+            GroupTransferRequestStore::remove(group_id)
+        };
+
+        Self::clear_transfer_request_timer(group_id);
+        // best-effort, requests created before notifications were linked have no notification
+        let _ = NotificationCalls::notification_accept_or_decline_transfer_group_ownership_request(
+            (group_id, request),
+            accept,
+        );
+
+        Ok(result)
+    }
+
+    pub fn expire_transfer_group_ownership_request(group_id: u64) {
+        if let Ok((_, request)) = GroupTransferRequestStore::get(group_id) {
+            if request.is_expired() {
+                NotificationCalls::notification_expire_transfer_group_ownership_request((
+                    group_id, request,
+                ));
+                GroupTransferRequestStore::remove(group_id);
+            }
         }
+
+        Self::clear_transfer_request_timer(group_id);
+    }
+
+    fn set_transfer_request_timer(group_id: u64, nanos: u64) {
+        Self::clear_transfer_request_timer(group_id);
+
+        let timer_id = set_timer(Duration::from_nanos(nanos), move || {
+            Self::expire_transfer_group_ownership_request(group_id)
+        });
+
+        TRANSFER_REQUEST_TIMERS.with(|t| {
+            t.borrow_mut().insert(group_id, timer_id);
+        });
+    }
+
+    fn clear_transfer_request_timer(group_id: u64) {
+        if let Some(timer_id) = TRANSFER_REQUEST_TIMERS.with(|t| t.borrow_mut().remove(&group_id)) {
+            clear_timer(timer_id);
+        }
+    }
+
+    pub fn start_transfer_request_timers_after_upgrade() {
+        GroupTransferRequestStore::get_all()
+            .into_iter()
+            .for_each(|(group_id, request)| {
+                if let Some(expires_at) = request.expires_at {
+                    Self::set_transfer_request_timer(group_id, expires_at.saturating_sub(time()));
+                }
+            });
     }
 
     pub fn transfer_group_ownership(
@@ -1152,9 +1907,22 @@ impl GroupValidation {
             ),
         ];
 
+        if post_group.max_members == Some(0) {
+            return Err(ApiError::bad_request().add_message("max_members should be at least 1"));
+        }
+
+        Self::validate_publish_at(post_group.publish_at)?;
+
         Validator::new(validator_fields).validate()
     }
 
+    pub fn validate_publish_at(publish_at: Option<u64>) -> Result<(), ApiError> {
+        if publish_at.is_some_and(|publish_at| publish_at <= time()) {
+            return Err(ApiError::bad_request().add_message("publish_at should be in the future"));
+        }
+        Ok(())
+    }
+
     pub fn validate_update_group(update_group: UpdateGroup) -> Result<(), ApiError> {
         let validator_fields = vec![
             ValidateField(
@@ -1175,9 +1943,69 @@ impl GroupValidation {
             ),
         ];
 
+        if update_group.max_members == Some(0) {
+            return Err(ApiError::bad_request().add_message("max_members should be at least 1"));
+        }
+
         Validator::new(validator_fields).validate()
     }
 
+    pub fn validate_moderation(reason: &str, expires_at: Option<u64>) -> Result<(), ApiError> {
+        let validator_fields = vec![ValidateField(
+            ValidationType::StringLength(reason.to_string(), 0, 500),
+            "reason".to_string(),
+        )];
+
+        Validator::new(validator_fields).validate()?;
+
+        if expires_at.is_some_and(|expires_at| expires_at <= time()) {
+            return Err(ApiError::bad_request().add_message("Expiry must be in the future"));
+        }
+
+        Ok(())
+    }
+
+    pub fn validate_custom_permission(
+        group: &Group,
+        name: &str,
+        description: &str,
+    ) -> Result<(), ApiError> {
+        let validator_fields = vec![
+            ValidateField(
+                ValidationType::StringLength(name.to_string(), 3, 64),
+                "name".to_string(),
+            ),
+            ValidateField(
+                ValidationType::StringLength(description.to_string(), 0, 500),
+                "description".to_string(),
+            ),
+            ValidateField(
+                ValidationType::Count(group.get_custom_permissions().len(), 0, 49),
+                "custom_permissions".to_string(),
+            ),
+        ];
+
+        Validator::new(validator_fields).validate()?;
+
+        // the name is used as a key by other canisters, so keep it simple
+        if !name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || "_-.:".contains(c))
+        {
+            return Err(ApiError::bad_request().add_message(
+                "Permission name can only contain lowercase letters, digits and `_-.:`",
+            ));
+        }
+
+        if default_permission_names().contains(&name.to_string())
+            || group.has_custom_permission(name)
+        {
+            return Err(ApiError::duplicate().add_message("Permission already exists"));
+        }
+
+        Ok(())
+    }
+
     async fn validate_group_privacy(
         caller: &Principal,
         account_identifier: Option<String>,
@@ -1383,11 +2211,24 @@ impl GroupValidation {
         }
     }
 
+    /// Put the principal on the waitlist of a full group, the member is returned unchanged with the waitlist position
+    fn add_to_waitlist(
+        group_id: u64,
+        mut member_collection: MemberCollection,
+        principal: Principal,
+        member: Member,
+    ) -> Result<(Member, Option<u64>), ApiError> {
+        let position = member_collection.add_to_waitlist(principal);
+        GroupMemberStore::update(group_id, member_collection)?;
+
+        Ok((member, Some(position)))
+    }
+
     async fn validate_member_join(
         caller: Principal,
         group_id: u64,
         account_identifier: &Option<String>,
-    ) -> Result<Member, ApiError> {
+    ) -> Result<(Member, Option<u64>), ApiError> {
         let (group_id, group) = GroupStore::get(group_id)?;
         let (_, mut member) = MemberStore::get(caller)?;
 
@@ -1401,11 +2242,16 @@ impl GroupValidation {
         }
 
         let (_, mut member_collection) = GroupMemberStore::get(group_id)?;
+        let has_capacity = group.has_capacity(member_collection.get_member_count());
 
         use Privacy::*;
         let validated_member = match group.privacy {
             // If the group is public, add the member to the group
             Public => {
+                if !has_capacity {
+                    return Self::add_to_waitlist(group_id, member_collection, caller, member);
+                }
+
                 member.add_joined(group_id, vec![MEMBER_ROLE.to_string()]);
                 let group_member_principals = GroupCalls::get_group_members(group_id)?
                     .iter()
@@ -1458,6 +2304,15 @@ impl GroupValidation {
                             }
                         }
                         if is_valid {
+                            if !has_capacity {
+                                return Self::add_to_waitlist(
+                                    group_id,
+                                    member_collection,
+                                    caller,
+                                    member,
+                                );
+                            }
+
                             member.add_joined(group_id, vec![MEMBER_ROLE.to_string()]);
                             member_collection.add_member(caller);
 
@@ -1486,6 +2341,15 @@ impl GroupValidation {
                             }
                         }
                         if is_valid {
+                            if !has_capacity {
+                                return Self::add_to_waitlist(
+                                    group_id,
+                                    member_collection,
+                                    caller,
+                                    member,
+                                );
+                            }
+
                             member.add_joined(group_id, vec![MEMBER_ROLE.to_string()]);
                             member_collection.add_member(caller);
 
@@ -1506,6 +2370,6 @@ impl GroupValidation {
 
         GroupMemberStore::update(group_id, member_collection)?;
 
-        validated_member
+        validated_member.map(|member| (member, None))
     }
 }
@@ -13,6 +13,20 @@ impl Ledger {
     pub async fn validate_transaction(
         principal: Principal,
         block_index: BlockIndex,
+    ) -> Result<Tokens, ApiError> {
+        Self::validate_transfer(
+            principal,
+            Principal::from_text(CATALYZE_MULTI_SIG).unwrap(),
+            block_index,
+        )
+        .await
+    }
+
+    // This method checks if the transaction is send from the given principal to the given recipient
+    pub async fn validate_transfer(
+        principal: Principal,
+        recipient: Principal,
+        block_index: BlockIndex,
     ) -> Result<Tokens, ApiError> {
         // Get the block
         let block = Self::get_block(block_index).await;
@@ -30,11 +44,7 @@ impl Ledger {
                         if from != Self::principal_to_account_identifier(principal) {
                             return Err(ApiError::bad_request().add_message("Invalid from adrress"));
                         }
-                        if to
-                            != Self::principal_to_account_identifier(
-                                Principal::from_text(CATALYZE_MULTI_SIG).unwrap(),
-                            )
-                        {
+                        if to != Self::principal_to_account_identifier(recipient) {
                             return Err(ApiError::bad_request().add_message("Invalid to address"));
                         }
                         Ok(amount)
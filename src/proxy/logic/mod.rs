@@ -1,6 +1,11 @@
 pub mod attendee_logic;
+pub mod audit_log_logic;
 pub mod boost_logic;
+pub mod calendar_logic;
+pub mod check_in_logic;
 pub mod event_logic;
+pub mod export_logic;
+pub mod feedback_logic;
 pub mod friend_request_logic;
 pub mod group_logic;
 pub mod history_event_logic;
@@ -12,5 +17,6 @@ pub mod notification_logic;
 pub mod profile_logic;
 pub mod report_logic;
 pub mod reward_buffer_logic;
+pub mod session_logic;
 pub mod topic_logic;
 pub mod websocket_logic;
@@ -3,11 +3,13 @@ use canister_types::models::{
     api_error::ApiError,
     attendee::{AttendeeInvite, InviteAttendeeResponse, JoinedAttendeeResponse},
     friend_request::{FriendRequest, FriendRequestResponse},
+    group_transfer_request::{GroupTransferRequest, GroupTransferRequestResponse},
     member::{InviteMemberResponse, JoinedMemberResponse, MemberInvite},
     notification::{
-        EventNotificationType, GroupNotificationType, MultisigNotificationType, Notification,
-        NotificationResponse, NotificationType, RelationNotificationType,
-        TransactionNotificationType,
+        EventCanceledResponse, EventNotificationType, EventReminderResponse,
+        EventRescheduledResponse, EventTransferredResponse, GroupNotificationType,
+        MultisigNotificationType, Notification, NotificationResponse, NotificationType,
+        RelationNotificationType, TransactionNotificationType,
     },
     transaction_data::{TransactionCompleteData, TransactionData},
     user_notifications::{UserNotificationData, UserNotifications},
@@ -132,6 +134,15 @@ impl NotificationCalls {
         }
     }
 
+    // store + sends notification, can be called from a timer so the caller is not stored as receiver
+    pub fn notification_group_published(receivers: Vec<Principal>, group_id: u64) {
+        let _ = Self::add_and_send_notification_without_caller(
+            receivers,
+            NotificationType::Group(GroupNotificationType::GroupPublished(group_id)),
+            false,
+        );
+    }
+
     pub fn notification_leave_group(receivers: Vec<Principal>, group_id: u64) {
         for receiver in receivers {
             Self::send_notification(
@@ -145,6 +156,17 @@ impl NotificationCalls {
         }
     }
 
+    pub fn notification_group_waitlist_promoted(member: JoinedMemberResponse) {
+        Self::send_notification(
+            None,
+            Notification::new(
+                NotificationType::Group(GroupNotificationType::WaitlistPromoted(member.clone())),
+                false,
+            ),
+            member.principal,
+        );
+    }
+
     // stores + sends notification
     pub fn notification_user_join_request_group(
         receivers: Vec<Principal>,
@@ -370,6 +392,108 @@ impl NotificationCalls {
         }
     }
 
+    // stores + sends notification
+    pub fn notification_transfer_group_ownership_request(
+        transfer_request: GroupTransferRequestResponse,
+    ) -> Result<u64, ApiError> {
+        let (notification_id, _) = Self::add_and_send_notification(
+            vec![transfer_request.to],
+            NotificationType::Group(GroupNotificationType::TransferOwnershipRequest(
+                transfer_request,
+            )),
+            true,
+        )?;
+
+        Ok(notification_id)
+    }
+
+    /// stores + sends notification
+    pub fn notification_accept_or_decline_transfer_group_ownership_request(
+        transfer_request_data: (u64, GroupTransferRequest),
+        is_accepted: bool,
+    ) -> Result<(), ApiError> {
+        let (group_id, transfer_request) = transfer_request_data;
+
+        // check if the notification exists
+        if let Some(notification_id) = transfer_request.notification_id {
+            let (_, mut notification) = NotificationStore::get(notification_id)?;
+
+            let transfer_request_response =
+                GroupTransferRequestResponse::new(group_id, transfer_request.clone());
+
+            // mark the notification as accepted, this also marks it as not actionable
+            let notification_type = match is_accepted {
+                true => {
+                    GroupNotificationType::TransferOwnershipRequestAccept(transfer_request_response)
+                }
+                false => GroupNotificationType::TransferOwnershipRequestDecline(
+                    transfer_request_response,
+                ),
+            };
+            notification.mark_as_accepted(is_accepted, NotificationType::Group(notification_type));
+            let _ = NotificationStore::update(notification_id, notification.clone());
+
+            Self::send_notification(
+                Some(notification_id),
+                notification.clone(),
+                transfer_request.from,
+            );
+            Self::send_notification(None, notification, transfer_request.to);
+
+            Ok(())
+        } else {
+            Err(ApiError::not_found())
+        }
+    }
+
+    // sends notification
+    pub fn notification_cancel_transfer_group_ownership_request(
+        transfer_request_data: (u64, GroupTransferRequest),
+    ) {
+        let (group_id, transfer_request) = transfer_request_data;
+
+        if let Some(notification_id) = transfer_request.notification_id {
+            if let Ok((_, mut notification)) = NotificationStore::get(notification_id) {
+                notification.mark_as_accepted(
+                    false,
+                    NotificationType::Group(GroupNotificationType::TransferOwnershipRequestCancel(
+                        GroupTransferRequestResponse::new(group_id, transfer_request.clone()),
+                    )),
+                );
+                let _ = NotificationStore::update(notification_id, notification.clone());
+
+                Self::send_notification(Some(notification_id), notification, transfer_request.to);
+            }
+        }
+    }
+
+    // sends notification
+    pub fn notification_expire_transfer_group_ownership_request(
+        transfer_request_data: (u64, GroupTransferRequest),
+    ) {
+        let (group_id, transfer_request) = transfer_request_data;
+
+        if let Some(notification_id) = transfer_request.notification_id {
+            if let Ok((_, mut notification)) = NotificationStore::get(notification_id) {
+                // expiring is not an action taken by a user, so the notification is only marked as not actionable
+                notification.notification_type = NotificationType::Group(
+                    GroupNotificationType::TransferOwnershipRequestExpired(
+                        GroupTransferRequestResponse::new(group_id, transfer_request.clone()),
+                    ),
+                );
+                notification.set_is_actionable(false);
+                let _ = NotificationStore::update(notification_id, notification.clone());
+
+                Self::send_notification(
+                    Some(notification_id),
+                    notification.clone(),
+                    transfer_request.to,
+                );
+                Self::send_notification(Some(notification_id), notification, transfer_request.from);
+            }
+        }
+    }
+
     // Event notifications
 
     // sends notification
@@ -388,6 +512,81 @@ impl NotificationCalls {
         }
     }
 
+    // store + sends notification
+    pub fn notification_event_co_host_invite(
+        receivers: Vec<Principal>,
+        group_id: u64,
+        event_id: u64,
+    ) {
+        let _ = Self::add_and_send_notification(
+            receivers,
+            NotificationType::Event(EventNotificationType::CoHostInvite((group_id, event_id))),
+            true,
+        );
+    }
+
+    // store + sends notification, can be called from a timer so the caller is not stored as receiver
+    pub fn notification_event_published(receivers: Vec<Principal>, group_id: u64, event_id: u64) {
+        let _ = Self::add_and_send_notification_without_caller(
+            receivers,
+            NotificationType::Event(EventNotificationType::EventPublished((group_id, event_id))),
+            false,
+        );
+    }
+
+    // store + sends notification
+    pub fn notification_event_canceled(receivers: Vec<Principal>, canceled: EventCanceledResponse) {
+        let _ = Self::add_and_send_notification(
+            receivers,
+            NotificationType::Event(EventNotificationType::EventCanceled(canceled)),
+            false,
+        );
+    }
+
+    // store + sends notification
+    pub fn notification_event_rescheduled(
+        receivers: Vec<Principal>,
+        rescheduled: EventRescheduledResponse,
+    ) {
+        let _ = Self::add_and_send_notification(
+            receivers,
+            NotificationType::Event(EventNotificationType::EventRescheduled(rescheduled)),
+            false,
+        );
+    }
+
+    // store + sends notification
+    pub fn notification_event_transferred(
+        receivers: Vec<Principal>,
+        transferred: EventTransferredResponse,
+    ) {
+        let _ = Self::add_and_send_notification(
+            receivers,
+            NotificationType::Event(EventNotificationType::EventTransferred(transferred)),
+            false,
+        );
+    }
+
+    pub fn notification_event_waitlist_promoted(attendee: JoinedAttendeeResponse) {
+        Self::send_notification(
+            None,
+            Notification::new(
+                NotificationType::Event(EventNotificationType::WaitlistPromoted(attendee.clone())),
+                false,
+            ),
+            attendee.principal,
+        );
+    }
+
+    // store + sends notification, called from a timer so the caller is not stored as receiver
+    pub fn notification_event_reminder(receivers: Vec<Principal>, reminder: EventReminderResponse) {
+        let _ = Self::add_and_send_notification_without_caller(
+            receivers,
+            NotificationType::Event(EventNotificationType::EventReminder(reminder)),
+            false,
+        );
+    }
+
     // store + sends notification
     pub fn notification_user_join_request_event(
         receivers: Vec<Principal>,
@@ -531,6 +730,17 @@ impl NotificationCalls {
         }
     }
 
+    pub fn notification_change_event_attendee_role(attendee: JoinedAttendeeResponse) {
+        Self::send_notification(
+            None,
+            Notification::new(
+                NotificationType::Event(EventNotificationType::RoleAssignByOwner(attendee.clone())),
+                false,
+            ),
+            attendee.principal,
+        );
+    }
+
     pub fn notification_remove_event_attendee(
         attendee: JoinedAttendeeResponse,
         receivers: Vec<Principal>,
@@ -1,11 +1,11 @@
 use crate::storage::{
-    reward_canister_storage::RewardCanisterStorage, CellStorage, GroupMemberStore, GroupStore,
-    RewardBufferStore, RewardTimerStore, StorageQueryable, StorageUpdateable,
+    reward_canister_storage::RewardCanisterStorage, CellStorage, EventStore, GroupMemberStore,
+    GroupStore, RewardBufferStore, RewardTimerStore, StorageQueryable, StorageUpdateable,
 };
 
 use candid::Principal;
 use canister_types::models::reward::{
-    Activity, GroupReward, RewardDataPackage, RewardableActivity,
+    Activity, EventAttendanceReward, GroupReward, RewardDataPackage, RewardableActivity,
 };
 use ic_cdk::call;
 
@@ -19,6 +19,7 @@ pub fn process_buffer() -> RewardDataPackage {
     let mut user_referrals: Vec<Principal> = Vec::new();
     let mut filled_profiles: Vec<Principal> = Vec::new();
     let mut first_group_joined: Vec<Principal> = Vec::new();
+    let mut event_attendances: Vec<EventAttendanceReward> = Vec::new();
 
     for rewardable in rewardables.iter() {
         match rewardable.get_activity() {
@@ -37,6 +38,15 @@ pub fn process_buffer() -> RewardDataPackage {
             Activity::UserReferral(principal) => user_referrals.push(principal),
             Activity::UserProfileFilled(principal) => filled_profiles.push(principal),
             Activity::FirstGroupJoined(principal) => first_group_joined.push(principal),
+            Activity::EventAttendance((event_id, principal)) => {
+                if let Ok((_, event)) = EventStore::get(event_id) {
+                    event_attendances.push(EventAttendanceReward::new(
+                        principal,
+                        event_id,
+                        event.group_id,
+                    ));
+                }
+            }
             Activity::None => {}
         }
     }
@@ -46,6 +56,7 @@ pub fn process_buffer() -> RewardDataPackage {
         user_referrals,
         filled_profiles,
         first_group_joined,
+        event_attendances,
     }
 }
 
@@ -0,0 +1,204 @@
+use crate::{
+    helpers::validator::Validator,
+    storage::{
+        AttendeeStore, EventSessionStore, EventStore, StorageInsertableByKey, StorageQueryable,
+    },
+};
+
+use super::event_logic::EventCalls;
+use canister_types::models::{
+    api_error::ApiError,
+    date_range::DateRange,
+    event::Event,
+    privacy::Privacy,
+    session::{EventSessionResponse, PostEventSession},
+    validation::{ValidateField, ValidationType},
+};
+use ic_cdk::{api::time, caller};
+
+pub struct SessionCalls;
+
+impl SessionCalls {
+    pub fn add_event_session(
+        event_id: u64,
+        group_id: u64,
+        post_session: PostEventSession,
+    ) -> Result<EventSessionResponse, ApiError> {
+        let event = EventCalls::get_hosted_event(event_id, group_id)?;
+        Self::validate_session(&event, &post_session)?;
+
+        let mut sessions = EventSessionStore::get_or_default(event_id);
+        let session = sessions.add(post_session, caller(), time());
+        EventSessionStore::upsert_by_key(event_id, sessions);
+
+        Ok(EventSessionResponse::new(event_id, session, caller()))
+    }
+
+    pub fn edit_event_session(
+        event_id: u64,
+        group_id: u64,
+        session_id: u64,
+        post_session: PostEventSession,
+    ) -> Result<EventSessionResponse, ApiError> {
+        let event = EventCalls::get_hosted_event(event_id, group_id)?;
+        Self::validate_session(&event, &post_session)?;
+
+        let mut sessions = EventSessionStore::get_or_default(event_id);
+        let session = sessions
+            .update(session_id, post_session, time())
+            .ok_or_else(|| ApiError::not_found().add_message("Session not found"))?;
+        EventSessionStore::upsert_by_key(event_id, sessions);
+
+        Ok(EventSessionResponse::new(event_id, session, caller()))
+    }
+
+    pub fn remove_event_session(
+        event_id: u64,
+        group_id: u64,
+        session_id: u64,
+    ) -> Result<(), ApiError> {
+        EventCalls::get_hosted_event(event_id, group_id)?;
+
+        let mut sessions = EventSessionStore::get_or_default(event_id);
+        if !sessions.remove(session_id) {
+            return Err(ApiError::not_found().add_message("Session not found"));
+        }
+        EventSessionStore::upsert_by_key(event_id, sessions);
+        Ok(())
+    }
+
+    pub fn bookmark_event_session(
+        event_id: u64,
+        session_id: u64,
+        bookmark: bool,
+    ) -> Result<EventSessionResponse, ApiError> {
+        let (_, attendee) = AttendeeStore::get(caller())?;
+        if !attendee.is_event_joined(&event_id) {
+            return Err(ApiError::unauthorized()
+                .add_message("Only attendees of the event can bookmark sessions"));
+        }
+
+        let mut sessions = EventSessionStore::get_or_default(event_id);
+        let session = sessions
+            .set_bookmark(session_id, caller(), bookmark)
+            .ok_or_else(|| ApiError::not_found().add_message("Session not found"))?;
+        EventSessionStore::upsert_by_key(event_id, sessions);
+
+        Ok(EventSessionResponse::new(event_id, session, caller()))
+    }
+
+    pub fn get_event_agenda(event_id: u64) -> Result<Vec<EventSessionResponse>, ApiError> {
+        let (_, event) = EventStore::get(event_id)?;
+
+        if !EventCalls::is_visible(&event) {
+            return Err(ApiError::not_found());
+        }
+
+        if event.match_privacy(Privacy::InviteOnly) {
+            let (_, attendee) = AttendeeStore::get(caller())?;
+            if !attendee.is_event_joined(&event_id) {
+                return Err(ApiError::unauthorized());
+            }
+        }
+
+        Ok(EventSessionStore::get_or_default(event_id)
+            .get_agenda()
+            .into_iter()
+            .map(|session| EventSessionResponse::new(event_id, session, caller()))
+            .collect())
+    }
+
+    // Sessions should take place within the (first occurrence of the) event
+    fn validate_session(event: &Event, post_session: &PostEventSession) -> Result<(), ApiError> {
+        if let Some(message) = Self::session_date_error(event, &post_session.date) {
+            return Err(ApiError::bad_request().add_message(message));
+        }
+
+        Validator::new(Self::session_fields(post_session)).validate()
+    }
+
+    fn session_date_error(event: &Event, date: &DateRange) -> Option<&'static str> {
+        if date.start_date() > date.end_date() {
+            return Some("The session start_date is after the end_date");
+        }
+
+        if event.date.is_outside(date.start_date()) || event.date.is_outside(date.end_date()) {
+            return Some("The session should take place within the event date");
+        }
+
+        None
+    }
+
+    fn session_fields(post_session: &PostEventSession) -> Vec<ValidateField> {
+        vec![
+            ValidateField(
+                ValidationType::StringLength(post_session.title.clone(), 3, 100),
+                "title".to_string(),
+            ),
+            ValidateField(
+                ValidationType::StringLength(post_session.description.clone(), 0, 2500),
+                "description".to_string(),
+            ),
+            ValidateField(
+                ValidationType::StringLength(post_session.room.clone().unwrap_or_default(), 0, 100),
+                "room".to_string(),
+            ),
+            ValidateField(
+                ValidationType::StringLength(
+                    post_session.stream_url.clone().unwrap_or_default(),
+                    0,
+                    200,
+                ),
+                "stream_url".to_string(),
+            ),
+            ValidateField(
+                ValidationType::Count(post_session.speakers.len(), 0, 20),
+                "speakers".to_string(),
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SessionCalls;
+    use crate::helpers::validator::Validator;
+    use canister_types::models::{date_range::DateRange, event::Event, session::PostEventSession};
+
+    fn post_session(title: &str, start_date: u64, end_date: u64) -> PostEventSession {
+        PostEventSession {
+            title: title.to_string(),
+            description: String::new(),
+            speakers: vec![],
+            room: None,
+            stream_url: None,
+            date: DateRange::new(start_date, end_date),
+        }
+    }
+
+    fn is_valid(event: &Event, post_session: &PostEventSession) -> bool {
+        SessionCalls::session_date_error(event, &post_session.date).is_none()
+            && Validator::new(SessionCalls::session_fields(post_session))
+                .errors()
+                .is_empty()
+    }
+
+    #[test]
+    fn test_validate_session() {
+        let event = Event {
+            date: DateRange::new(100, 200),
+            ..Default::default()
+        };
+
+        assert!(SessionCalls::validate_session(&event, &post_session("keynote", 100, 200)).is_ok());
+        assert!(is_valid(&event, &post_session("keynote", 150, 150)));
+
+        // outside of the event
+        assert!(!is_valid(&event, &post_session("keynote", 50, 150)));
+        assert!(!is_valid(&event, &post_session("keynote", 150, 250)));
+        // ends before it starts
+        assert!(!is_valid(&event, &post_session("keynote", 180, 120)));
+        // title too short
+        assert!(!is_valid(&event, &post_session("ab", 100, 200)));
+    }
+}
@@ -197,6 +197,10 @@ fn attendees_from_old(old_data: &OldData) -> Vec<(Principal, Attendee)> {
                     group_id: Identifier::from(join.group_identifier).id(),
                     updated_at: join.updated_at,
                     created_at: join.created_at,
+                    occurrences: None,
+                    rsvp: None,
+                    rsvp_updated_at: None,
+                    roles: None,
                 };
                 (id, join)
             })
@@ -267,6 +271,11 @@ fn groups_from_old(old_data: &OldData) -> Vec<(u64, Group)> {
             wallets: old_group.wallets.clone(),
             notification_id: None,
             special_members: HashMap::new(),
+            custom_permissions: None,
+            max_members: None,
+            moderation_records: None,
+            is_draft: None,
+            publish_at: None,
         };
 
         new_groups.push((id, group));
@@ -280,7 +289,7 @@ fn groups_from_old(old_data: &OldData) -> Vec<(u64, Group)> {
 
 impl From<GroupRole> for Role {
     fn from(group_role: GroupRole) -> Self {
-        Self {
+        let mut role = Self {
             name: group_role.name,
             protected: group_role.protected,
             permissions: group_role
@@ -290,7 +299,9 @@ impl From<GroupRole> for Role {
                 .collect(),
             color: group_role.color,
             index: group_role.index,
-        }
+        };
+        role.remap_legacy_index();
+        role
     }
 }
 
@@ -318,6 +329,16 @@ fn events_from_old(old_data: &OldData) -> Vec<(u64, Event)> {
             date: old_event.date.clone(),
             image: old_event.image.clone(),
             is_deleted: old_event.is_deleted,
+            recurrence: None,
+            detached_from: None,
+            max_attendees: None,
+            ticket_price: None,
+            co_hosts: None,
+            co_host_invites: None,
+            min_group_tenure_days: None,
+            time_zone: None,
+            is_draft: None,
+            publish_at: None,
         };
 
         new_events.push((id, event));
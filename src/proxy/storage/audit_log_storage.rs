@@ -0,0 +1,27 @@
+use super::{
+    storage_api::{
+        StaticStorageRef, Storage, StorageQueryable, StorageUpdateable, AUDIT_LOGS,
+        AUDIT_LOGS_MEMORY_ID,
+    },
+    StorageInsertable, ID_KIND_AUDIT_LOGS,
+};
+use canister_types::models::audit_log::AuditLog;
+use ic_stable_structures::memory_manager::MemoryId;
+
+pub struct AuditLogStore;
+
+impl Storage<u64, AuditLog> for AuditLogStore {
+    const NAME: &'static str = ID_KIND_AUDIT_LOGS;
+
+    fn storage() -> StaticStorageRef<u64, AuditLog> {
+        &AUDIT_LOGS
+    }
+
+    fn memory_id() -> MemoryId {
+        AUDIT_LOGS_MEMORY_ID
+    }
+}
+
+impl StorageQueryable<u64, AuditLog> for AuditLogStore {}
+impl StorageUpdateable<u64, AuditLog> for AuditLogStore {}
+impl StorageInsertable<AuditLog> for AuditLogStore {}
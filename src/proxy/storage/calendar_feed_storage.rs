@@ -0,0 +1,25 @@
+use super::storage_api::{
+    StaticStorageRef, Storage, StorageInsertableByKey, StorageQueryable, StorageUpdateable,
+    CALENDAR_FEEDS, CALENDAR_FEEDS_MEMORY_ID,
+};
+use candid::Principal;
+use canister_types::models::calendar_feed::CalendarFeed;
+use ic_stable_structures::memory_manager::MemoryId;
+
+pub struct CalendarFeedStore;
+
+impl Storage<Principal, CalendarFeed> for CalendarFeedStore {
+    const NAME: &'static str = "calendar_feeds";
+
+    fn storage() -> StaticStorageRef<Principal, CalendarFeed> {
+        &CALENDAR_FEEDS
+    }
+
+    fn memory_id() -> MemoryId {
+        CALENDAR_FEEDS_MEMORY_ID
+    }
+}
+
+impl StorageQueryable<Principal, CalendarFeed> for CalendarFeedStore {}
+impl StorageUpdateable<Principal, CalendarFeed> for CalendarFeedStore {}
+impl StorageInsertableByKey<Principal, CalendarFeed> for CalendarFeedStore {}
@@ -0,0 +1,27 @@
+use super::{
+    storage_api::{
+        StaticStorageRef, Storage, StorageInsertableByKey, StorageQueryable, StorageUpdateable,
+        EVENT_CHECK_INS, EVENT_CHECK_INS_MEMORY_ID,
+    },
+    ID_KIND_EVENT_CHECK_INS,
+};
+use canister_types::models::check_in::EventCheckIns;
+use ic_stable_structures::memory_manager::MemoryId;
+
+pub struct EventCheckInStore;
+
+impl Storage<u64, EventCheckIns> for EventCheckInStore {
+    const NAME: &'static str = ID_KIND_EVENT_CHECK_INS;
+
+    fn storage() -> StaticStorageRef<u64, EventCheckIns> {
+        &EVENT_CHECK_INS
+    }
+
+    fn memory_id() -> MemoryId {
+        EVENT_CHECK_INS_MEMORY_ID
+    }
+}
+
+impl StorageQueryable<u64, EventCheckIns> for EventCheckInStore {}
+impl StorageUpdateable<u64, EventCheckIns> for EventCheckInStore {}
+impl StorageInsertableByKey<u64, EventCheckIns> for EventCheckInStore {}
@@ -0,0 +1,27 @@
+use super::{
+    storage_api::{
+        StaticStorageRef, Storage, StorageInsertableByKey, StorageQueryable, StorageUpdateable,
+        EVENT_FEEDBACK, EVENT_FEEDBACK_MEMORY_ID,
+    },
+    ID_KIND_EVENT_FEEDBACK,
+};
+use canister_types::models::feedback::EventFeedbacks;
+use ic_stable_structures::memory_manager::MemoryId;
+
+pub struct EventFeedbackStore;
+
+impl Storage<u64, EventFeedbacks> for EventFeedbackStore {
+    const NAME: &'static str = ID_KIND_EVENT_FEEDBACK;
+
+    fn storage() -> StaticStorageRef<u64, EventFeedbacks> {
+        &EVENT_FEEDBACK
+    }
+
+    fn memory_id() -> MemoryId {
+        EVENT_FEEDBACK_MEMORY_ID
+    }
+}
+
+impl StorageQueryable<u64, EventFeedbacks> for EventFeedbackStore {}
+impl StorageUpdateable<u64, EventFeedbacks> for EventFeedbackStore {}
+impl StorageInsertableByKey<u64, EventFeedbacks> for EventFeedbackStore {}
@@ -0,0 +1,27 @@
+use super::{
+    storage_api::{
+        StaticStorageRef, Storage, StorageInsertableByKey, StorageQueryable, StorageUpdateable,
+        EVENT_SESSIONS, EVENT_SESSIONS_MEMORY_ID,
+    },
+    ID_KIND_EVENT_SESSIONS,
+};
+use canister_types::models::session::EventSessions;
+use ic_stable_structures::memory_manager::MemoryId;
+
+pub struct EventSessionStore;
+
+impl Storage<u64, EventSessions> for EventSessionStore {
+    const NAME: &'static str = ID_KIND_EVENT_SESSIONS;
+
+    fn storage() -> StaticStorageRef<u64, EventSessions> {
+        &EVENT_SESSIONS
+    }
+
+    fn memory_id() -> MemoryId {
+        EVENT_SESSIONS_MEMORY_ID
+    }
+}
+
+impl StorageQueryable<u64, EventSessions> for EventSessionStore {}
+impl StorageUpdateable<u64, EventSessions> for EventSessionStore {}
+impl StorageInsertableByKey<u64, EventSessions> for EventSessionStore {}
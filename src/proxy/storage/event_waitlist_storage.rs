@@ -0,0 +1,27 @@
+use super::{
+    storage_api::{
+        StaticStorageRef, Storage, StorageInsertableByKey, StorageQueryable, StorageUpdateable,
+        EVENT_WAITLISTS, EVENT_WAITLISTS_MEMORY_ID,
+    },
+    ID_KIND_EVENT_WAITLISTS,
+};
+use canister_types::models::waitlist::Waitlist;
+use ic_stable_structures::memory_manager::MemoryId;
+
+pub struct EventWaitlistStore;
+
+impl Storage<u64, Waitlist> for EventWaitlistStore {
+    const NAME: &'static str = ID_KIND_EVENT_WAITLISTS;
+
+    fn storage() -> StaticStorageRef<u64, Waitlist> {
+        &EVENT_WAITLISTS
+    }
+
+    fn memory_id() -> MemoryId {
+        EVENT_WAITLISTS_MEMORY_ID
+    }
+}
+
+impl StorageQueryable<u64, Waitlist> for EventWaitlistStore {}
+impl StorageUpdateable<u64, Waitlist> for EventWaitlistStore {}
+impl StorageInsertableByKey<u64, Waitlist> for EventWaitlistStore {}
@@ -0,0 +1,27 @@
+use super::{
+    storage_api::{
+        StaticStorageRef, Storage, StorageInsertableByKey, StorageQueryable, StorageUpdateable,
+        GROUP_AUDIT_LOGS, GROUP_AUDIT_LOGS_MEMORY_ID,
+    },
+    ID_KIND_GROUP_AUDIT_LOGS,
+};
+use canister_types::models::audit_log_collection::AuditLogCollection;
+use ic_stable_structures::memory_manager::MemoryId;
+
+pub struct GroupAuditLogStore;
+
+impl Storage<u64, AuditLogCollection> for GroupAuditLogStore {
+    const NAME: &'static str = ID_KIND_GROUP_AUDIT_LOGS;
+
+    fn storage() -> StaticStorageRef<u64, AuditLogCollection> {
+        &GROUP_AUDIT_LOGS
+    }
+
+    fn memory_id() -> MemoryId {
+        GROUP_AUDIT_LOGS_MEMORY_ID
+    }
+}
+
+impl StorageQueryable<u64, AuditLogCollection> for GroupAuditLogStore {}
+impl StorageUpdateable<u64, AuditLogCollection> for GroupAuditLogStore {}
+impl StorageInsertableByKey<u64, AuditLogCollection> for GroupAuditLogStore {}
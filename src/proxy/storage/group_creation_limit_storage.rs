@@ -0,0 +1,21 @@
+use canister_types::models::group_creation_limit::GroupCreationLimits;
+use ic_stable_structures::memory_manager::MemoryId;
+
+use super::{
+    storage_api::{GROUP_CREATION_LIMITS, GROUP_CREATION_LIMITS_MEMORY_ID},
+    CellStorage, CellStorageRef,
+};
+
+pub struct GroupCreationLimitStorage;
+
+impl CellStorage<GroupCreationLimits> for GroupCreationLimitStorage {
+    const NAME: &'static str = "group_creation_limits";
+
+    fn storage() -> CellStorageRef<GroupCreationLimits> {
+        &GROUP_CREATION_LIMITS
+    }
+
+    fn memory_id() -> MemoryId {
+        GROUP_CREATION_LIMITS_MEMORY_ID
+    }
+}
@@ -0,0 +1,27 @@
+use super::{
+    storage_api::{
+        StaticStorageRef, Storage, StorageInsertableByKey, StorageQueryable, StorageUpdateable,
+        GROUP_RATINGS, GROUP_RATINGS_MEMORY_ID,
+    },
+    ID_KIND_GROUP_RATINGS,
+};
+use canister_types::models::group_rating::GroupRating;
+use ic_stable_structures::memory_manager::MemoryId;
+
+pub struct GroupRatingStore;
+
+impl Storage<u64, GroupRating> for GroupRatingStore {
+    const NAME: &'static str = ID_KIND_GROUP_RATINGS;
+
+    fn storage() -> StaticStorageRef<u64, GroupRating> {
+        &GROUP_RATINGS
+    }
+
+    fn memory_id() -> MemoryId {
+        GROUP_RATINGS_MEMORY_ID
+    }
+}
+
+impl StorageQueryable<u64, GroupRating> for GroupRatingStore {}
+impl StorageUpdateable<u64, GroupRating> for GroupRatingStore {}
+impl StorageInsertableByKey<u64, GroupRating> for GroupRatingStore {}
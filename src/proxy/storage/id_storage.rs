@@ -16,6 +16,14 @@ pub const ID_KIND_TAGS: &str = "tags";
 pub const ID_KIND_CATEGORIES: &str = "categories";
 pub const ID_KIND_REWARDS_BUFFER: &str = "rewards_buffer";
 pub const ID_KIND_SKILLS: &str = "skills";
+pub const ID_KIND_AUDIT_LOGS: &str = "audit_logs";
+pub const ID_KIND_EVENT_WAITLISTS: &str = "event_waitlists";
+pub const ID_KIND_EVENT_CHECK_INS: &str = "event_check_ins";
+pub const ID_KIND_TICKET_RECEIPTS: &str = "ticket_receipts";
+pub const ID_KIND_EVENT_SESSIONS: &str = "event_sessions";
+pub const ID_KIND_EVENT_FEEDBACK: &str = "event_feedback";
+pub const ID_KIND_GROUP_AUDIT_LOGS: &str = "group_audit_logs";
+pub const ID_KIND_GROUP_RATINGS: &str = "group_ratings";
 
 #[derive(Debug, Clone)]
 pub enum IDKind {
@@ -33,6 +41,14 @@ pub enum IDKind {
     Categories,
     Skills,
     RewardBuffer,
+    AuditLogs,
+    EventWaitlists,
+    EventCheckIns,
+    TicketReceipts,
+    EventSessions,
+    EventFeedback,
+    GroupAuditLogs,
+    GroupRatings,
 }
 
 impl std::fmt::Display for IDKind {
@@ -52,6 +68,14 @@ impl std::fmt::Display for IDKind {
             IDKind::Categories => write!(f, "{ID_KIND_CATEGORIES}"),
             IDKind::Skills => write!(f, "{ID_KIND_SKILLS}"),
             IDKind::RewardBuffer => write!(f, "{ID_KIND_REWARDS_BUFFER}"),
+            IDKind::AuditLogs => write!(f, "{ID_KIND_AUDIT_LOGS}"),
+            IDKind::EventWaitlists => write!(f, "{ID_KIND_EVENT_WAITLISTS}"),
+            IDKind::EventCheckIns => write!(f, "{ID_KIND_EVENT_CHECK_INS}"),
+            IDKind::TicketReceipts => write!(f, "{ID_KIND_TICKET_RECEIPTS}"),
+            IDKind::EventSessions => write!(f, "{ID_KIND_EVENT_SESSIONS}"),
+            IDKind::EventFeedback => write!(f, "{ID_KIND_EVENT_FEEDBACK}"),
+            IDKind::GroupAuditLogs => write!(f, "{ID_KIND_GROUP_AUDIT_LOGS}"),
+            IDKind::GroupRatings => write!(f, "{ID_KIND_GROUP_RATINGS}"),
         }
     }
 }
@@ -75,6 +99,14 @@ impl std::str::FromStr for IDKind {
             ID_KIND_CATEGORIES => Ok(IDKind::Categories),
             ID_KIND_SKILLS => Ok(IDKind::Skills),
             ID_KIND_REWARDS_BUFFER => Ok(IDKind::RewardBuffer),
+            ID_KIND_AUDIT_LOGS => Ok(IDKind::AuditLogs),
+            ID_KIND_EVENT_WAITLISTS => Ok(IDKind::EventWaitlists),
+            ID_KIND_EVENT_CHECK_INS => Ok(IDKind::EventCheckIns),
+            ID_KIND_TICKET_RECEIPTS => Ok(IDKind::TicketReceipts),
+            ID_KIND_EVENT_SESSIONS => Ok(IDKind::EventSessions),
+            ID_KIND_EVENT_FEEDBACK => Ok(IDKind::EventFeedback),
+            ID_KIND_GROUP_AUDIT_LOGS => Ok(IDKind::GroupAuditLogs),
+            ID_KIND_GROUP_RATINGS => Ok(IDKind::GroupRatings),
             _ => Err(format!("Unknown IDKind: {s}")),
         }
     }
@@ -138,6 +170,14 @@ impl IDStore {
             IDKind::Categories => super::CategoryStore::storage().with(last_key),
             IDKind::Skills => super::SkillStore::storage().with(last_key),
             IDKind::RewardBuffer => super::RewardBufferStore::storage().with(last_key),
+            IDKind::AuditLogs => super::AuditLogStore::storage().with(last_key),
+            IDKind::EventWaitlists => super::EventWaitlistStore::storage().with(last_key),
+            IDKind::EventCheckIns => super::EventCheckInStore::storage().with(last_key),
+            IDKind::TicketReceipts => super::TicketReceiptStore::storage().with(last_key),
+            IDKind::EventSessions => super::EventSessionStore::storage().with(last_key),
+            IDKind::EventFeedback => super::EventFeedbackStore::storage().with(last_key),
+            IDKind::GroupAuditLogs => super::GroupAuditLogStore::storage().with(last_key),
+            IDKind::GroupRatings => super::GroupRatingStore::storage().with(last_key),
         }
     }
 }
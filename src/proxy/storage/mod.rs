@@ -1,11 +1,20 @@
 mod attendee_storage;
+mod audit_log_storage;
 mod boosted_storage;
+mod calendar_feed_storage;
 mod cell_api;
 mod event_attendees_storage;
+mod event_check_in_storage;
+mod event_feedback_storage;
+mod event_session_storage;
 mod event_storage;
+mod event_waitlist_storage;
 mod friend_request_storage;
+mod group_audit_log_storage;
+pub mod group_creation_limit_storage;
 mod group_events_storage;
 mod group_members_storage;
+mod group_rating_storage;
 mod group_storage;
 pub mod group_transfer_request_storage;
 mod history_canister_storage;
@@ -20,18 +29,27 @@ mod report_storage;
 pub mod reward_canister_storage;
 pub mod reward_storage;
 pub mod storage_api;
+mod ticket_receipt_storage;
 mod topic_storage;
 mod user_notification_storage;
 // Re-export stores
 
 pub use attendee_storage::AttendeeStore;
+pub use audit_log_storage::AuditLogStore;
 pub use boosted_storage::BoostedStore;
+pub use calendar_feed_storage::CalendarFeedStore;
 pub use cell_api::{CellStorage, CellStorageRef};
 pub use event_attendees_storage::EventAttendeeStore;
+pub use event_check_in_storage::EventCheckInStore;
+pub use event_feedback_storage::EventFeedbackStore;
+pub use event_session_storage::EventSessionStore;
 pub use event_storage::EventStore;
+pub use event_waitlist_storage::EventWaitlistStore;
 pub use friend_request_storage::FriendRequestStore;
+pub use group_audit_log_storage::GroupAuditLogStore;
 pub use group_events_storage::GroupEventsStore;
 pub use group_members_storage::GroupMemberStore;
+pub use group_rating_storage::GroupRatingStore;
 pub use group_storage::GroupStore;
 pub use history_canister_storage::HistoryCanisterStorage;
 pub use history_point_storage::HistoryPointStorage;
@@ -42,6 +60,7 @@ pub use report_storage::ReportStore;
 pub use storage_api::{
     StorageInsertable, StorageInsertableByKey, StorageQueryable, StorageUpdateable,
 };
+pub use ticket_receipt_storage::TicketReceiptStore;
 pub use topic_storage::{CategoryStore, SkillStore, TagStore};
 pub use user_notification_storage::UserNotificationStore;
 
@@ -82,4 +82,9 @@ impl RewardBufferStore {
             RewardableActivity::new(Activity::FirstGroupJoined(principal));
         let _ = RewardBufferStore::insert(activity);
     }
+
+    pub fn notify_event_attended(event_id: u64, principal: Principal) {
+        let activity = RewardableActivity::new(Activity::EventAttendance((event_id, principal)));
+        let _ = RewardBufferStore::insert(activity);
+    }
 }
@@ -1,11 +1,14 @@
 use candid::Principal;
 use canister_types::models::{
-    api_error::ApiError, attendee::Attendee, boosted::Boost, event::Event,
-    event_collection::EventCollection, friend_request::FriendRequest, group::Group,
+    api_error::ApiError, attendee::Attendee, audit_log::AuditLog,
+    audit_log_collection::AuditLogCollection, boosted::Boost, calendar_feed::CalendarFeed,
+    check_in::EventCheckIns, event::Event, event_collection::EventCollection,
+    feedback::EventFeedbacks, friend_request::FriendRequest, group::Group,
+    group_creation_limit::GroupCreationLimits, group_rating::GroupRating,
     group_transfer_request::GroupTransferRequest, log::Logger, member::Member,
     member_collection::MemberCollection, notification::Notification, profile::Profile,
-    referral::Referral, report::Report, reward::RewardableActivity,
-    user_notifications::UserNotifications,
+    referral::Referral, report::Report, reward::RewardableActivity, session::EventSessions,
+    ticket::TicketReceipt, user_notifications::UserNotifications, waitlist::Waitlist,
 };
 use ic_stable_structures::{
     memory_manager::{MemoryId, MemoryManager, VirtualMemory},
@@ -58,6 +61,16 @@ pub static REWARD_CANISTER_MEMORY_ID: MemoryId = MemoryId::new(21);
 
 pub static GROUP_TRANSFER_REQUESTS_MEMORY_ID: MemoryId = MemoryId::new(22);
 pub static REFERRAL_MEMORY_ID: MemoryId = MemoryId::new(23);
+pub static AUDIT_LOGS_MEMORY_ID: MemoryId = MemoryId::new(24);
+pub static GROUP_CREATION_LIMITS_MEMORY_ID: MemoryId = MemoryId::new(25);
+pub static EVENT_WAITLISTS_MEMORY_ID: MemoryId = MemoryId::new(26);
+pub static EVENT_CHECK_INS_MEMORY_ID: MemoryId = MemoryId::new(27);
+pub static CALENDAR_FEEDS_MEMORY_ID: MemoryId = MemoryId::new(28);
+pub static TICKET_RECEIPTS_MEMORY_ID: MemoryId = MemoryId::new(29);
+pub static EVENT_SESSIONS_MEMORY_ID: MemoryId = MemoryId::new(30);
+pub static EVENT_FEEDBACK_MEMORY_ID: MemoryId = MemoryId::new(31);
+pub static GROUP_AUDIT_LOGS_MEMORY_ID: MemoryId = MemoryId::new(32);
+pub static GROUP_RATINGS_MEMORY_ID: MemoryId = MemoryId::new(33);
 
 /// A reference to a `StableBTreeMap` that is wrapped in a `RefCell`.
 ///# Generics
@@ -102,6 +115,18 @@ pub trait StorageQueryable<K: 'static + Storable + Ord + Clone, V: 'static + Sto
         })
     }
 
+    /// Get a single entity by key, or the default value if it does not exist
+    /// # Arguments
+    /// * `key` - The key of the entity to get
+    /// # Returns
+    /// * `V` - The entity if found, otherwise the default value
+    fn get_or_default(key: K) -> V
+    where
+        V: Default,
+    {
+        Self::get(key).map(|(_, value)| value).unwrap_or_default()
+    }
+
     /// Get multiple entities by key
     /// # Arguments
     /// * `keys` - The keys of the entities to get
@@ -205,6 +230,19 @@ pub trait StorageInsertableByKey<K: 'static + Storable + Ord + Clone, V: 'static
             Ok((key, value))
         })
     }
+
+    /// Insert a single entity by key, or replace it if it already exists
+    /// # Arguments
+    /// * `key` - The key of the entity to insert or replace
+    /// * `value` - The entity to insert or replace
+    /// # Returns
+    /// * `(K, V)` - The stored entity
+    fn upsert_by_key(key: K, value: V) -> (K, V) {
+        Self::storage().with(|data| {
+            data.borrow_mut().insert(key.clone(), value.clone());
+            (key, value)
+        })
+    }
 }
 
 pub trait StorageUpdateable<K: 'static + Storable + Ord + Clone, V: 'static + Storable + Clone>:
@@ -366,4 +404,46 @@ thread_local! {
         StableBTreeMap::init(MEMORY_MANAGER.with(|p| p.borrow().get(REFERRAL_MEMORY_ID)))
     );
 
+    pub static AUDIT_LOGS: StorageRef<u64, AuditLog> = RefCell::new(
+        StableBTreeMap::init(MEMORY_MANAGER.with(|p| p.borrow().get(AUDIT_LOGS_MEMORY_ID)))
+    );
+
+    pub static GROUP_CREATION_LIMITS: RefCell<Cell<Option<GroupCreationLimits>, Memory>> = RefCell::new(
+        Cell::init(MEMORY_MANAGER.with(|p| p.borrow().get(GROUP_CREATION_LIMITS_MEMORY_ID)), None)
+            .expect("Failed to initialize group creation limits")
+    );
+
+    pub static EVENT_WAITLISTS: StorageRef<u64, Waitlist> = RefCell::new(
+        StableBTreeMap::init(MEMORY_MANAGER.with(|p| p.borrow().get(EVENT_WAITLISTS_MEMORY_ID)))
+    );
+
+    pub static EVENT_CHECK_INS: StorageRef<u64, EventCheckIns> = RefCell::new(
+        StableBTreeMap::init(MEMORY_MANAGER.with(|p| p.borrow().get(EVENT_CHECK_INS_MEMORY_ID)))
+    );
+
+    pub static CALENDAR_FEEDS: StorageRef<Principal, CalendarFeed> = RefCell::new(
+        StableBTreeMap::init(MEMORY_MANAGER.with(|p| p.borrow().get(CALENDAR_FEEDS_MEMORY_ID)))
+    );
+
+    // keyed by the block height of the ticket payment
+    pub static TICKET_RECEIPTS: StorageRef<u64, TicketReceipt> = RefCell::new(
+        StableBTreeMap::init(MEMORY_MANAGER.with(|p| p.borrow().get(TICKET_RECEIPTS_MEMORY_ID)))
+    );
+
+    pub static EVENT_SESSIONS: StorageRef<u64, EventSessions> = RefCell::new(
+        StableBTreeMap::init(MEMORY_MANAGER.with(|p| p.borrow().get(EVENT_SESSIONS_MEMORY_ID)))
+    );
+
+    pub static EVENT_FEEDBACK: StorageRef<u64, EventFeedbacks> = RefCell::new(
+        StableBTreeMap::init(MEMORY_MANAGER.with(|p| p.borrow().get(EVENT_FEEDBACK_MEMORY_ID)))
+    );
+
+    pub static GROUP_AUDIT_LOGS: StorageRef<u64, AuditLogCollection> = RefCell::new(
+        StableBTreeMap::init(MEMORY_MANAGER.with(|p| p.borrow().get(GROUP_AUDIT_LOGS_MEMORY_ID)))
+    );
+
+    pub static GROUP_RATINGS: StorageRef<u64, GroupRating> = RefCell::new(
+        StableBTreeMap::init(MEMORY_MANAGER.with(|p| p.borrow().get(GROUP_RATINGS_MEMORY_ID)))
+    );
+
 }
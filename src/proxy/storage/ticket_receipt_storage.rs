@@ -0,0 +1,27 @@
+use super::{
+    storage_api::{
+        StaticStorageRef, Storage, StorageInsertableByKey, StorageQueryable, StorageUpdateable,
+        TICKET_RECEIPTS, TICKET_RECEIPTS_MEMORY_ID,
+    },
+    ID_KIND_TICKET_RECEIPTS,
+};
+use canister_types::models::ticket::TicketReceipt;
+use ic_stable_structures::memory_manager::MemoryId;
+
+pub struct TicketReceiptStore;
+
+impl Storage<u64, TicketReceipt> for TicketReceiptStore {
+    const NAME: &'static str = ID_KIND_TICKET_RECEIPTS;
+
+    fn storage() -> StaticStorageRef<u64, TicketReceipt> {
+        &TICKET_RECEIPTS
+    }
+
+    fn memory_id() -> MemoryId {
+        TICKET_RECEIPTS_MEMORY_ID
+    }
+}
+
+impl StorageQueryable<u64, TicketReceipt> for TicketReceiptStore {}
+impl StorageUpdateable<u64, TicketReceipt> for TicketReceiptStore {}
+impl StorageInsertableByKey<u64, TicketReceipt> for TicketReceiptStore {}
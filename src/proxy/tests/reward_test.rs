@@ -137,6 +137,9 @@ pub fn test_first_group_join() {
             image: Asset::None,
             banner_image: Asset::None,
             tags: vec![],
+            max_members: None,
+            is_draft: None,
+            publish_at: None,
         },
     );
 